@@ -14,6 +14,12 @@
 
 #![forbid(unsafe_code)]
 
+mod mnemonic;
+pub use mnemonic::*;
+
+mod signer;
+pub use signer::*;
+
 use snarkvm::{
     console::{network::prelude::*, types::Field},
     prelude::*,
@@ -39,6 +45,11 @@ impl<N: Network> Account<N> {
         Self::try_from(PrivateKey::new(rng)?)
     }
 
+    /// Derives the account at the given index from a mnemonic and passphrase.
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: &str, index: u32) -> Result<Self> {
+        Self::try_from(mnemonic.derive_private_key::<N>(passphrase, index)?)
+    }
+
     /// Returns the account private key.
     pub const fn private_key(&self) -> &PrivateKey<N> {
         &self.private_key
@@ -166,6 +177,21 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_from_mnemonic() {
+        // Initialize the RNG.
+        let mut rng = TestRng::default();
+        // Sample a mnemonic.
+        let mnemonic = Mnemonic::new(Mnemonic::DEFAULT_WORD_COUNT, &mut rng).unwrap();
+        // Ensure the derived accounts are deterministic and distinct per index.
+        let account = Account::<CurrentNetwork>::from_mnemonic(&mnemonic, "", 0).unwrap();
+        let recovered =
+            Account::<CurrentNetwork>::from_mnemonic(&mnemonic.to_string().parse().unwrap(), "", 0).unwrap();
+        let other = Account::<CurrentNetwork>::from_mnemonic(&mnemonic, "", 1).unwrap();
+        assert_eq!(account.address(), recovered.address());
+        assert_ne!(account.address(), other.address());
+    }
+
     #[test]
     fn test_sign() {
         // Initialize the RNG.
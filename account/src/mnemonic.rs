@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm::{
+    console::{network::prelude::*, types::Field},
+    prelude::*,
+};
+
+use bip39::Language;
+use core::fmt;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+/// The HMAC key used to derive account seeds from a mnemonic seed.
+const DERIVATION_KEY: &[u8] = b"Aleo Account Seed";
+
+/// A BIP39 mnemonic phrase, from which Aleo accounts are deterministically derived.
+///
+/// The mnemonic (and an optional passphrase) is stretched into a 64-byte seed following BIP39.
+/// The private key at a given index is then derived as:
+/// ```ignore
+///     PrivateKey::try_from(Field::from_bytes_le_mod_order(HMAC-SHA512(DERIVATION_KEY, seed || index_le)))
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic {
+    /// The underlying BIP39 mnemonic.
+    mnemonic: bip39::Mnemonic,
+}
+
+impl Mnemonic {
+    /// The default number of words in a mnemonic.
+    pub const DEFAULT_WORD_COUNT: usize = 24;
+
+    /// Samples a new mnemonic with the given number of words (one of 12, 15, 18, 21, or 24).
+    pub fn new<R: Rng + CryptoRng>(word_count: usize, rng: &mut R) -> Result<Self> {
+        // Ensure the word count is valid.
+        if word_count < 12 || word_count > 24 || word_count % 3 != 0 {
+            bail!("Invalid mnemonic word count '{word_count}' (expected one of 12, 15, 18, 21, or 24)");
+        }
+        // Sample the entropy, which is 32 bits for every 3 words.
+        let entropy = (0..(word_count / 3) * 4).map(|_| rng.gen::<u8>()).collect::<Vec<u8>>();
+        // Construct the mnemonic.
+        let mnemonic = bip39::Mnemonic::from_entropy_in(Language::English, &entropy)
+            .map_err(|e| anyhow!("Failed to construct the mnemonic - {e}"))?;
+        Ok(Self { mnemonic })
+    }
+
+    /// Returns the number of words in the mnemonic.
+    pub fn word_count(&self) -> usize {
+        self.mnemonic.word_count()
+    }
+
+    /// Returns the BIP39 seed for the mnemonic, using the given passphrase.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        self.mnemonic.to_seed(passphrase)
+    }
+
+    /// Returns the private key at the given index, using the given passphrase.
+    pub fn derive_private_key<N: Network>(&self, passphrase: &str, index: u32) -> Result<PrivateKey<N>> {
+        // Compute the BIP39 seed.
+        let seed = self.to_seed(passphrase);
+        // Derive the account seed for the given index.
+        let mut mac = Hmac::<Sha512>::new_from_slice(DERIVATION_KEY).map_err(|e| anyhow!("{e}"))?;
+        mac.update(&seed);
+        mac.update(&index.to_le_bytes());
+        let bytes = mac.finalize().into_bytes();
+        // Map the account seed into a field element.
+        let field = Field::<N>::new(<N as Environment>::Field::from_bytes_le_mod_order(&bytes));
+        // Recover the private key from the field element.
+        PrivateKey::try_from(field).map_err(|_| anyhow!("Failed to derive a private key at index {index}"))
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    /// Initializes a mnemonic from a phrase of English words.
+    fn from_str(phrase: &str) -> Result<Self, Self::Err> {
+        // Normalize the whitespace between the words.
+        let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+        // Parse the mnemonic.
+        let mnemonic = bip39::Mnemonic::parse_in_normalized(Language::English, &phrase.to_lowercase())
+            .map_err(|e| anyhow!("Invalid mnemonic - {e}"))?;
+        Ok(Self { mnemonic })
+    }
+}
+
+impl Display for Mnemonic {
+    /// Renders the mnemonic as a phrase of space-separated words.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic)
+    }
+}
+
+impl Debug for Mnemonic {
+    /// Renders the mnemonic without revealing the phrase.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Mnemonic({} words)", self.word_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// The BIP39 test vector for 12 words of zero entropy.
+    const PHRASE: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_new() {
+        let mut rng = TestRng::default();
+        for word_count in [12, 15, 18, 21, 24] {
+            let mnemonic = Mnemonic::new(word_count, &mut rng).unwrap();
+            assert_eq!(mnemonic.word_count(), word_count);
+            // Ensure the mnemonic round-trips through its phrase.
+            assert_eq!(mnemonic, Mnemonic::from_str(&mnemonic.to_string()).unwrap());
+        }
+        // Ensure invalid word counts are rejected.
+        for word_count in [0, 11, 13, 25, 27] {
+            assert!(Mnemonic::new(word_count, &mut rng).is_err());
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        // Ensure whitespace and case are normalized.
+        let mnemonic = Mnemonic::from_str(PHRASE).unwrap();
+        let candidate = Mnemonic::from_str(&format!("  {}  ", PHRASE.to_uppercase().replace(' ', "\n "))).unwrap();
+        assert_eq!(mnemonic, candidate);
+        // Ensure an invalid checksum is rejected.
+        assert!(Mnemonic::from_str(&PHRASE.replace("about", "abandon")).is_err());
+        // Ensure an unknown word is rejected.
+        assert!(Mnemonic::from_str(&PHRASE.replace("about", "aleo")).is_err());
+    }
+
+    #[test]
+    fn test_derive_private_key() {
+        let mnemonic = Mnemonic::from_str(PHRASE).unwrap();
+        // Ensure derivation is deterministic.
+        let a = mnemonic.derive_private_key::<CurrentNetwork>("", 0).unwrap();
+        let b = mnemonic.derive_private_key::<CurrentNetwork>("", 0).unwrap();
+        assert_eq!(a, b);
+        // Ensure the index and passphrase change the private key.
+        let c = mnemonic.derive_private_key::<CurrentNetwork>("", 1).unwrap();
+        let d = mnemonic.derive_private_key::<CurrentNetwork>("passphrase", 0).unwrap();
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+        assert_ne!(c, d);
+    }
+
+    #[test]
+    fn test_derive_private_key_known_answers() {
+        let mnemonic = Mnemonic::from_str(PHRASE).unwrap();
+        // Ensure the BIP39 seed matches the reference vector.
+        let expected_seed =
+            [0x5e, 0xb0, 0x0b, 0xbd, 0xdc, 0xf0, 0x69, 0x08, 0x48, 0x89, 0xa8, 0xab, 0x91, 0x55, 0x56, 0x81];
+        assert_eq!(mnemonic.to_seed("")[..16], expected_seed);
+        // Ensure the derived private keys are pinned, with and without a passphrase.
+        for (passphrase, index, expected) in [
+            ("", 0, "APrivateKey1zkpJDQxdwp5bkVWM3o1tA5ukoXKMQ44TSSZLkS56ibX4uVJ"),
+            ("", 1, "APrivateKey1zkpCyUgCESkQUrWzWGbVKW9KKrDsYgFHHjcgd22SWQ4fJG8"),
+            ("passphrase", 0, "APrivateKey1zkp6zvQg9y35hd7qimKAwgpKkhDWgFc6NnEZuht9hfV8Zkz"),
+            ("passphrase", 1, "APrivateKey1zkpHx15TkEyt2kAAbwtdu98969v7ZwkK13jQkTSaPvTkjfC"),
+        ] {
+            let private_key = mnemonic.derive_private_key::<CurrentNetwork>(passphrase, index).unwrap();
+            assert_eq!(private_key.to_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm::{
+    console::{network::prelude::*, types::Field},
+    prelude::*,
+};
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// A guard that prevents a signer from signing conflicting batches.
+///
+/// For every batch author, the guard records the latest round and batch ID that was signed.
+/// A request is refused if it is for an earlier round, or for the same round with a different batch ID.
+/// Re-signing the exact same batch is permitted, as peers may request a signature again.
+#[derive(Debug)]
+pub struct DoubleSignGuard<N: Network> {
+    /// The map of `author` to the latest `(round, batch ID)` signed.
+    signed: Mutex<HashMap<Address<N>, (u64, Field<N>)>>,
+    /// The optional path where the guard state is persisted.
+    path: Option<PathBuf>,
+}
+
+impl<N: Network> Default for DoubleSignGuard<N> {
+    /// Initializes a new in-memory guard.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> DoubleSignGuard<N> {
+    /// Initializes a new in-memory guard.
+    pub fn new() -> Self {
+        Self { signed: Default::default(), path: None }
+    }
+
+    /// Opens a guard that is persisted at the given path, loading any existing state.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut signed = HashMap::new();
+        // Load the existing state, if it exists.
+        if path.exists() {
+            let contents = std::fs::read_to_string(&path)?;
+            for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+                // Parse the line as '<author> <round> <batch ID>'.
+                let mut parts = line.split_whitespace();
+                let (Some(author), Some(round), Some(batch_id), None) =
+                    (parts.next(), parts.next(), parts.next(), parts.next())
+                else {
+                    bail!("Malformed entry '{line}' in the double-sign guard at '{}'", path.display());
+                };
+                signed.insert(Address::from_str(author)?, (round.parse()?, Field::from_str(batch_id)?));
+            }
+        }
+        Ok(Self { signed: Mutex::new(signed), path: Some(path) })
+    }
+
+    /// Checks that signing the given batch does not conflict with a previous signature, and records it.
+    pub fn check_and_record(&self, author: Address<N>, round: u64, batch_id: Field<N>) -> Result<()> {
+        let mut signed = self.signed.lock().map_err(|_| anyhow!("The double-sign guard is poisoned"))?;
+        // Ensure the batch does not conflict with the latest signed batch from this author.
+        if let Some((latest_round, latest_batch_id)) = signed.get(&author) {
+            if round < *latest_round {
+                bail!(
+                    "Refusing to sign a batch for round {round} from '{author}' (already signed round {latest_round})"
+                );
+            }
+            if round == *latest_round {
+                if batch_id != *latest_batch_id {
+                    bail!("Refusing to double-sign a batch for round {round} from '{author}'");
+                }
+                // The batch was already signed, so there is nothing to record.
+                return Ok(());
+            }
+        }
+        // Record the batch.
+        signed.insert(author, (round, batch_id));
+        // Persist the state, if a path is set.
+        if let Some(path) = &self.path {
+            let contents = signed
+                .iter()
+                .map(|(author, (round, batch_id))| format!("{author} {round} {batch_id}\n"))
+                .collect::<String>();
+            // Write to a temporary file first, so the state is never left partially written.
+            let tmp_path = path.with_extension("tmp");
+            std::fs::write(&tmp_path, contents)?;
+            std::fs::rename(&tmp_path, path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_check_and_record() {
+        let mut rng = TestRng::default();
+
+        let guard = DoubleSignGuard::<CurrentNetwork>::new();
+        let author = Address::rand(&mut rng);
+        let (batch_a, batch_b) = (Field::rand(&mut rng), Field::rand(&mut rng));
+
+        // Ensure the first signature is accepted, and may be repeated.
+        assert!(guard.check_and_record(author, 2, batch_a).is_ok());
+        assert!(guard.check_and_record(author, 2, batch_a).is_ok());
+        // Ensure a conflicting batch in the same round is refused.
+        assert!(guard.check_and_record(author, 2, batch_b).is_err());
+        // Ensure an earlier round is refused.
+        assert!(guard.check_and_record(author, 1, batch_b).is_err());
+        // Ensure a later round is accepted.
+        assert!(guard.check_and_record(author, 3, batch_b).is_ok());
+        // Ensure other authors are tracked independently.
+        assert!(guard.check_and_record(Address::rand(&mut rng), 1, batch_a).is_ok());
+    }
+
+    #[test]
+    fn test_open() {
+        let mut rng = TestRng::default();
+
+        let path = std::env::temp_dir().join(format!("snarkos-double-sign-guard-{}", rng.gen::<u64>()));
+        let author = Address::rand(&mut rng);
+        let (batch_a, batch_b) = (Field::rand(&mut rng), Field::rand(&mut rng));
+
+        // Record a batch in a persisted guard.
+        let guard = DoubleSignGuard::<CurrentNetwork>::open(&path).unwrap();
+        assert!(guard.check_and_record(author, 5, batch_a).is_ok());
+        drop(guard);
+
+        // Ensure the reopened guard still refuses a conflicting batch.
+        let guard = DoubleSignGuard::<CurrentNetwork>::open(&path).unwrap();
+        assert!(guard.check_and_record(author, 5, batch_b).is_err());
+        assert!(guard.check_and_record(author, 5, batch_a).is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
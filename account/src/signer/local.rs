@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{DoubleSignGuard, SignRequest, Signer};
+use crate::Account;
+
+use snarkvm::{console::network::prelude::*, prelude::*};
+
+/// A signer that holds the account private key in process memory.
+#[derive(Debug)]
+pub struct LocalSigner<N: Network> {
+    /// The account of the signer.
+    account: Account<N>,
+    /// The double-sign guard.
+    guard: DoubleSignGuard<N>,
+}
+
+impl<N: Network> LocalSigner<N> {
+    /// Initializes a new local signer, with an in-memory double-sign guard.
+    pub fn new(account: Account<N>) -> Self {
+        Self::with_guard(account, DoubleSignGuard::new())
+    }
+
+    /// Initializes a new local signer, with the given double-sign guard.
+    pub fn with_guard(account: Account<N>, guard: DoubleSignGuard<N>) -> Self {
+        Self { account, guard }
+    }
+}
+
+impl<N: Network> Signer<N> for LocalSigner<N> {
+    /// Returns the address of the signer.
+    fn address(&self) -> Address<N> {
+        self.account.address()
+    }
+
+    /// Returns a signature for the given request.
+    fn sign(&self, request: &SignRequest<N>) -> Result<Signature<N>> {
+        let rng = &mut rand::thread_rng();
+        match *request {
+            SignRequest::BatchHeader { round, batch_id } => {
+                self.guard.check_and_record(self.account.address(), round, batch_id)?;
+                self.account.sign(&[batch_id], rng)
+            }
+            SignRequest::BatchSignature { author, round, batch_id } => {
+                // Ensure the signer does not sign its own batch as a peer.
+                ensure!(author != self.account.address(), "Refusing to sign a batch signature for our own batch");
+                self.guard.check_and_record(author, round, batch_id)?;
+                self.account.sign(&[batch_id], rng)
+            }
+            SignRequest::Challenge { nonce } => self.account.sign_bytes(&nonce.to_le_bytes(), rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::{console::types::Field, prelude::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sign() {
+        let mut rng = TestRng::default();
+
+        let account = Account::<CurrentNetwork>::new(&mut rng).unwrap();
+        let signer = LocalSigner::new(account.clone());
+        assert_eq!(signer.address(), account.address());
+
+        // Ensure the batch header signature is valid.
+        let batch_id = Field::rand(&mut rng);
+        let signature = signer.sign(&SignRequest::BatchHeader { round: 1, batch_id }).unwrap();
+        assert!(account.verify(&[batch_id], &signature));
+        // Ensure a conflicting batch header is refused.
+        assert!(signer.sign(&SignRequest::BatchHeader { round: 1, batch_id: Field::rand(&mut rng) }).is_err());
+
+        // Ensure the batch signature is valid.
+        let author = Address::rand(&mut rng);
+        let signature = signer.sign(&SignRequest::BatchSignature { author, round: 1, batch_id }).unwrap();
+        assert!(account.verify(&[batch_id], &signature));
+        // Ensure our own batch is refused as a peer.
+        let author = account.address();
+        assert!(signer.sign(&SignRequest::BatchSignature { author, round: 2, batch_id }).is_err());
+
+        // Ensure the challenge signature is valid.
+        let nonce = rng.gen::<u64>();
+        let signature = signer.sign(&SignRequest::Challenge { nonce }).unwrap();
+        assert!(account.verify_bytes(&nonce.to_le_bytes(), &signature));
+    }
+}
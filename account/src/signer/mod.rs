@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod guard;
+pub use guard::*;
+
+mod local;
+pub use local::*;
+
+#[cfg(unix)]
+mod remote;
+#[cfg(unix)]
+pub use remote::*;
+
+use crate::Account;
+
+use snarkvm::{
+    console::{network::prelude::*, types::Field},
+    prelude::*,
+};
+
+use std::sync::Arc;
+
+/// A request to sign a message on behalf of a validator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignRequest<N: Network> {
+    /// Signs the batch ID of a batch header proposed by this validator.
+    BatchHeader { round: u64, batch_id: Field<N> },
+    /// Signs the batch ID of a batch header proposed by the given author.
+    BatchSignature { author: Address<N>, round: u64, batch_id: Field<N> },
+    /// Signs the nonce of a challenge request from a peer.
+    Challenge { nonce: u64 },
+}
+
+impl<N: Network> ToBytes for SignRequest<N> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::BatchHeader { round, batch_id } => {
+                0u8.write_le(&mut writer)?;
+                round.write_le(&mut writer)?;
+                batch_id.write_le(&mut writer)
+            }
+            Self::BatchSignature { author, round, batch_id } => {
+                1u8.write_le(&mut writer)?;
+                author.write_le(&mut writer)?;
+                round.write_le(&mut writer)?;
+                batch_id.write_le(&mut writer)
+            }
+            Self::Challenge { nonce } => {
+                2u8.write_le(&mut writer)?;
+                nonce.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for SignRequest<N> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => {
+                let round = u64::read_le(&mut reader)?;
+                let batch_id = Field::read_le(&mut reader)?;
+                Ok(Self::BatchHeader { round, batch_id })
+            }
+            1 => {
+                let author = Address::read_le(&mut reader)?;
+                let round = u64::read_le(&mut reader)?;
+                let batch_id = Field::read_le(&mut reader)?;
+                Ok(Self::BatchSignature { author, round, batch_id })
+            }
+            2 => {
+                let nonce = u64::read_le(&mut reader)?;
+                Ok(Self::Challenge { nonce })
+            }
+            variant => Err(error(format!("Invalid sign request variant '{variant}'"))),
+        }
+    }
+}
+
+/// A signer holds (or has access to) the private key of a validator,
+/// and produces signatures for the BFT on its behalf.
+pub trait Signer<N: Network>: Send + Sync {
+    /// Returns the address of the signer.
+    fn address(&self) -> Address<N>;
+
+    /// Returns a signature for the given request.
+    fn sign(&self, request: &SignRequest<N>) -> Result<Signature<N>>;
+}
+
+impl<N: Network> From<Account<N>> for Arc<dyn Signer<N>> {
+    /// Initializes a local signer from the given account.
+    fn from(account: Account<N>) -> Self {
+        Arc::new(LocalSigner::new(account))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sign_request_bytes() {
+        let mut rng = TestRng::default();
+
+        let requests = [
+            SignRequest::<CurrentNetwork>::BatchHeader { round: rng.gen(), batch_id: Field::rand(&mut rng) },
+            SignRequest::BatchSignature {
+                author: Address::rand(&mut rng),
+                round: rng.gen(),
+                batch_id: Field::rand(&mut rng),
+            },
+            SignRequest::Challenge { nonce: rng.gen() },
+        ];
+        for expected in requests {
+            let bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, SignRequest::read_le(&bytes[..]).unwrap());
+        }
+    }
+}
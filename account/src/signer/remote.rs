@@ -0,0 +1,247 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{LocalSigner, SignRequest, Signer};
+
+use snarkvm::{console::network::prelude::*, prelude::*};
+
+use nix::sys::stat::{umask, Mode};
+use std::{
+    io::{Read as _, Write as _},
+    os::unix::net::{UnixListener, UnixStream},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+/// The maximum size of a message exchanged with the signer, in bytes.
+const MAX_MESSAGE_SIZE: u32 = 1024;
+/// The maximum number of concurrent connections served by the signer.
+const MAX_CONNECTIONS: usize = 8;
+/// The timeout for a response from the signer.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The request for the address of the signer.
+const REQUEST_ADDRESS: u8 = 0;
+/// The request for a signature.
+const REQUEST_SIGN: u8 = 1;
+/// The response status for a successful request.
+const RESPONSE_OK: u8 = 0;
+/// The response status for a failed request, followed by the error message.
+const RESPONSE_ERROR: u8 = 1;
+
+/// Writes a length-prefixed message to the stream.
+fn write_message(stream: &mut UnixStream, message: &[u8]) -> Result<()> {
+    stream.write_all(&(message.len() as u32).to_le_bytes())?;
+    stream.write_all(message)?;
+    Ok(stream.flush()?)
+}
+
+/// Reads a length-prefixed message from the stream.
+fn read_message(stream: &mut UnixStream) -> Result<Vec<u8>> {
+    let mut length = [0u8; 4];
+    stream.read_exact(&mut length)?;
+    let length = u32::from_le_bytes(length);
+    ensure!(length <= MAX_MESSAGE_SIZE, "The signer message is too large ({length} bytes)");
+    let mut message = vec![0u8; length as usize];
+    stream.read_exact(&mut message)?;
+    Ok(message)
+}
+
+/// A signer that forwards requests to an external signer process over a Unix socket.
+///
+/// The external process is started with `SignerServer::run`, and holds the private key
+/// (and the double-sign guard), so the private key never enters the memory of the node.
+pub struct RemoteSigner<N: Network> {
+    /// The path to the Unix socket of the signer.
+    path: PathBuf,
+    /// The address of the signer.
+    address: Address<N>,
+    /// The connection to the signer.
+    stream: Mutex<UnixStream>,
+}
+
+impl<N: Network> RemoteSigner<N> {
+    /// Connects to the signer at the given Unix socket path.
+    pub fn connect<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        // Connect to the signer.
+        let mut stream = Self::open(&path)?;
+        // Request the address of the signer.
+        let address = Address::read_le(&Self::request(&mut stream, &[REQUEST_ADDRESS])?[..])?;
+        Ok(Self { path, address, stream: Mutex::new(stream) })
+    }
+
+    /// Opens a connection to the signer at the given path.
+    fn open(path: &Path) -> Result<UnixStream> {
+        let stream = UnixStream::connect(path)
+            .map_err(|e| anyhow!("Failed to connect to the signer at '{}' - {e}", path.display()))?;
+        stream.set_read_timeout(Some(RESPONSE_TIMEOUT))?;
+        stream.set_write_timeout(Some(RESPONSE_TIMEOUT))?;
+        Ok(stream)
+    }
+
+    /// Sends the given request to the signer, and returns the response body.
+    fn request(stream: &mut UnixStream, request: &[u8]) -> Result<Vec<u8>> {
+        write_message(stream, request)?;
+        let response = read_message(stream)?;
+        match response.split_first() {
+            Some((&RESPONSE_OK, body)) => Ok(body.to_vec()),
+            Some((&RESPONSE_ERROR, message)) => {
+                bail!("The signer refused the request - {}", String::from_utf8_lossy(message))
+            }
+            _ => bail!("The signer returned a malformed response"),
+        }
+    }
+}
+
+impl<N: Network> Signer<N> for RemoteSigner<N> {
+    /// Returns the address of the signer.
+    fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns a signature for the given request.
+    fn sign(&self, request: &SignRequest<N>) -> Result<Signature<N>> {
+        // Prepare the request.
+        let mut message = vec![REQUEST_SIGN];
+        request.write_le(&mut message)?;
+
+        let mut stream = self.stream.lock().map_err(|_| anyhow!("The signer connection is poisoned"))?;
+        // Send the request, reconnecting once if the connection was lost.
+        let response = match Self::request(&mut stream, &message) {
+            Ok(response) => response,
+            Err(error) if error.downcast_ref::<std::io::Error>().is_some() => {
+                *stream = Self::open(&self.path)?;
+                Self::request(&mut stream, &message)?
+            }
+            Err(error) => return Err(error),
+        };
+        Ok(Signature::read_le(&response[..])?)
+    }
+}
+
+/// The server side of a `RemoteSigner`, which runs in the external signer process.
+pub struct SignerServer<N: Network> {
+    /// The signer that holds the private key.
+    signer: Arc<LocalSigner<N>>,
+}
+
+impl<N: Network> SignerServer<N> {
+    /// Initializes a new signer server.
+    pub fn new(signer: LocalSigner<N>) -> Self {
+        Self { signer: Arc::new(signer) }
+    }
+
+    /// Listens on the given Unix socket path, and serves signature requests until an error occurs.
+    pub fn run<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        // Remove a stale socket from a previous run.
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        // Create the socket with owner-only (0600) permissions, so only the owner may request signatures.
+        let previous_umask = umask(Mode::from_bits_truncate(0o177));
+        let listener = UnixListener::bind(path);
+        umask(previous_umask);
+        let listener = listener?;
+
+        // The number of connections being served.
+        let num_connections = Arc::new(AtomicUsize::new(0));
+        for stream in listener.incoming() {
+            let mut stream = stream?;
+            // Ensure the number of connections is bounded, by dropping the connections over the limit.
+            if num_connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+                num_connections.fetch_sub(1, Ordering::SeqCst);
+                continue;
+            }
+            let signer = self.signer.clone();
+            let num_connections = num_connections.clone();
+            std::thread::spawn(move || {
+                // Serve the requests on this connection, until it is closed.
+                while let Ok(request) = read_message(&mut stream) {
+                    let response = match Self::handle(&signer, &request) {
+                        Ok(body) => [vec![RESPONSE_OK], body].concat(),
+                        Err(error) => [vec![RESPONSE_ERROR], error.to_string().into_bytes()].concat(),
+                    };
+                    if write_message(&mut stream, &response).is_err() {
+                        break;
+                    }
+                }
+                num_connections.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+        Ok(())
+    }
+
+    /// Handles the given request, and returns the response body.
+    fn handle(signer: &LocalSigner<N>, request: &[u8]) -> Result<Vec<u8>> {
+        match request.split_first() {
+            Some((&REQUEST_ADDRESS, [])) => Ok(signer.address().to_bytes_le()?),
+            Some((&REQUEST_SIGN, request)) => Ok(signer.sign(&SignRequest::read_le(request)?)?.to_bytes_le()?),
+            _ => bail!("Malformed request"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Account;
+    use snarkvm::{console::types::Field, prelude::Testnet3};
+
+    use std::os::unix::fs::PermissionsExt;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_remote_signer() {
+        let mut rng = TestRng::default();
+
+        // Start the signer server.
+        let account = Account::<CurrentNetwork>::new(&mut rng).unwrap();
+        let path = std::env::temp_dir().join(format!("snarkos-signer-{}.sock", rng.gen::<u64>()));
+        let server = SignerServer::new(LocalSigner::new(account.clone()));
+        let server_path = path.clone();
+        std::thread::spawn(move || server.run(server_path));
+
+        // Connect to the signer, retrying until the server is listening.
+        let signer = (0..50)
+            .find_map(|_| {
+                std::thread::sleep(Duration::from_millis(20));
+                RemoteSigner::<CurrentNetwork>::connect(&path).ok()
+            })
+            .unwrap();
+        assert_eq!(signer.address(), account.address());
+        // Ensure only the owner may connect to the socket.
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+        // Ensure the signatures are valid.
+        let batch_id = Field::rand(&mut rng);
+        let signature = signer.sign(&SignRequest::BatchHeader { round: 1, batch_id }).unwrap();
+        assert!(account.verify(&[batch_id], &signature));
+        let nonce = rng.gen::<u64>();
+        let signature = signer.sign(&SignRequest::Challenge { nonce }).unwrap();
+        assert!(account.verify_bytes(&nonce.to_le_bytes(), &signature));
+
+        // Ensure the double-sign protection is enforced by the server.
+        assert!(signer.sign(&SignRequest::BatchHeader { round: 1, batch_id: Field::rand(&mut rng) }).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
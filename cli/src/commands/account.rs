@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::helpers::{format_output, progress};
+
 use snarkvm::console::{
     account::PrivateKey,
     prelude::{Environment, Uniform},
     types::Field,
 };
 
+use snarkos_account::Mnemonic;
+#[cfg(target_family = "unix")]
+use snarkos_account::{DoubleSignGuard, LocalSigner, SignerServer};
+
 use anyhow::{anyhow, bail, Result};
 use clap::Parser;
 use colored::Colorize;
@@ -25,6 +31,9 @@ use core::str::FromStr;
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
 use rayon::prelude::*;
+use serde_json::json;
+#[cfg(target_family = "unix")]
+use std::path::{Path, PathBuf};
 
 type Network = snarkvm::prelude::Testnet3;
 
@@ -39,30 +48,102 @@ pub enum Account {
         /// Try until an address with the vanity string is found
         #[clap(short = 'v', long)]
         vanity: Option<String>,
+        /// Generate the account from a new mnemonic phrase
+        #[clap(short = 'm', long)]
+        mnemonic: bool,
+    },
+    /// Recovers an Aleo account from a mnemonic phrase
+    Recover {
+        /// The mnemonic phrase, as space-separated words
+        #[clap(short = 'm', long)]
+        mnemonic: String,
+        /// The optional passphrase for the mnemonic
+        #[clap(short = 'p', long, default_value = "")]
+        passphrase: String,
+        /// The index of the account to derive from the mnemonic
+        #[clap(short = 'i', long, default_value = "0")]
+        index: u32,
+    },
+    /// Runs a remote signer for a validator on a Unix socket
+    #[cfg(target_family = "unix")]
+    Signer {
+        /// The path of the Unix socket to listen on
+        #[clap(long)]
+        socket: PathBuf,
+        /// The path to a file containing the account private key
+        #[clap(long = "private-key-file")]
+        private_key_file: PathBuf,
+        /// The path to the file where the double-sign protection state is stored
+        #[clap(long)]
+        guard: PathBuf,
     },
 }
 
 impl Account {
     pub fn parse(self) -> Result<String> {
         match self {
-            Self::New { seed, vanity } => {
+            Self::New { seed, vanity, mnemonic } => {
                 // Ensure only the seed or the vanity string is specified.
                 if seed.is_some() && vanity.is_some() {
                     bail!("Cannot specify both the '--seed' and '--vanity' flags");
                 }
+                // Ensure the mnemonic is not specified with the seed or the vanity string.
+                if mnemonic && (seed.is_some() || vanity.is_some()) {
+                    bail!("Cannot specify the '--mnemonic' flag with the '--seed' or '--vanity' flags");
+                }
 
                 // Generate a vanity account.
                 if let Some(vanity) = vanity {
                     Self::new_vanity(&vanity)
                 }
+                // Generate an account from a new mnemonic.
+                else if mnemonic {
+                    Self::new_mnemonic()
+                }
                 // Default to generating a normal account, with an optional seed.
                 else {
                     Self::new_seeded(seed)
                 }
             }
+            Self::Recover { mnemonic, passphrase, index } => Self::recover(&mnemonic, &passphrase, index),
+            #[cfg(target_family = "unix")]
+            Self::Signer { socket, private_key_file, guard } => Self::run_signer(&socket, &private_key_file, &guard),
         }
     }
 
+    /// Runs a remote signer with the private key from the given file, until an error occurs.
+    #[cfg(target_family = "unix")]
+    fn run_signer(socket: &Path, private_key_file: &Path, guard: &Path) -> Result<String> {
+        // Load the account.
+        let account = snarkos_account::Account::<Network>::from_str(std::fs::read_to_string(private_key_file)?.trim())?;
+        // Load the double-sign guard.
+        let guard = DoubleSignGuard::open(guard)?;
+        // Start the signer.
+        progress!("🔑 Serving signatures for {} at '{}'\n", account.address().to_string().bold(), socket.display());
+        SignerServer::new(LocalSigner::with_guard(account, guard)).run(socket)?;
+        Ok(String::new())
+    }
+
+    /// Generates a new Aleo account from a new mnemonic.
+    fn new_mnemonic() -> Result<String> {
+        // Sample a new mnemonic.
+        let mnemonic = Mnemonic::new(Mnemonic::DEFAULT_WORD_COUNT, &mut ChaChaRng::from_entropy())?;
+        // Derive the first account from the mnemonic.
+        let account = snarkos_account::Account::<Network>::from_mnemonic(&mnemonic, "", 0)?;
+        // Print the mnemonic and the new Aleo account.
+        Self::output_account(&account, Some(&mnemonic))
+    }
+
+    /// Recovers the Aleo account at the given index from a mnemonic.
+    fn recover(mnemonic: &str, passphrase: &str, index: u32) -> Result<String> {
+        // Parse the mnemonic.
+        let mnemonic = Mnemonic::from_str(mnemonic)?;
+        // Derive the account from the mnemonic.
+        let account = snarkos_account::Account::<Network>::from_mnemonic(&mnemonic, passphrase, index)?;
+        // Print the recovered Aleo account.
+        Self::output_account(&account, None)
+    }
+
     /// Generates a new Aleo account with the given vanity string.
     fn new_vanity(vanity: &str) -> Result<String> {
         // A closure to generate a new Aleo account.
@@ -83,7 +164,7 @@ impl Account {
         if vanity.len() > 4 {
             let message =
                 format!(" The vanity string '{vanity}' contains 5 or more characters and will take a while to find.\n");
-            println!("{}", message.yellow());
+            progress!("{}", message.yellow());
         }
 
         loop {
@@ -111,12 +192,12 @@ impl Account {
 
             // Return the result if a candidate was found.
             if let Some(account) = account {
-                println!(); // Add a newline for formatting.
-                return Ok(account.to_string());
+                progress!(); // Add a newline for formatting.
+                return Self::output_account(&account, None);
             } else {
                 let rate = ITERATIONS / timer.elapsed().as_millis();
                 let rate = format!("[{rate} a/ms]");
-                println!(" {} Sampled {ITERATIONS_STR} accounts, searching...", rate.dimmed());
+                progress!(" {} Sampled {ITERATIONS_STR} accounts, searching...", rate.dimmed());
             }
         }
     }
@@ -138,7 +219,21 @@ impl Account {
         // Construct the account.
         let account = snarkos_account::Account::<Network>::try_from(private_key)?;
         // Print the new Aleo account.
-        Ok(account.to_string())
+        Self::output_account(&account, None)
+    }
+
+    /// Returns the output for the given account, and the mnemonic it was derived from.
+    fn output_account(account: &snarkos_account::Account<Network>, mnemonic: Option<&Mnemonic>) -> Result<String> {
+        let output = json!({
+            "private_key": account.private_key().to_string(),
+            "view_key": account.view_key().to_string(),
+            "address": account.address().to_string(),
+            "mnemonic": mnemonic.map(|mnemonic| mnemonic.to_string()),
+        });
+        format_output(&output, || match mnemonic {
+            Some(mnemonic) => format!(" {:>12}  {mnemonic}\n{account}", "Mnemonic".cyan().bold()),
+            None => account.to_string(),
+        })
     }
 }
 
@@ -151,11 +246,35 @@ mod tests {
     #[test]
     fn test_new() {
         for _ in 0..3 {
-            let account = Account::New { seed: None, vanity: None };
+            let account = Account::New { seed: None, vanity: None, mnemonic: false };
             assert!(account.parse().is_ok());
         }
     }
 
+    #[test]
+    fn test_new_mnemonic() {
+        let account = Account::New { seed: None, vanity: None, mnemonic: true };
+        assert!(account.parse().is_ok());
+
+        // Ensure the mnemonic cannot be combined with a seed.
+        let account = Account::New { seed: Some("1".to_string()), vanity: None, mnemonic: true };
+        assert!(account.parse().is_err());
+    }
+
+    #[test]
+    fn test_recover() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        // Ensure recovery is deterministic.
+        let recover = |index| Account::Recover { mnemonic: mnemonic.to_string(), passphrase: String::new(), index };
+        assert_eq!(recover(0).parse().unwrap(), recover(0).parse().unwrap());
+        assert_ne!(recover(0).parse().unwrap(), recover(1).parse().unwrap());
+
+        // Ensure an invalid mnemonic is rejected.
+        let account = Account::Recover { mnemonic: "aleo".to_string(), passphrase: String::new(), index: 0 };
+        assert!(account.parse().is_err());
+    }
+
     #[test]
     fn test_new_seeded() {
         let seed = Some(1231275789u64.to_string());
@@ -177,7 +296,7 @@ mod tests {
         );
 
         let vanity = None;
-        let account = Account::New { seed, vanity };
+        let account = Account::New { seed, vanity, mnemonic: false };
         let actual = account.parse().unwrap();
         assert_eq!(expected, actual);
     }
@@ -203,7 +322,7 @@ mod tests {
         );
 
         let vanity = None;
-        let account = Account::New { seed, vanity };
+        let account = Account::New { seed, vanity, mnemonic: false };
         let actual = account.parse().unwrap();
         assert_eq!(expected, actual);
     }
@@ -12,9 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use anyhow::{bail, Result};
+use super::ledger_leftovers;
+use crate::helpers::format_output;
+
+use snarkos_node::{bft::storage_service::BFTPersistentStorage, PEER_BOOK_EXTENSION};
+use snarkvm::prelude::Network;
+
+use anyhow::{bail, ensure, Result};
 use clap::Parser;
 use colored::Colorize;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+type CurrentNetwork = snarkvm::prelude::Testnet3;
 
 /// Cleans the snarkOS node storage.
 #[derive(Debug, Parser)]
@@ -25,34 +35,210 @@ pub struct Clean {
     /// Enables development mode, specify the unique ID of the local node to clean.
     #[clap(long)]
     pub dev: Option<u16>,
+    /// Only remove the BFT transmissions, and keep the ledger.
+    #[clap(long, conflicts_with_all = ["ledger_only", "peers_only"])]
+    pub bft_only: bool,
+    /// Only remove the ledger, and keep the leftovers of interrupted rollbacks and snapshot restores, and the peer book.
+    #[clap(long, conflicts_with = "peers_only")]
+    pub ledger_only: bool,
+    /// Only remove the peer book, and keep the ledger.
+    #[clap(long)]
+    pub peers_only: bool,
+    /// Print what would be removed and its size, without removing anything.
+    #[clap(long)]
+    pub dry_run: bool,
+    /// Specify the path of the ledger storage, if it is not in the default location.
+    #[clap(long)]
+    pub path: Option<PathBuf>,
 }
 
 impl Clean {
     /// Cleans the snarkOS node storage.
     pub fn parse(self) -> Result<String> {
-        // Remove the specified ledger from storage.
-        Self::remove_ledger(self.network, self.dev)
+        // Remove the BFT transmissions from storage.
+        if self.bft_only {
+            return Self::remove_bft(self.network, self.dev, self.path, self.dry_run);
+        }
+        let ledger_dir = self.path.unwrap_or_else(|| aleo_std::aleo_ledger_dir(self.network, self.dev));
+        let peer_book = ledger_dir.with_extension(PEER_BOOK_EXTENSION);
+        // Remove the peer book from storage.
+        if self.peers_only {
+            return Self::remove_paths(&[peer_book], self.dry_run);
+        }
+        // Remove the specified ledger from storage, along with its leftovers and the peer book.
+        let mut paths = vec![ledger_dir.clone()];
+        if !self.ledger_only {
+            paths.extend(ledger_leftovers(&ledger_dir));
+            paths.push(peer_book);
+        }
+        Self::remove_paths(&paths, self.dry_run)
     }
 
     /// Removes the specified ledger from storage.
     pub(crate) fn remove_ledger(network: u16, dev: Option<u16>) -> Result<String> {
-        // Construct the path to the ledger in storage.
-        let path = aleo_std::aleo_ledger_dir(network, dev);
+        Self::remove_paths(&[aleo_std::aleo_ledger_dir(network, dev)], false)
+    }
 
-        // Prepare the path string.
-        let path_string = format!("(in \"{}\")", path.display()).dimmed();
+    /// Removes the given paths from storage, or only reports their size in a dry run.
+    /// The first path is the main one (e.g. the ledger), which is reported if no path is found.
+    fn remove_paths(paths: &[PathBuf], dry_run: bool) -> Result<String> {
+        let mut removed = Vec::new();
+        let mut lines = Vec::new();
+        for path in paths.iter().filter(|path| path.exists()) {
+            // Prepare the path string.
+            let size = Self::size_of(path)?;
+            let path_string = format!("(in \"{}\", {})", path.display(), Self::format_size(size)).dimmed();
 
-        // Check if the path to the ledger exists in storage.
-        if path.exists() {
-            // Remove the ledger files from storage.
-            match std::fs::remove_dir_all(&path) {
-                Ok(_) => Ok(format!("✅ Cleaned the snarkOS node storage {path_string}")),
-                Err(error) => {
+            if dry_run {
+                lines.push(format!("🔍 Would clean the snarkOS node storage {path_string}"));
+            } else {
+                // Remove the files from storage.
+                let result = match path.is_dir() {
+                    true => std::fs::remove_dir_all(path),
+                    false => std::fs::remove_file(path),
+                };
+                if let Err(error) = result {
                     bail!("Failed to remove the snarkOS node storage {path_string}\n{}", error.to_string().dimmed())
                 }
+                lines.push(format!("✅ Cleaned the snarkOS node storage {path_string}"));
             }
+            removed.push(json!({ "path": path, "size": size }));
+        }
+        if lines.is_empty() {
+            let path_string = paths.first().map(|path| format!("(in \"{}\")", path.display())).unwrap_or_default();
+            lines.push(format!("✅ No snarkOS node storage was found {}", path_string.dimmed()));
+        }
+
+        format_output(&json!({ "dry_run": dry_run, "removed": removed }), || lines.join("\n"))
+    }
+
+    /// Removes the BFT transmissions from the ledger storage, or only counts them in a dry run.
+    ///
+    /// The BFT storage is opened in the ledger of the given network and dev ID, so a given path
+    /// must refer to that ledger.
+    fn remove_bft(network: u16, dev: Option<u16>, custom_path: Option<PathBuf>, dry_run: bool) -> Result<String> {
+        ensure!(network == CurrentNetwork::ID, "The BFT storage of network {network} is not supported");
+        // Construct the path to the ledger in storage, which also holds the BFT transmissions.
+        let path = aleo_std::aleo_ledger_dir(network, dev);
+        if let Some(custom_path) = custom_path {
+            ensure!(
+                Self::is_same_path(&custom_path, &path),
+                "The BFT storage in '{}' can only be cleaned as the ledger of network {network}{} in '{}'",
+                custom_path.display(),
+                dev.map(|id| format!(" (dev {id})")).unwrap_or_default(),
+                path.display()
+            );
+        }
+        let path_string = format!("(in \"{}\")", path.display()).dimmed();
+        if !path.exists() {
+            return format_output(&json!({ "dry_run": dry_run, "path": path, "transmissions": 0 }), || {
+                format!("✅ No snarkOS node storage was found {path_string}")
+            });
+        }
+
+        // Open the BFT storage, which fails if the node is running.
+        let storage = BFTPersistentStorage::<CurrentNetwork>::open(dev)?;
+        let transmissions = match dry_run {
+            true => storage.num_transmissions(),
+            false => storage.clear()?,
+        };
+        format_output(&json!({ "dry_run": dry_run, "path": path, "transmissions": transmissions }), || match dry_run {
+            true => format!("🔍 Would clean {transmissions} BFT transmissions {path_string}"),
+            false => format!("✅ Cleaned {transmissions} BFT transmissions {path_string}"),
+        })
+    }
+
+    /// Returns `true` if the given paths refer to the same file or directory.
+    fn is_same_path(a: &Path, b: &Path) -> bool {
+        match (a.canonicalize(), b.canonicalize()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => a == b,
+        }
+    }
+
+    /// Returns the total size of the files at the given path, in bytes.
+    fn size_of(path: &Path) -> Result<u64> {
+        let metadata = std::fs::symlink_metadata(path)?;
+        if !metadata.is_dir() {
+            return Ok(metadata.len());
+        }
+        let mut size = 0;
+        for entry in std::fs::read_dir(path)? {
+            size += Self::size_of(&entry?.path())?;
+        }
+        Ok(size)
+    }
+
+    /// Formats the given number of bytes for display.
+    fn format_size(bytes: u64) -> String {
+        const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+        let mut size = bytes as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        match unit {
+            0 => format!("{bytes} B"),
+            _ => format!("{size:.1} {}", UNITS[unit]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+
+    #[test]
+    fn clap_snarkos_clean() {
+        let cli = CLI::parse_from(["snarkos", "clean", "--dev", "1", "--ledger-only", "--dry-run"]);
+        if let Command::Clean(clean) = cli.command {
+            assert_eq!(clean.dev, Some(1));
+            assert!(!clean.bft_only);
+            assert!(clean.ledger_only);
+            assert!(!clean.peers_only);
+            assert!(clean.dry_run);
+            assert_eq!(clean.path, None);
         } else {
-            Ok(format!("✅ No snarkOS node storage was found {path_string}"))
+            panic!("Unexpected result of clap parsing!");
         }
+
+        // Ensure only one part of the storage is selected.
+        assert!(CLI::try_parse_from(["snarkos", "clean", "--bft-only", "--ledger-only"]).is_err());
+        assert!(CLI::try_parse_from(["snarkos", "clean", "--bft-only", "--peers-only"]).is_err());
+        assert!(CLI::try_parse_from(["snarkos", "clean", "--ledger-only", "--peers-only"]).is_err());
+        // Ensure a custom path is accepted for every part of the storage.
+        assert!(CLI::try_parse_from(["snarkos", "clean", "--bft-only", "--path", "ledger"]).is_ok());
+        assert!(CLI::try_parse_from(["snarkos", "clean", "--peers-only", "--path", "ledger"]).is_ok());
+    }
+
+    #[test]
+    fn test_remove_paths() {
+        let root = std::env::temp_dir().join(format!("snarkos-clean-{}", std::process::id()));
+        let ledger_dir = root.join("ledger-3");
+        std::fs::create_dir_all(ledger_dir.join("nested")).unwrap();
+        std::fs::write(ledger_dir.join("000001.sst"), [0u8; 1000]).unwrap();
+        std::fs::write(ledger_dir.join("nested").join("MANIFEST"), [0u8; 24]).unwrap();
+        let leftovers = ledger_leftovers(&ledger_dir);
+        std::fs::create_dir_all(&leftovers[0]).unwrap();
+        assert_eq!(Clean::size_of(&ledger_dir).unwrap(), 1024);
+
+        // Ensure a dry run keeps the files.
+        let paths = [ledger_dir.clone(), leftovers[0].clone()];
+        Clean::remove_paths(&paths, true).unwrap();
+        assert!(ledger_dir.exists() && leftovers[0].exists());
+
+        // Ensure the files are removed otherwise.
+        Clean::remove_paths(&paths, false).unwrap();
+        assert!(!ledger_dir.exists() && !leftovers[0].exists());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(Clean::format_size(512), "512 B");
+        assert_eq!(Clean::format_size(1536), "1.5 KB");
+        assert_eq!(Clean::format_size(3 * 1024 * 1024 * 1024), "3.0 GB");
     }
 }
@@ -0,0 +1,422 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentAleo, CurrentNetwork, Developer};
+use crate::helpers::progress;
+
+use snarkvm::{
+    console::program::ProgramOwner,
+    prelude::{
+        block::{Deployment, Transaction},
+        deployment_cost,
+        query::Query,
+        store::{
+            helpers::memory::{BlockMemory, ConsensusMemory},
+            ConsensusStore,
+        },
+        Authorization,
+        CryptoRng,
+        Field,
+        Identifier,
+        Locator,
+        PrivateKey,
+        Program,
+        ProgramID,
+        Rng,
+        Value,
+        VM,
+    },
+};
+
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, str::FromStr};
+
+/// A signed execution or deployment, together with the signed fee,
+/// which may be proven into a transaction without the private key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SignedAuthorization {
+    /// A signed request to execute a function, together with the signed fee over the execution ID.
+    Execute {
+        /// The authorization of the function execution.
+        authorization: Authorization<CurrentNetwork>,
+        /// The authorization of the fee.
+        fee_authorization: Authorization<CurrentNetwork>,
+    },
+    /// A program deployment with the signature of its owner, together with the signed fee over the deployment ID.
+    Deploy {
+        /// The deployment of the program.
+        deployment: Deployment<CurrentNetwork>,
+        /// The owner of the program, who signed the deployment ID.
+        owner: ProgramOwner<CurrentNetwork>,
+        /// The authorization of the fee.
+        fee_authorization: Authorization<CurrentNetwork>,
+    },
+}
+
+impl SignedAuthorization {
+    /// Returns the name of the authorized function, or of the deployed program.
+    pub fn name(&self) -> Result<String> {
+        match self {
+            Self::Execute { authorization, .. } => {
+                let Some(request) = authorization.to_vec_deque().front().cloned() else {
+                    bail!("The authorization does not contain a request");
+                };
+                Ok(format!("{}/{}", request.program_id(), request.function_name()))
+            }
+            Self::Deploy { deployment, .. } => Ok(deployment.program_id().to_string()),
+        }
+    }
+
+    /// Proves the signed authorization into a transaction, using the given query for the state root.
+    /// For an execution, the executed programs and their imports must be loaded in the VM.
+    pub fn prove<R: Rng + CryptoRng>(
+        self,
+        vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
+        query: Query<CurrentNetwork, BlockMemory<CurrentNetwork>>,
+        rng: &mut R,
+    ) -> Result<Transaction<CurrentNetwork>> {
+        match self {
+            Self::Execute { authorization, fee_authorization } => {
+                vm.execute_authorization(authorization, Some(fee_authorization), Some(query), rng)
+            }
+            Self::Deploy { deployment, owner, fee_authorization } => {
+                let fee = vm.execute_fee_authorization(fee_authorization, Some(query), rng)?;
+                Transaction::from_deployment(owner, deployment, fee)
+            }
+        }
+    }
+}
+
+/// Authorizes an Aleo program function, or the deployment of a program, offline without querying the network.
+#[derive(Debug, Parser)]
+pub struct Authorize {
+    /// The program identifier.
+    program_id: ProgramID<CurrentNetwork>,
+    /// The function name.
+    #[clap(required_unless_present = "deploy")]
+    function: Option<Identifier<CurrentNetwork>>,
+    /// The function inputs.
+    #[clap(conflicts_with = "deploy")]
+    inputs: Vec<Value<CurrentNetwork>>,
+    /// Authorizes the deployment of the program, which must be loaded with '--program', instead of an execution.
+    #[clap(long, conflicts_with = "function")]
+    deploy: bool,
+    /// The private key used to authorize the execution or deployment.
+    #[clap(short, long)]
+    private_key: String,
+    /// The path to a program (or import) to load, in the order of the imports. Not needed for `credits.aleo`.
+    #[clap(long = "program")]
+    programs: Vec<PathBuf>,
+    /// The base fee in microcredits. Required for executions, as their cost is only known once proven;
+    /// for deployments, defaults to the deployment cost.
+    #[clap(long, required_unless_present = "deploy")]
+    base_fee: Option<u64>,
+    /// The priority fee in microcredits.
+    #[clap(long)]
+    priority_fee: Option<u64>,
+    /// The record to spend the fee from. If not set, the fee is paid from the public balance.
+    #[clap(long)]
+    fee_record: Option<String>,
+    /// The path to store the signed authorization. If not set, the authorization is displayed.
+    #[clap(long)]
+    out: Option<PathBuf>,
+}
+
+impl Authorize {
+    /// Authorizes an Aleo program function with the provided inputs, or the deployment of the program.
+    pub fn parse(self) -> Result<String> {
+        // Retrieve the private key.
+        let private_key = PrivateKey::from_str(&self.private_key)?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Initialize the VM.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None)?;
+        let vm = VM::from(store)?;
+
+        // Load the programs into the process, except for the program to deploy.
+        let mut program_to_deploy = None;
+        for path in &self.programs {
+            let program = Program::<CurrentNetwork>::from_str(&std::fs::read_to_string(path)?)?;
+            if self.deploy && program.id() == &self.program_id {
+                program_to_deploy = Some(program);
+            } else if !vm.process().read().contains_program(program.id()) {
+                vm.process().write().add_program(&program)?;
+            }
+        }
+
+        // Generate the signed authorization.
+        let signed = match (self.deploy, &self.function) {
+            (true, _) => {
+                let Some(program) = program_to_deploy else {
+                    bail!("The program '{}' is not loaded, please specify it with '--program'", self.program_id)
+                };
+                progress!("🔏 Authorizing the deployment of '{}'...\n", &self.program_id.to_string().bold());
+
+                // Synthesize the deployment, which does not require the private key.
+                let deployment = vm.process().read().deploy::<CurrentAleo, _>(&program, rng)?;
+                // Compute the deployment ID, which the owner and the fee commit to.
+                let deployment_id = deployment.to_deployment_id()?;
+                // Sign the deployment ID as the program owner.
+                let owner = ProgramOwner::new(&private_key, deployment_id, rng)?;
+                // Determine the base fee, which must cover the deployment cost.
+                let base_fee = match self.base_fee {
+                    Some(base_fee) => base_fee,
+                    None => deployment_cost(&deployment)?.0,
+                };
+                // Authorize the fee.
+                let fee_authorization = self.authorize_fee(&vm, &private_key, base_fee, deployment_id, rng)?;
+
+                SignedAuthorization::Deploy { deployment, owner, fee_authorization }
+            }
+            (false, Some(function)) => {
+                ensure!(
+                    vm.process().read().contains_program(&self.program_id),
+                    "The program '{}' is not loaded, please specify it with '--program'",
+                    self.program_id
+                );
+                let locator = Locator::<CurrentNetwork>::new(self.program_id, *function);
+                progress!("🔏 Authorizing '{}'...\n", &locator.to_string().bold());
+
+                // Authorize the execution.
+                let authorization = vm.authorize(&private_key, self.program_id, *function, self.inputs.iter(), rng)?;
+                // Compute the execution ID, which the fee commits to.
+                let execution_id = authorization.to_execution_id()?;
+                // Retrieve the base fee, which must cover the storage and finalize cost of the execution.
+                let Some(base_fee) = self.base_fee else {
+                    bail!("Please specify the base fee of the execution with '--base-fee'")
+                };
+                // Authorize the fee.
+                let fee_authorization = self.authorize_fee(&vm, &private_key, base_fee, execution_id, rng)?;
+
+                SignedAuthorization::Execute { authorization, fee_authorization }
+            }
+            (false, None) => bail!("Please specify the function to authorize, or '--deploy'"),
+        };
+
+        progress!("✅ Authorized '{}'", signed.name()?.bold());
+
+        // Store or display the signed authorization.
+        let signed = serde_json::to_string_pretty(&signed)?;
+        match self.out {
+            Some(path) => {
+                if path.exists() {
+                    bail!("The file '{}' already exists", path.display());
+                }
+                std::fs::write(&path, signed)?;
+                Ok(format!("The signed authorization was stored to {}", path.display()))
+            }
+            None => Ok(signed),
+        }
+    }
+
+    /// Authorizes the fee over the given execution or deployment ID, from the fee record or the public balance.
+    fn authorize_fee<R: Rng + CryptoRng>(
+        &self,
+        vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        base_fee: u64,
+        id: Field<CurrentNetwork>,
+        rng: &mut R,
+    ) -> Result<Authorization<CurrentNetwork>> {
+        let priority_fee = self.priority_fee.unwrap_or(0);
+        match &self.fee_record {
+            Some(record) => vm.authorize_fee_private(
+                private_key,
+                Developer::parse_record(private_key, record)?,
+                base_fee,
+                priority_fee,
+                id,
+                rng,
+            ),
+            None => vm.authorize_fee_public(private_key, base_fee, priority_fee, id, rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+    use snarkvm::prelude::{Address, Ledger, TestRng, Uniform};
+
+    type CurrentLedger = Ledger<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
+
+    /// Returns a private key, and a ledger whose genesis block funds the private key.
+    fn sample_ledger(rng: &mut TestRng) -> (PrivateKey<CurrentNetwork>, CurrentLedger) {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let vm =
+            VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap()).unwrap();
+        let genesis = vm.genesis_beacon(&private_key, rng).unwrap();
+        (private_key, CurrentLedger::load(genesis, None).unwrap())
+    }
+
+    /// Deserializes the signed authorization, proves it against the ledger, and checks the transaction.
+    fn prove_and_check(ledger: &CurrentLedger, signed: &str, rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+        let signed: SignedAuthorization = serde_json::from_str(signed).unwrap();
+        let query = Query::from(ledger.vm().block_store().clone());
+        let transaction = signed.prove(ledger.vm(), query, rng).unwrap();
+        ledger.check_transaction_basic(&transaction, None, rng).unwrap();
+        transaction
+    }
+
+    #[test]
+    fn clap_snarkos_authorize() {
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "authorize",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--program",
+            "imports/token.aleo",
+            "--program",
+            "main.aleo",
+            "--base-fee",
+            "5000",
+            "--out",
+            "authorization.json",
+            "hello.aleo",
+            "hello",
+            "1u32",
+            "2u32",
+        ];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Developer(Developer::Authorize(authorize)) = cli.command {
+            assert_eq!(authorize.private_key, "PRIVATE_KEY");
+            assert_eq!(authorize.programs, vec![PathBuf::from("imports/token.aleo"), PathBuf::from("main.aleo")]);
+            assert_eq!(authorize.base_fee, Some(5000));
+            assert_eq!(authorize.priority_fee, None);
+            assert_eq!(authorize.out, Some(PathBuf::from("authorization.json")));
+            assert_eq!(authorize.program_id, "hello.aleo".try_into().unwrap());
+            assert_eq!(authorize.function, Some("hello".try_into().unwrap()));
+            assert_eq!(authorize.inputs, vec!["1u32".try_into().unwrap(), "2u32".try_into().unwrap()]);
+            assert!(!authorize.deploy);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn clap_snarkos_authorize_deploy() {
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "authorize",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--program",
+            "main.aleo",
+            "--deploy",
+            "hello.aleo",
+        ];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Developer(Developer::Authorize(authorize)) = cli.command {
+            assert!(authorize.deploy);
+            assert_eq!(authorize.function, None);
+            assert_eq!(authorize.base_fee, None);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn clap_snarkos_authorize_requires_base_fee() {
+        // An execution requires the base fee, as it is not proven to compute its cost.
+        let arg_vec = vec!["snarkos", "developer", "authorize", "--private-key", "PRIVATE_KEY", "hello.aleo", "hello"];
+        assert!(CLI::try_parse_from(arg_vec).is_err());
+        // A deployment does not take a function or inputs.
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "authorize",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--deploy",
+            "hello.aleo",
+            "hello",
+        ];
+        assert!(CLI::try_parse_from(arg_vec).is_err());
+    }
+
+    #[test]
+    fn test_authorize_and_prove_execution() {
+        let rng = &mut TestRng::default();
+        let (private_key, ledger) = sample_ledger(rng);
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Authorize a public transfer offline.
+        let authorize = Authorize::try_parse_from([
+            "authorize",
+            "--private-key",
+            &private_key.to_string(),
+            "--base-fee",
+            "1000000",
+            "credits.aleo",
+            "transfer_public",
+            &recipient.to_string(),
+            "1u64",
+        ])
+        .unwrap();
+        let signed = authorize.parse().unwrap();
+
+        // Prove the signed authorization, without the private key.
+        let transaction = prove_and_check(&ledger, &signed, rng);
+        assert!(transaction.is_execute());
+    }
+
+    #[test]
+    fn test_authorize_and_prove_deployment() {
+        let rng = &mut TestRng::default();
+        let (private_key, ledger) = sample_ledger(rng);
+
+        // Write a program to deploy.
+        let program = r"
+program authorize_test.aleo;
+
+function hello:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+        let path = std::env::temp_dir().join(format!("snarkos-authorize-{}.aleo", u64::rand(rng)));
+        std::fs::write(&path, program).unwrap();
+
+        // Authorize the deployment offline.
+        let authorize = Authorize::try_parse_from([
+            "authorize",
+            "--private-key",
+            &private_key.to_string(),
+            "--program",
+            path.to_str().unwrap(),
+            "--deploy",
+            "authorize_test.aleo",
+        ])
+        .unwrap();
+        let signed = authorize.parse();
+        std::fs::remove_file(&path).unwrap();
+
+        // Prove the signed authorization, without the private key.
+        let transaction = prove_and_check(&ledger, &signed.unwrap(), rng);
+        assert!(transaction.is_deploy());
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentNetwork, Developer};
+
+use snarkvm::prelude::Address;
+
+use anyhow::Result;
+use clap::Parser;
+
+/// The number of microcredits in one credit.
+const MICROCREDITS_PER_CREDIT: u64 = 1_000_000;
+
+/// Queries the public balance of an address in `credits.aleo/account`.
+#[derive(Debug, Parser)]
+pub struct Balance {
+    /// The address to query the public balance of.
+    #[clap(short, long)]
+    address: Address<CurrentNetwork>,
+    /// The endpoint to query node state from.
+    #[clap(short, long)]
+    query: String,
+}
+
+impl Balance {
+    /// Fetches the public balance of the address.
+    pub fn parse(self) -> Result<String> {
+        // Fetch the public balance in microcredits.
+        let balance = Developer::get_public_balance(&self.address, &self.query)?;
+        // Output the balance.
+        Ok(format!(
+            "💰 The public balance of {} is {balance} microcredits ({} credits)",
+            self.address,
+            format_credits(balance)
+        ))
+    }
+}
+
+/// Formats the given amount of microcredits as credits.
+fn format_credits(microcredits: u64) -> String {
+    let (credits, remainder) = (microcredits / MICROCREDITS_PER_CREDIT, microcredits % MICROCREDITS_PER_CREDIT);
+    match remainder {
+        0 => credits.to_string(),
+        _ => format!("{credits}.{}", format!("{remainder:06}").trim_end_matches('0')),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_credits() {
+        assert_eq!(format_credits(0), "0");
+        assert_eq!(format_credits(1), "0.000001");
+        assert_eq!(format_credits(1_500_000), "1.5");
+        assert_eq!(format_credits(10_000_000), "10");
+        assert_eq!(format_credits(123_456_789), "123.456789");
+    }
+}
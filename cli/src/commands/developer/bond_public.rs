@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ensure_nonzero_amount, CreditsOptions, CurrentNetwork};
+use crate::helpers::progress;
+
+use snarkvm::prelude::{Address, Value};
+
+use anyhow::Result;
+use clap::Parser;
+use std::str::FromStr;
+
+/// Executes the `bond_public` function in the `credits.aleo` program.
+#[derive(Debug, Parser)]
+pub struct BondPublic {
+    /// The address of the validator to bond to.
+    #[clap(long)]
+    validator: Address<CurrentNetwork>,
+    /// The number of microcredits to bond.
+    #[clap(long)]
+    amount: u64,
+    /// The options for the transaction.
+    #[clap(flatten)]
+    options: CreditsOptions,
+}
+
+impl BondPublic {
+    /// Creates a bond to the validator with the provided inputs.
+    pub fn parse(self) -> Result<String> {
+        // Validate the inputs.
+        self.options.ensure_action()?;
+        ensure_nonzero_amount(self.amount)?;
+
+        progress!("📦 Creating bond of {} microcredits to {}...\n", self.amount, self.validator);
+
+        // Prepare the inputs for the bond.
+        let inputs =
+            vec![Value::from_str(&format!("{}", self.validator))?, Value::from_str(&format!("{}u64", self.amount))?];
+        // Create the transaction, and broadcast, store, or display it.
+        self.options.execute("bond_public", inputs, self.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, Developer, CLI};
+
+    #[test]
+    fn clap_snarkos_bond_public() {
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "bond-public",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--query",
+            "QUERY",
+            "--validator",
+            "aleo1uxl69laseuv3876ksh8k0nd7tvpgjt6ccrgccedpjk9qwyfensxst9ftg5",
+            "--amount",
+            "1000000",
+            "--fee-record",
+            "RECORD",
+            "--broadcast",
+            "ENDPOINT",
+        ];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Developer(Developer::BondPublic(bond)) = cli.command {
+            assert_eq!(bond.validator.to_string(), "aleo1uxl69laseuv3876ksh8k0nd7tvpgjt6ccrgccedpjk9qwyfensxst9ftg5");
+            assert_eq!(bond.amount, 1000000);
+            assert_eq!(bond.options.fee_record, Some("RECORD".into()));
+            assert_eq!(bond.options.broadcast, Some("ENDPOINT".into()));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        // Ensure '--broadcast' and '--dry-run' conflict.
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "bond-public",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--query",
+            "QUERY",
+            "--validator",
+            "aleo1uxl69laseuv3876ksh8k0nd7tvpgjt6ccrgccedpjk9qwyfensxst9ftg5",
+            "--amount",
+            "1000000",
+            "--broadcast",
+            "ENDPOINT",
+            "--dry-run",
+        ];
+        assert!(CLI::try_parse_from(arg_vec).is_err());
+    }
+}
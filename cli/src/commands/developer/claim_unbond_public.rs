@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::CreditsOptions;
+use crate::helpers::progress;
+
+use anyhow::Result;
+use clap::Parser;
+
+/// Executes the `claim_unbond_public` function in the `credits.aleo` program.
+#[derive(Debug, Parser)]
+pub struct ClaimUnbondPublic {
+    /// The options for the transaction.
+    #[clap(flatten)]
+    options: CreditsOptions,
+}
+
+impl ClaimUnbondPublic {
+    /// Creates a claim of the unbonded microcredits.
+    pub fn parse(self) -> Result<String> {
+        // Validate the inputs.
+        self.options.ensure_action()?;
+
+        progress!("📦 Creating claim of unbonded microcredits...\n");
+
+        // Create the transaction, and broadcast, store, or display it.
+        self.options.execute("claim_unbond_public", vec![], 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, Developer, CLI};
+
+    #[test]
+    fn clap_snarkos_claim_unbond_public() {
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "claim-unbond-public",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--query",
+            "QUERY",
+            "--store",
+            "transaction.json",
+        ];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Developer(Developer::ClaimUnbondPublic(claim)) = cli.command {
+            assert_eq!(claim.options.private_key, "PRIVATE_KEY");
+            assert_eq!(claim.options.query, "QUERY");
+            assert_eq!(claim.options.store, Some("transaction.json".into()));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_claim_unbond_public_validation() {
+        // Ensure an action is required.
+        let args =
+            ["snarkos", "--private-key", "APrivateKey1zkp2n22c19hNdGF8wuEoQcuiyuWbquY6up4CtG5DYKqPX2X", "--query", ""];
+        let error = ClaimUnbondPublic::try_parse_from(args).unwrap().parse().unwrap_err().to_string();
+        assert!(error.contains("Please specify one of the following actions"), "{error}");
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentNetwork, Developer};
+use crate::helpers::progress;
+
+use snarkvm::prelude::{
+    execution_cost,
+    query::Query,
+    store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    Address,
+    Locator,
+    PrivateKey,
+    Value,
+    VM,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use clap::Args;
+use colored::Colorize;
+use std::str::FromStr;
+
+/// The options to create a `credits.aleo` execution, and to broadcast, store, or display it.
+#[derive(Debug, Args)]
+pub struct CreditsOptions {
+    /// The private key used to generate the execution.
+    #[clap(short, long)]
+    pub(crate) private_key: String,
+    /// The endpoint to query node state from.
+    #[clap(short, long)]
+    pub(crate) query: String,
+    /// The priority fee in microcredits.
+    #[clap(long)]
+    pub(crate) priority_fee: Option<u64>,
+    /// The record to spend the fee from. If not set, the fee is paid from the public balance.
+    #[clap(long)]
+    pub(crate) fee_record: Option<String>,
+    /// The endpoint used to broadcast the generated transaction.
+    #[clap(short, long, conflicts_with = "dry_run")]
+    pub(crate) broadcast: Option<String>,
+    /// Performs a dry-run of transaction generation.
+    #[clap(short, long, conflicts_with = "broadcast")]
+    pub(crate) dry_run: bool,
+    /// Store generated transaction to a local file.
+    #[clap(long)]
+    pub(crate) store: Option<String>,
+}
+
+impl CreditsOptions {
+    /// Ensures that the user has specified an action.
+    pub(crate) fn ensure_action(&self) -> Result<()> {
+        if !self.dry_run && self.broadcast.is_none() && self.store.is_none() {
+            bail!("❌ Please specify one of the following actions: --broadcast, --dry-run, --store");
+        }
+        Ok(())
+    }
+
+    /// Returns the private key used to generate the execution.
+    pub(crate) fn private_key(&self) -> Result<PrivateKey<CurrentNetwork>> {
+        PrivateKey::from_str(&self.private_key)
+    }
+
+    /// Creates an execution of the given `credits.aleo` function with the provided inputs,
+    /// where `public_amount` is the number of microcredits spent from the public balance by the function.
+    /// The execution is then broadcast, stored, or displayed to the user.
+    ///
+    /// The caller is expected to validate the inputs with `ensure_action` beforehand.
+    pub(crate) fn execute(
+        self,
+        function: &str,
+        inputs: Vec<Value<CurrentNetwork>>,
+        public_amount: u64,
+    ) -> Result<String> {
+        // Specify the query
+        let query = Query::from(&self.query);
+
+        // Retrieve the private key.
+        let private_key = self.private_key()?;
+
+        let locator = Locator::<CurrentNetwork>::from_str(&format!("credits.aleo/{function}"))?;
+        progress!("📦 Creating execution transaction for '{}'...\n", &locator.to_string().bold());
+
+        // Generate the execution transaction, and compute its base fee.
+        let (transaction, base_fee) = {
+            // Initialize an RNG.
+            let rng = &mut rand::thread_rng();
+
+            // Initialize the VM.
+            let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None)?;
+            let vm = VM::from(store)?;
+
+            // Prepare the fee.
+            let fee_record = match &self.fee_record {
+                Some(record_string) => Some(Developer::parse_record(&private_key, record_string)?),
+                None => None,
+            };
+            let priority_fee = self.priority_fee.unwrap_or(0);
+
+            // Create a new transaction.
+            let transaction = vm.execute(
+                &private_key,
+                ("credits.aleo", function),
+                inputs.iter(),
+                fee_record,
+                priority_fee,
+                Some(query),
+                rng,
+            )?;
+
+            // Compute the base fee, which covers the storage and finalize cost of the execution.
+            let execution =
+                transaction.execution().ok_or_else(|| anyhow!("The transaction does not contain an execution"))?;
+            let (base_fee, _) = execution_cost(&vm, execution)?;
+
+            (transaction, base_fee)
+        };
+
+        // Determine the amount to be spent from the public balance.
+        let public_spend = match self.fee_record {
+            // The fee is paid from the fee record.
+            Some(_) => public_amount,
+            // The fee is paid from the public balance.
+            None => public_amount.saturating_add(base_fee).saturating_add(self.priority_fee.unwrap_or(0)),
+        };
+
+        // Check if the public balance is sufficient.
+        if public_spend > 0 {
+            // Fetch the public balance.
+            let address = Address::try_from(&private_key)?;
+            let public_balance = Developer::get_public_balance(&address, &self.query)?;
+
+            // If the public balance is insufficient, return an error.
+            ensure!(
+                public_balance >= public_spend,
+                "❌ The public balance of {} is insufficient to pay {} microcredits for `{}`",
+                public_balance,
+                public_spend,
+                locator.to_string().bold()
+            );
+        }
+
+        progress!("✅ Created execution transaction for '{}'", locator.to_string().bold());
+
+        // Determine if the transaction should be broadcast, stored, or displayed to user.
+        Developer::handle_transaction(self.broadcast, self.dry_run, self.store, transaction, locator.to_string())
+    }
+}
+
+/// Ensures the given amount of microcredits is nonzero.
+pub(crate) fn ensure_nonzero_amount(amount: u64) -> Result<()> {
+    ensure!(amount > 0, "❌ The amount of microcredits must be greater than zero");
+    Ok(())
+}
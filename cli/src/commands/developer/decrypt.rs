@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::CurrentNetwork;
+use crate::helpers::format_output;
 
 use snarkvm::{
     console::program::Ciphertext,
@@ -21,6 +22,7 @@ use snarkvm::{
 
 use anyhow::{bail, Result};
 use clap::Parser;
+use serde_json::json;
 use std::str::FromStr;
 
 /// Decrypts a record ciphertext.
@@ -49,7 +51,9 @@ impl Decrypt {
         let view_key = ViewKey::<CurrentNetwork>::from_str(view_key)?;
 
         match ciphertext_record.decrypt(&view_key) {
-            Ok(plaintext_record) => Ok(plaintext_record.to_string()),
+            Ok(plaintext_record) => {
+                format_output(&json!({ "record": plaintext_record }), || plaintext_record.to_string())
+            }
             Err(_) => bail!("Invalid view key for the provided record ciphertext"),
         }
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::{CurrentAleo, CurrentNetwork, Developer};
+use crate::helpers::progress;
 
 use snarkvm::{
     console::program::ProgramOwner,
@@ -80,7 +81,7 @@ impl Deploy {
         // Fetch the package from the directory.
         let package = Developer::parse_package(self.program_id, self.path)?;
 
-        println!("📦 Creating deployment transaction for '{}'...\n", &self.program_id.to_string().bold());
+        progress!("📦 Creating deployment transaction for '{}'...\n", &self.program_id.to_string().bold());
 
         // Generate the deployment
         let deployment = package.deploy::<CurrentAleo>(None)?;
@@ -129,7 +130,7 @@ impl Deploy {
             // Create a new transaction.
             Transaction::from_deployment(owner, deployment, fee)?
         };
-        println!("✅ Created deployment transaction for '{}'", self.program_id.to_string().bold());
+        progress!("✅ Created deployment transaction for '{}'", self.program_id.to_string().bold());
 
         // Determine if the transaction should be broadcast, stored, or displayed to user.
         Developer::handle_transaction(
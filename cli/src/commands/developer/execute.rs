@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::{CurrentNetwork, Developer};
+use crate::helpers::progress;
 
 use snarkvm::prelude::{
     query::Query,
@@ -80,7 +81,7 @@ impl Execute {
         let private_key = PrivateKey::from_str(&self.private_key)?;
 
         let locator = Locator::<CurrentNetwork>::from_str(&format!("{}/{}", self.program_id, self.function))?;
-        println!("📦 Creating execution transaction for '{}'...\n", &locator.to_string().bold());
+        progress!("📦 Creating execution transaction for '{}'...\n", &locator.to_string().bold());
 
         // Generate the execution transaction.
         let transaction = {
@@ -140,7 +141,7 @@ impl Execute {
             }
         }
 
-        println!("✅ Created execution transaction for '{}'", locator.to_string().bold());
+        progress!("✅ Created execution transaction for '{}'", locator.to_string().bold());
 
         // Determine if the transaction should be broadcast, stored, or displayed to user.
         Developer::handle_transaction(self.broadcast, self.dry_run, self.store, transaction, locator.to_string())
@@ -148,7 +149,7 @@ impl Execute {
 }
 
 /// A helper function to recursively load the program and all of its imports into the process.
-fn load_program(
+pub(crate) fn load_program(
     endpoint: &str,
     process: &mut Process<CurrentNetwork>,
     program_id: &ProgramID<CurrentNetwork>,
@@ -12,6 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod authorize;
+pub use authorize::*;
+
+mod balance;
+pub use balance::*;
+
+mod bond_public;
+pub use bond_public::*;
+
+mod claim_unbond_public;
+pub use claim_unbond_public::*;
+
+mod credits;
+pub use credits::*;
+
 mod decrypt;
 pub use decrypt::*;
 
@@ -21,12 +36,32 @@ pub use deploy::*;
 mod execute;
 pub use execute::*;
 
+mod prove;
+pub use prove::*;
+
 mod scan;
 pub use scan::*;
 
+mod status;
+pub use status::*;
+
 mod transfer_private;
 pub use transfer_private::*;
 
+mod transfer_private_to_public;
+pub use transfer_private_to_public::*;
+
+mod transfer_public;
+pub use transfer_public::*;
+
+mod transfer_public_to_private;
+pub use transfer_public_to_private::*;
+
+mod unbond_public;
+pub use unbond_public::*;
+
+use crate::helpers::{progress, OutputFormat};
+
 use snarkvm::{
     package::Package,
     prelude::{
@@ -49,6 +84,8 @@ use snarkvm::{
 use anyhow::{bail, ensure, Result};
 use clap::Parser;
 use colored::Colorize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
 use std::{path::PathBuf, str::FromStr};
 
 type CurrentAleo = snarkvm::circuit::AleoV0;
@@ -57,26 +94,56 @@ type CurrentNetwork = snarkvm::prelude::Testnet3;
 /// Commands to manage Aleo accounts.
 #[derive(Debug, Parser)]
 pub enum Developer {
+    /// Authorize a program function, or the deployment of a program, offline.
+    Authorize(Authorize),
+    /// Query the public balance of an address.
+    Balance(Balance),
+    /// Execute the `credits.aleo/bond_public` function.
+    BondPublic(BondPublic),
+    /// Execute the `credits.aleo/claim_unbond_public` function.
+    ClaimUnbondPublic(ClaimUnbondPublic),
     /// Decrypt a ciphertext.
     Decrypt(Decrypt),
     /// Deploy a program.
     Deploy(Deploy),
     /// Execute a program function.
     Execute(Execute),
+    /// Prove a signed authorization into a transaction.
+    Prove(Prove),
     /// Scan the node for records.
     Scan(Scan),
+    /// Query the status of a transaction.
+    Status(Status),
     /// Execute the `credits.aleo/transfer_private` function.
     TransferPrivate(TransferPrivate),
+    /// Execute the `credits.aleo/transfer_private_to_public` function.
+    TransferPrivateToPublic(TransferPrivateToPublic),
+    /// Execute the `credits.aleo/transfer_public` function.
+    TransferPublic(TransferPublic),
+    /// Execute the `credits.aleo/transfer_public_to_private` function.
+    TransferPublicToPrivate(TransferPublicToPrivate),
+    /// Execute the `credits.aleo/unbond_public` function.
+    UnbondPublic(UnbondPublic),
 }
 
 impl Developer {
     pub fn parse(self) -> Result<String> {
         match self {
+            Self::Authorize(authorize) => authorize.parse(),
+            Self::Balance(balance) => balance.parse(),
+            Self::BondPublic(bond_public) => bond_public.parse(),
+            Self::ClaimUnbondPublic(claim_unbond_public) => claim_unbond_public.parse(),
             Self::Decrypt(decrypt) => decrypt.parse(),
             Self::Deploy(deploy) => deploy.parse(),
             Self::Execute(execute) => execute.parse(),
+            Self::Prove(prove) => prove.parse(),
             Self::Scan(scan) => scan.parse(),
+            Self::Status(status) => status.parse(),
             Self::TransferPrivate(transfer_private) => transfer_private.parse(),
+            Self::TransferPrivateToPublic(transfer_private_to_public) => transfer_private_to_public.parse(),
+            Self::TransferPublic(transfer_public) => transfer_public.parse(),
+            Self::TransferPublicToPrivate(transfer_public_to_private) => transfer_public_to_private.parse(),
+            Self::UnbondPublic(unbond_public) => unbond_public.parse(),
         }
     }
 
@@ -118,6 +185,23 @@ impl Developer {
         }
     }
 
+    /// Fetch and deserialize the JSON response from the given URL.
+    fn fetch_json<T: DeserializeOwned>(url: &str) -> Result<T> {
+        // Send a request to the query node.
+        let response = ureq::get(url).call();
+
+        // Deserialize the response.
+        match response {
+            Ok(response) => response.into_json().map_err(|err| err.into()),
+            Err(err) => match err {
+                ureq::Error::Status(_status, response) => {
+                    bail!(response.into_string().unwrap_or("Response too large!".to_owned()))
+                }
+                err => bail!(err),
+            },
+        }
+    }
+
     /// Fetch the program from the given endpoint.
     fn fetch_program(program_id: &ProgramID<CurrentNetwork>, endpoint: &str) -> Result<Program<CurrentNetwork>> {
         // Send a request to the query node.
@@ -180,23 +264,23 @@ impl Developer {
         ensure!(!transaction.is_fee(), "The transaction is a fee transaction and cannot be broadcast");
 
         // Determine if the transaction should be stored.
-        if let Some(path) = store {
-            match PathBuf::from_str(&path) {
+        if let Some(path) = &store {
+            match PathBuf::from_str(path) {
                 Ok(file_path) => {
                     let transaction_bytes = transaction.to_bytes_le()?;
                     std::fs::write(&file_path, transaction_bytes)?;
-                    println!("Transaction {transaction_id} was stored to {}", file_path.display());
+                    progress!("Transaction {transaction_id} was stored to {}", file_path.display());
                 }
                 Err(err) => {
-                    println!("The transaction was unable to be stored due to: {err}");
+                    progress!("The transaction was unable to be stored due to: {err}");
                 }
             }
         };
 
         // Determine if the transaction should be broadcast to the network.
-        if let Some(endpoint) = broadcast {
+        if let Some(endpoint) = &broadcast {
             // Send the deployment request to the local development node.
-            match ureq::post(&endpoint).send_json(&transaction) {
+            match ureq::post(endpoint).send_json(&transaction) {
                 Ok(id) => {
                     // Remove the quotes from the response.
                     let response_string = id.into_string()?.trim_matches('\"').to_string();
@@ -207,21 +291,21 @@ impl Developer {
 
                     match transaction {
                         Transaction::Deploy(..) => {
-                            println!(
+                            progress!(
                                 "✅ Successfully broadcast deployment {transaction_id} ('{}') to {}.",
                                 operation.bold(),
                                 endpoint
                             )
                         }
                         Transaction::Execute(..) => {
-                            println!(
+                            progress!(
                                 "✅ Successfully broadcast execution {transaction_id} ('{}') to {}.",
                                 operation.bold(),
                                 endpoint
                             )
                         }
                         Transaction::Fee(..) => {
-                            println!("❌ Failed to broadcast fee '{}' to the {}.", operation.bold(), endpoint)
+                            progress!("❌ Failed to broadcast fee '{}' to the {}.", operation.bold(), endpoint)
                         }
                     }
                 }
@@ -256,7 +340,20 @@ impl Developer {
                     }
                 }
             };
+        }
 
+        // Output the summary of the transaction as JSON, if requested.
+        if OutputFormat::is_json() {
+            let output = json!({
+                "transaction_id": transaction_id,
+                "type": if transaction.is_deploy() { "deploy" } else { "execute" },
+                "operation": operation,
+                "stored": store,
+                "broadcast": broadcast,
+                "transaction": dry_run.then_some(&transaction),
+            });
+            Ok(serde_json::to_string(&output)?)
+        } else if broadcast.is_some() {
             // Output the transaction id.
             Ok(transaction_id.to_string())
         } else if dry_run {
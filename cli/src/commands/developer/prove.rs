@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{load_program, CurrentNetwork, Developer, SignedAuthorization};
+use crate::helpers::progress;
+
+use snarkvm::prelude::{
+    query::Query,
+    store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    VM,
+};
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Proves a signed authorization into a transaction, without the private key.
+#[derive(Debug, Parser)]
+pub struct Prove {
+    /// The path to the signed authorization, as produced by `developer authorize`.
+    #[clap(short, long)]
+    authorization: PathBuf,
+    /// The endpoint to query node state from.
+    #[clap(short, long)]
+    query: String,
+    /// The endpoint used to broadcast the generated transaction.
+    #[clap(short, long, conflicts_with = "dry_run")]
+    broadcast: Option<String>,
+    /// Performs a dry-run of transaction generation.
+    #[clap(short, long, conflicts_with = "broadcast")]
+    dry_run: bool,
+    /// Store generated transaction to a local file.
+    #[clap(long)]
+    store: Option<String>,
+}
+
+impl Prove {
+    /// Proves the signed authorization.
+    pub fn parse(self) -> Result<String> {
+        // Ensure that the user has specified an action.
+        if !self.dry_run && self.broadcast.is_none() && self.store.is_none() {
+            bail!("❌ Please specify one of the following actions: --broadcast, --dry-run, --store");
+        }
+
+        // Load the signed authorization.
+        let signed: SignedAuthorization = serde_json::from_str(&std::fs::read_to_string(&self.authorization)?)?;
+        let name = signed.name()?;
+        progress!("📦 Proving the authorization for '{name}'...\n");
+
+        // Specify the query
+        let query = Query::from(&self.query);
+
+        // Generate the transaction.
+        let transaction = {
+            // Initialize an RNG.
+            let rng = &mut rand::thread_rng();
+
+            // Initialize the VM.
+            let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None)?;
+            let vm = VM::from(store)?;
+
+            // Load the executed programs and their imports into the process.
+            if let SignedAuthorization::Execute { authorization, .. } = &signed {
+                for request in authorization.to_vec_deque() {
+                    load_program(&self.query, &mut vm.process().write(), request.program_id())?;
+                }
+            }
+
+            // Prove the execution or deployment, and the fee.
+            signed.prove(&vm, query, rng)?
+        };
+
+        let kind = if transaction.is_deploy() { "deployment" } else { "execution" };
+        progress!("✅ Created {kind} transaction for '{name}'");
+
+        // Determine if the transaction should be broadcast, stored, or displayed to user.
+        Developer::handle_transaction(self.broadcast, self.dry_run, self.store, transaction, name)
+    }
+}
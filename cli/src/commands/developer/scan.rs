@@ -15,31 +15,49 @@
 #![allow(clippy::type_complexity)]
 
 use super::CurrentNetwork;
-
-use snarkvm::prelude::{block::Block, Ciphertext, Field, FromBytes, Network, Plaintext, PrivateKey, Record, ViewKey};
+use crate::helpers::{progress, OutputFormat};
+
+use snarkvm::prelude::{
+    block::Block,
+    Address,
+    Ciphertext,
+    Field,
+    FromBytes,
+    Network,
+    Plaintext,
+    PrivateKey,
+    Record,
+    ViewKey,
+};
 
 use anyhow::{bail, ensure, Result};
 use clap::Parser;
+use indexmap::IndexMap;
 use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::{
-    io::{stdout, Write},
+    io::{stderr, stdout, Write},
+    path::{Path, PathBuf},
     str::FromStr,
     sync::Arc,
 };
 
 const MAX_BLOCK_RANGE: u32 = 50;
 const CDN_ENDPOINT: &str = "https://s3.us-west-1.amazonaws.com/testnet3.blocks/phase3";
+/// The ledger error returned (with a '500' status) by the node, when no transition contains the given ID.
+const TRANSITION_ID_NOT_FOUND: &str = "Failed to find the transition ID";
 
 /// Scan the snarkOS node for records.
 #[derive(Debug, Parser)]
 pub struct Scan {
-    /// An optional private key scan for unspent records.
-    #[clap(short, long)]
-    private_key: Option<String>,
+    /// The private keys used to scan for records, and to determine if they are spent.
+    #[clap(short = 'p', long = "private-key")]
+    private_keys: Vec<String>,
 
-    /// The view key used to scan for records.
-    #[clap(short, long)]
-    view_key: Option<String>,
+    /// The view keys used to scan for records.
+    #[clap(short = 'v', long = "view-key")]
+    view_keys: Vec<String>,
 
     /// The block height to start scanning from.
     #[clap(long, conflicts_with = "last")]
@@ -56,60 +74,153 @@ pub struct Scan {
     /// The endpoint to scan blocks from.
     #[clap(long)]
     endpoint: String,
+
+    /// The path to the scan cache. Defaults to a file for the network in the Aleo directory.
+    #[clap(long, conflicts_with = "no_cache")]
+    cache: Option<PathBuf>,
+
+    /// Disables the scan cache, and scans the full block range.
+    #[clap(long)]
+    no_cache: bool,
+}
+
+/// An account to scan for records.
+#[derive(Clone)]
+struct ScanAccount {
+    /// The optional private key, used to determine if records are spent.
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+    /// The view key, used to decrypt records.
+    view_key: ViewKey<CurrentNetwork>,
+    /// The x-coordinate of the address of the account.
+    address_x_coordinate: Field<CurrentNetwork>,
+    /// The block height from which to scan for records.
+    scan_from: u32,
 }
 
 impl Scan {
     pub fn parse(self) -> Result<String> {
-        // Derive the view key and optional private key.
-        let (private_key, view_key) = self.parse_account()?;
+        // Derive the view keys and optional private keys.
+        let keys = self.parse_accounts::<CurrentNetwork>()?;
+
+        // Fetch the genesis block from the endpoint, which identifies the network.
+        let genesis_block: Block<CurrentNetwork> =
+            ureq::get(&format!("{}/testnet3/block/0", self.endpoint)).call()?.into_json()?;
+
+        // Load the scan cache.
+        let cache_path = match (self.no_cache, &self.cache) {
+            (true, _) => None,
+            (false, Some(path)) => Some(path.clone()),
+            (false, None) => Some(ScanCache::default_path(&genesis_block)),
+        };
+        let mut cache = match &cache_path {
+            Some(path) => ScanCache::open(path)?,
+            None => ScanCache::default(),
+        };
 
         // Find the start and end height to scan.
-        let (start_height, end_height) = self.parse_block_range()?;
+        let (start_height, end_height) = match (self.start, self.end, self.last) {
+            // If no block range is given, resume from the cache.
+            (None, None, None) => match cache.resume_height(keys.iter().map(|(_, view_key)| view_key.to_address())) {
+                Some(start) => (start, self.latest_height()?),
+                None => bail!("Missing data about block range, and no previous scan was found in the cache."),
+            },
+            _ => self.parse_block_range()?,
+        };
+
+        // Determine the height to scan from for each account, skipping the blocks that are already cached.
+        let accounts = keys
+            .iter()
+            .map(|(private_key, view_key)| {
+                let address = view_key.to_address();
+                let scan_from = cache.account(&address).map_or(start_height, |state| state.scan_from(start_height));
+                ScanAccount {
+                    private_key: *private_key,
+                    view_key: *view_key,
+                    address_x_coordinate: address.to_x_coordinate(),
+                    scan_from,
+                }
+            })
+            .collect::<Vec<_>>();
 
         // Fetch the records from the network.
-        let records = Self::fetch_records(private_key, &view_key, &self.endpoint, start_height, end_height)?;
+        let records = Self::fetch_records(&genesis_block, &accounts, &self.endpoint, end_height)?;
+
+        // Update the scan state of each account.
+        for (index, account) in accounts.iter().enumerate() {
+            let state = cache.account_mut(&account.view_key.to_address());
+            state.extend(account.scan_from, end_height, records.iter().filter(|(i, _)| *i == index).map(|(_, r)| r));
+
+            // Determine if the records in the block range are spent, if the private key is known.
+            if let Some(private_key) = account.private_key {
+                let is_unknown = |record: &&mut ScannedRecord| {
+                    record.spent != Some(true) && (start_height..=end_height).contains(&record.height)
+                };
+                for record in state.records.iter_mut().filter(is_unknown) {
+                    record.spent = Some(Self::is_spent(&self.endpoint, private_key, record.commitment)?);
+                }
+            }
+        }
 
-        // Output the decrypted records associated with the view key.
-        if records.is_empty() {
-            Ok("No records found".to_string())
+        // Save the scan cache.
+        if let Some(path) = &cache_path {
+            cache.save(path)?;
+        }
+
+        // Output the decrypted records associated with each view key.
+        let output = keys
+            .iter()
+            .map(|(_, view_key)| {
+                let address = view_key.to_address();
+                let records = cache.account(&address).map(|state| state.records_in(start_height, end_height));
+                json!({ "address": address, "records": records.unwrap_or_default() })
+            })
+            .collect::<Vec<_>>();
+
+        if OutputFormat::is_json() {
+            let output = json!({ "start": start_height, "end": end_height, "accounts": output });
+            Ok(serde_json::to_string(&output)?)
         } else {
-            if private_key.is_none() {
-                println!("⚠️  This list may contain records that have already been spent.\n");
+            if keys.iter().any(|(private_key, _)| private_key.is_none()) {
+                progress!("⚠️  The spent status of records is only known for accounts with a private key.\n");
             }
 
-            Ok(serde_json::to_string_pretty(&records)?.replace("\\n", ""))
+            Ok(serde_json::to_string_pretty(&output)?.replace("\\n", ""))
         }
     }
 
-    /// Returns the view key and optional private key, from the given configurations.
-    fn parse_account<N: Network>(&self) -> Result<(Option<PrivateKey<N>>, ViewKey<N>)> {
-        match (&self.private_key, &self.view_key) {
-            (Some(private_key), Some(view_key)) => {
-                // Derive the private key.
-                let private_key = PrivateKey::<N>::from_str(private_key)?;
-                // Derive the expected view key.
-                let expected_view_key = ViewKey::<N>::try_from(private_key)?;
-                // Derive the view key.
-                let view_key = ViewKey::<N>::from_str(view_key)?;
+    /// Returns the view keys and optional private keys, from the given configurations.
+    fn parse_accounts<N: Network>(&self) -> Result<Vec<(Option<PrivateKey<N>>, ViewKey<N>)>> {
+        ensure!(!self.private_keys.is_empty() || !self.view_keys.is_empty(), "Missing private key or view key.");
 
-                ensure!(
-                    expected_view_key == view_key,
-                    "The provided private key does not correspond to the provided view key."
-                );
+        // Derive the view key of each private key.
+        let mut accounts = Vec::with_capacity(self.private_keys.len() + self.view_keys.len());
+        for private_key in &self.private_keys {
+            // Derive the private key.
+            let private_key = PrivateKey::<N>::from_str(private_key)?;
+            // Derive the view key.
+            let view_key = ViewKey::<N>::try_from(private_key)?;
 
-                Ok((Some(private_key), view_key))
+            if !accounts.iter().any(|(_, candidate)| *candidate == view_key) {
+                accounts.push((Some(private_key), view_key));
             }
-            (Some(private_key), _) => {
-                // Derive the private key.
-                let private_key = PrivateKey::<N>::from_str(private_key)?;
-                // Derive the view key.
-                let view_key = ViewKey::<N>::try_from(private_key)?;
+        }
+
+        for view_key in &self.view_keys {
+            // Derive the view key.
+            let view_key = ViewKey::<N>::from_str(view_key)?;
 
-                Ok((Some(private_key), view_key))
+            // If private keys are provided, ensure the view key corresponds to one of them.
+            if !self.private_keys.is_empty() {
+                ensure!(
+                    accounts.iter().any(|(_, candidate)| *candidate == view_key),
+                    "The provided view key does not correspond to any of the provided private keys."
+                );
+            } else if !accounts.iter().any(|(_, candidate)| *candidate == view_key) {
+                accounts.push((None, view_key));
             }
-            (None, Some(view_key)) => Ok((None, ViewKey::<N>::from_str(view_key)?)),
-            (None, None) => bail!("Missing private key or view key."),
         }
+
+        Ok(accounts)
     }
 
     /// Returns the `start` and `end` blocks to scan.
@@ -122,12 +233,11 @@ impl Scan {
             }
             (Some(start), None, None) => {
                 // Request the latest block height from the endpoint.
-                let endpoint = format!("{}/testnet3/latest/height", self.endpoint);
-                let latest_height = u32::from_str(&ureq::get(&endpoint).call()?.into_string()?)?;
+                let latest_height = self.latest_height()?;
 
                 // Print warning message if the user is attempting to scan the whole chain.
                 if start == 0 {
-                    println!("⚠️  Attention - Scanning the entire chain. This may take a while...\n");
+                    progress!("⚠️  Attention - Scanning the entire chain. This may take a while...\n");
                 }
 
                 Ok((start, latest_height))
@@ -135,8 +245,7 @@ impl Scan {
             (None, Some(end), None) => Ok((0, end)),
             (None, None, Some(last)) => {
                 // Request the latest block height from the endpoint.
-                let endpoint = format!("{}/testnet3/latest/height", self.endpoint);
-                let latest_height = u32::from_str(&ureq::get(&endpoint).call()?.into_string()?)?;
+                let latest_height = self.latest_height()?;
 
                 Ok((latest_height.saturating_sub(last), latest_height))
             }
@@ -145,22 +254,31 @@ impl Scan {
         }
     }
 
-    /// Fetch owned ciphertext records from the endpoint.
+    /// Returns the latest block height from the endpoint.
+    fn latest_height(&self) -> Result<u32> {
+        let endpoint = format!("{}/testnet3/latest/height", self.endpoint);
+        Ok(u32::from_str(&ureq::get(&endpoint).call()?.into_string()?)?)
+    }
+
+    /// Fetch the records owned by the given accounts from the endpoint, with the index of their account.
     fn fetch_records(
-        private_key: Option<PrivateKey<CurrentNetwork>>,
-        view_key: &ViewKey<CurrentNetwork>,
+        genesis_block: &Block<CurrentNetwork>,
+        accounts: &[ScanAccount],
         endpoint: &str,
-        start_height: u32,
         end_height: u32,
-    ) -> Result<Vec<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>> {
-        // Check the bounds of the request.
+    ) -> Result<Vec<(usize, ScannedRecord)>> {
+        // Determine the lowest height to scan from.
+        let start_height = match accounts.iter().map(|account| account.scan_from).min() {
+            Some(start_height) => start_height,
+            None => return Ok(Vec::new()),
+        };
+
+        // Return early if all accounts are already scanned up to the end height.
         if start_height > end_height {
-            bail!("Invalid block range");
+            progress!("✅ All blocks up to {end_height} were already scanned.\n");
+            return Ok(Vec::new());
         }
 
-        // Derive the x-coordinate of the address corresponding to the given view key.
-        let address_x_coordinate = view_key.to_address().to_x_coordinate();
-
         // Initialize a vector to store the records.
         let records = Arc::new(RwLock::new(Vec::new()));
 
@@ -168,30 +286,17 @@ impl Scan {
         let total_blocks = end_height.saturating_sub(start_height);
 
         // Log the initial progress.
-        print!("\rScanning {total_blocks} blocks for records (0% complete)...");
-        stdout().flush()?;
+        Self::log_progress(total_blocks, 0.0)?;
 
-        // Fetch the genesis block from the endpoint.
-        let genesis_block: Block<CurrentNetwork> =
-            ureq::get(&format!("{endpoint}/testnet3/block/0")).call()?.into_json()?;
         // Determine if the endpoint is on a development network.
-        let is_development_network = genesis_block != Block::from_bytes_le(CurrentNetwork::genesis_bytes())?;
+        let is_development_network = *genesis_block != Block::from_bytes_le(CurrentNetwork::genesis_bytes())?;
 
         // Determine the request start height.
         let mut request_start = match is_development_network {
             true => start_height,
             false => {
                 // Scan the CDN first for records.
-                Self::scan_from_cdn(
-                    start_height,
-                    end_height,
-                    CDN_ENDPOINT.to_string(),
-                    endpoint.to_string(),
-                    private_key,
-                    *view_key,
-                    address_x_coordinate,
-                    records.clone(),
-                )?;
+                Self::scan_from_cdn(start_height, end_height, CDN_ENDPOINT.to_string(), accounts, records.clone())?;
 
                 // Scan the remaining blocks from the endpoint.
                 end_height.saturating_sub(start_height % MAX_BLOCK_RANGE)
@@ -202,8 +307,7 @@ impl Scan {
         while request_start <= end_height {
             // Log the progress.
             let percentage_complete = request_start.saturating_sub(start_height) as f64 * 100.0 / total_blocks as f64;
-            print!("\rScanning {total_blocks} blocks for records ({percentage_complete:.2}% complete)...");
-            stdout().flush()?;
+            Self::log_progress(total_blocks, percentage_complete)?;
 
             let num_blocks_to_request =
                 std::cmp::min(MAX_BLOCK_RANGE, end_height.saturating_sub(request_start).saturating_add(1));
@@ -216,31 +320,26 @@ impl Scan {
 
             // Scan the blocks for owned records.
             for block in &blocks {
-                Self::scan_block(block, endpoint, private_key, view_key, &address_x_coordinate, records.clone())?;
+                Self::scan_block(block, accounts, records.clone())?;
             }
 
             request_start = request_start.saturating_add(num_blocks_to_request);
         }
 
         // Print final complete message.
-        println!("\rScanning {total_blocks} blocks for records (100% complete)...   \n");
-        stdout().flush()?;
+        progress!("\rScanning {total_blocks} blocks for records (100% complete)...   \n");
 
         let result = records.read().clone();
         Ok(result)
     }
 
     /// Scan the blocks from the CDN.
-    #[allow(clippy::too_many_arguments)]
     fn scan_from_cdn(
         start_height: u32,
         end_height: u32,
         cdn: String,
-        endpoint: String,
-        private_key: Option<PrivateKey<CurrentNetwork>>,
-        view_key: ViewKey<CurrentNetwork>,
-        address_x_coordinate: Field<CurrentNetwork>,
-        records: Arc<RwLock<Vec<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>>>,
+        accounts: &[ScanAccount],
+        records: Arc<RwLock<Vec<(usize, ScannedRecord)>>>,
     ) -> Result<()> {
         // Calculate the number of blocks to scan.
         let total_blocks = end_height.saturating_sub(start_height);
@@ -249,6 +348,9 @@ impl Scan {
         let cdn_request_start = start_height.saturating_sub(start_height % MAX_BLOCK_RANGE);
         let cdn_request_end = end_height.saturating_sub(start_height % MAX_BLOCK_RANGE);
 
+        // Prepare the accounts for the scanning task.
+        let accounts = accounts.to_vec();
+
         // Construct the runtime.
         let rt = tokio::runtime::Runtime::new()?;
 
@@ -263,11 +365,10 @@ impl Scan {
                 // Log the progress.
                 let percentage_complete =
                     block.height().saturating_sub(start_height) as f64 * 100.0 / total_blocks as f64;
-                print!("\rScanning {total_blocks} blocks for records ({percentage_complete:.2}% complete)...");
-                stdout().flush()?;
+                Self::log_progress(total_blocks, percentage_complete)?;
 
                 // Scan the block for records.
-                Self::scan_block(&block, &endpoint, private_key, &view_key, &address_x_coordinate, records.clone())?;
+                Self::scan_block(&block, &accounts, records.clone())?;
 
                 Ok(())
             })
@@ -277,23 +378,26 @@ impl Scan {
         Ok(())
     }
 
-    /// Scan a block for owned records.
+    /// Scan a block for records owned by the given accounts.
     fn scan_block(
         block: &Block<CurrentNetwork>,
-        endpoint: &str,
-        private_key: Option<PrivateKey<CurrentNetwork>>,
-        view_key: &ViewKey<CurrentNetwork>,
-        address_x_coordinate: &Field<CurrentNetwork>,
-        records: Arc<RwLock<Vec<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>>>,
+        accounts: &[ScanAccount],
+        records: Arc<RwLock<Vec<(usize, ScannedRecord)>>>,
     ) -> Result<()> {
         for (commitment, ciphertext_record) in block.records() {
-            // Check if the record is owned by the given view key.
-            if ciphertext_record.is_owner_with_address_x_coordinate(view_key, address_x_coordinate) {
-                // Decrypt and optionally filter the records.
-                if let Some(record) =
-                    Self::decrypt_record(private_key, view_key, endpoint, *commitment, ciphertext_record)?
+            for (index, account) in accounts.iter().enumerate() {
+                // Skip the block if it was already scanned for the account.
+                if block.height() < account.scan_from {
+                    continue;
+                }
+                // Check if the record is owned by the given view key.
+                if ciphertext_record
+                    .is_owner_with_address_x_coordinate(&account.view_key, &account.address_x_coordinate)
                 {
-                    records.write().push(record);
+                    // Decrypt the record.
+                    let record =
+                        Self::decrypt_record(&account.view_key, block.height(), *commitment, ciphertext_record)?;
+                    records.write().push((index, record));
                 }
             }
         }
@@ -301,47 +405,188 @@ impl Scan {
         Ok(())
     }
 
-    /// Decrypts the ciphertext record and filters spend record if a private key was provided.
+    /// Logs the scanning progress on a single line, to stderr in JSON mode.
+    fn log_progress(total_blocks: u32, percentage_complete: f64) -> Result<()> {
+        let message = format!("\rScanning {total_blocks} blocks for records ({percentage_complete:.2}% complete)...");
+        match OutputFormat::is_json() {
+            true => {
+                eprint!("{message}");
+                stderr().flush()?;
+            }
+            false => {
+                print!("{message}");
+                stdout().flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Decrypts the ciphertext record found at the given height.
     fn decrypt_record(
-        private_key: Option<PrivateKey<CurrentNetwork>>,
         view_key: &ViewKey<CurrentNetwork>,
-        endpoint: &str,
+        height: u32,
         commitment: Field<CurrentNetwork>,
         ciphertext_record: &Record<CurrentNetwork, Ciphertext<CurrentNetwork>>,
-    ) -> Result<Option<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>> {
-        // Check if a private key was provided.
-        if let Some(private_key) = private_key {
-            // Compute the serial number.
-            let serial_number =
-                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, commitment)?;
+    ) -> Result<ScannedRecord> {
+        Ok(ScannedRecord { height, commitment, record: ciphertext_record.decrypt(view_key)?, spent: None })
+    }
 
-            // Establish the endpoint.
-            let endpoint = format!("{endpoint}/testnet3/find/transitionID/{serial_number}");
-
-            // Check if the record is spent.
-            match ureq::get(&endpoint).call() {
-                // On success, skip as the record is spent.
-                Ok(_) => Ok(None),
-                // On error, add the record.
-                Err(_error) => {
-                    // TODO: Dedup the error types. We're adding the record as valid because the endpoint failed,
-                    //  meaning it couldn't find the serial number (ie. unspent). However if there's a DNS error or request error,
-                    //  we have a false positive here then.
-                    // Decrypt the record.
-                    Ok(Some(ciphertext_record.decrypt(view_key)?))
+    /// Returns `true` if the record with the given commitment is spent, by finding its serial number on the ledger.
+    fn is_spent(
+        endpoint: &str,
+        private_key: PrivateKey<CurrentNetwork>,
+        commitment: Field<CurrentNetwork>,
+    ) -> Result<bool> {
+        // Compute the serial number.
+        let serial_number =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, commitment)?;
+
+        // Establish the endpoint.
+        let endpoint = format!("{endpoint}/testnet3/find/transitionID/{serial_number}");
+
+        // Check if the record is spent.
+        match ureq::get(&endpoint).call() {
+            // On success, the serial number was found, so the record is spent.
+            Ok(_) => Ok(true),
+            // On an error response, the record is unspent only if the serial number was not found.
+            Err(ureq::Error::Status(status, response)) => {
+                let message = response.into_string().unwrap_or_default();
+                match Self::is_not_found(status, &message) {
+                    true => Ok(false),
+                    false => bail!("Failed to determine if the record {commitment} is spent - {status}: {message}"),
                 }
             }
-        } else {
-            // If no private key was provided, return the record.
-            Ok(Some(ciphertext_record.decrypt(view_key)?))
+            // On a transport error, the status is unknown, and the record must not be reported as unspent.
+            Err(error) => bail!("Failed to determine if the record {commitment} is spent - {error}"),
+        }
+    }
+
+    /// Returns `true` if the given error response reports that the serial number is not on the ledger.
+    fn is_not_found(status: u16, message: &str) -> bool {
+        status == 500 && message.contains(TRANSITION_ID_NOT_FOUND)
+    }
+}
+
+/// A record found by the scanner.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct ScannedRecord {
+    /// The height of the block containing the record.
+    height: u32,
+    /// The commitment of the record.
+    commitment: Field<CurrentNetwork>,
+    /// The decrypted record.
+    record: Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+    /// Whether the record is spent, if known.
+    spent: Option<bool>,
+}
+
+/// The scan state of an account.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct AccountScan {
+    /// The range of block heights that were scanned, if any.
+    scanned: Option<(u32, u32)>,
+    /// The records found in the scanned range.
+    records: Vec<ScannedRecord>,
+}
+
+impl AccountScan {
+    /// Returns the height to scan from, in order to scan from the given start height.
+    /// If the cached range contains the start height, the scan resumes after the cached range.
+    fn scan_from(&self, start_height: u32) -> u32 {
+        match self.scanned {
+            Some((start, end)) if start <= start_height && start_height <= end.saturating_add(1) => {
+                end.saturating_add(1).max(start_height)
+            }
+            _ => start_height,
+        }
+    }
+
+    /// Extends the scan state with the records found from `scan_from` up to `end_height`.
+    fn extend<'a>(&mut self, scan_from: u32, end_height: u32, records: impl Iterator<Item = &'a ScannedRecord>) {
+        // Update the scanned range, resetting the state if the new range is not contiguous with it.
+        self.scanned = match self.scanned {
+            _ if scan_from > end_height => self.scanned,
+            Some((start, end)) if start <= scan_from && scan_from <= end.saturating_add(1) => {
+                Some((start, end.max(end_height)))
+            }
+            _ => {
+                self.records.clear();
+                Some((scan_from, end_height))
+            }
+        };
+        // Add the new records.
+        for record in records {
+            if !self.records.iter().any(|candidate| candidate.commitment == record.commitment) {
+                self.records.push(record.clone());
+            }
         }
     }
+
+    /// Returns the records found between the given heights (inclusive).
+    fn records_in(&self, start_height: u32, end_height: u32) -> Vec<&ScannedRecord> {
+        self.records.iter().filter(|record| (start_height..=end_height).contains(&record.height)).collect()
+    }
+}
+
+/// The scan cache, which stores the scan state of each account on a network.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct ScanCache {
+    /// The scan state of each account, by address.
+    accounts: IndexMap<String, AccountScan>,
+}
+
+impl ScanCache {
+    /// Returns the default path of the scan cache for the network with the given genesis block.
+    fn default_path(genesis_block: &Block<CurrentNetwork>) -> PathBuf {
+        aleo_std::aleo_dir().join("scan").join(format!("{}.json", genesis_block.hash()))
+    }
+
+    /// Opens the scan cache at the given path, or returns an empty cache if it does not exist.
+    fn open(path: &Path) -> Result<Self> {
+        match path.exists() {
+            true => Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?),
+            false => Ok(Self::default()),
+        }
+    }
+
+    /// Saves the scan cache to the given path.
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first, so the cache is never left partially written.
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_string(self)?)?;
+        // Restrict the permissions, as the cache contains decrypted records.
+        #[cfg(target_family = "unix")]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&tmp_path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Returns the scan state of the given account, if it was scanned before.
+    fn account(&self, address: &Address<CurrentNetwork>) -> Option<&AccountScan> {
+        self.accounts.get(&address.to_string())
+    }
+
+    /// Returns a mutable reference to the scan state of the given account.
+    fn account_mut(&mut self, address: &Address<CurrentNetwork>) -> &mut AccountScan {
+        self.accounts.entry(address.to_string()).or_default()
+    }
+
+    /// Returns the lowest height scanned for the given accounts, if all of them were scanned before.
+    fn resume_height(&self, addresses: impl Iterator<Item = Address<CurrentNetwork>>) -> Option<u32> {
+        addresses.map(|address| self.account(&address)?.scanned.map(|(start, _)| start)).min().flatten()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use snarkvm::prelude::{TestRng, Testnet3};
+    use snarkvm::prelude::{TestRng, Testnet3, Uniform};
 
     type CurrentNetwork = Testnet3;
 
@@ -372,7 +617,7 @@ mod tests {
             .iter(),
         )
         .unwrap();
-        assert!(config.parse_account::<CurrentNetwork>().is_ok());
+        assert!(config.parse_accounts::<CurrentNetwork>().is_ok());
 
         let config = Scan::try_parse_from(
             [
@@ -389,7 +634,49 @@ mod tests {
             .iter(),
         )
         .unwrap();
-        assert!(config.parse_account::<CurrentNetwork>().is_err());
+        assert!(config.parse_accounts::<CurrentNetwork>().is_err());
+
+        // Multiple private keys and view keys may be given, and duplicates are removed.
+        let config = Scan::try_parse_from(
+            [
+                "snarkos",
+                "-p",
+                &format!("{private_key}"),
+                "-p",
+                &format!("{unassociated_private_key}"),
+                "-v",
+                &format!("{view_key}"),
+                "--last",
+                "10",
+                "--endpoint",
+                "",
+            ]
+            .iter(),
+        )
+        .unwrap();
+        let accounts = config.parse_accounts::<CurrentNetwork>().unwrap();
+        assert_eq!(accounts, vec![
+            (Some(private_key), view_key),
+            (Some(unassociated_private_key), unassociated_view_key)
+        ]);
+
+        let config = Scan::try_parse_from(
+            [
+                "snarkos",
+                "-v",
+                &format!("{view_key}"),
+                "-v",
+                &format!("{unassociated_view_key}"),
+                "--last",
+                "10",
+                "--endpoint",
+                "",
+            ]
+            .iter(),
+        )
+        .unwrap();
+        let accounts = config.parse_accounts::<CurrentNetwork>().unwrap();
+        assert_eq!(accounts, vec![(None, view_key), (None, unassociated_view_key)]);
     }
 
     #[test]
@@ -426,5 +713,96 @@ mod tests {
             )
             .is_err()
         );
+
+        // `cache` conflicts with `no-cache`
+        assert!(
+            Scan::try_parse_from(
+                ["snarkos", "--view-key", "", "--last", "10", "--endpoint", "", "--cache", "a", "--no-cache"].iter(),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_account_scan() {
+        let rng = &mut TestRng::default();
+
+        // Sample a record at the given height.
+        let mut sample_record = |height| {
+            let record = Record::from_str(&format!(
+                "{{ owner: {}.private, _nonce: 0group.public }}",
+                Address::<CurrentNetwork>::rand(rng)
+            ))
+            .unwrap();
+            ScannedRecord { height, commitment: Field::rand(rng), record, spent: None }
+        };
+
+        // An empty state scans from the start height.
+        let mut state = AccountScan::default();
+        assert_eq!(state.scan_from(10), 10);
+
+        // Scan blocks 10 to 20.
+        let first = sample_record(15);
+        state.extend(10, 20, [&first].into_iter());
+        assert_eq!(state.scanned, Some((10, 20)));
+
+        // A scan that starts within, or right after, the cached range resumes after it.
+        assert_eq!(state.scan_from(10), 21);
+        assert_eq!(state.scan_from(21), 21);
+        // A scan that starts before or after the cached range is not resumed.
+        assert_eq!(state.scan_from(5), 5);
+        assert_eq!(state.scan_from(22), 22);
+
+        // Resume the scan up to block 30, and ensure duplicates are ignored.
+        let second = sample_record(25);
+        state.extend(21, 30, [&first, &second].into_iter());
+        assert_eq!(state.scanned, Some((10, 30)));
+        assert_eq!(state.records, vec![first.clone(), second.clone()]);
+        assert_eq!(state.records_in(20, 30), vec![&second]);
+
+        // A scan that is already cached does not change the state.
+        state.extend(31, 30, std::iter::empty());
+        assert_eq!(state.scanned, Some((10, 30)));
+
+        // A scan that is not contiguous with the cached range replaces it.
+        let third = sample_record(45);
+        state.extend(40, 50, [&third].into_iter());
+        assert_eq!(state.scanned, Some((40, 50)));
+        assert_eq!(state.records, vec![third]);
+    }
+
+    #[test]
+    fn test_is_not_found() {
+        // Ensure the ledger error for an unknown serial number is recognized.
+        let message = "Something went wrong: Failed to find the transition ID for the given input or output ID";
+        assert!(Scan::is_not_found(500, message));
+        // Ensure any other error is not mistaken for an unspent record.
+        assert!(!Scan::is_not_found(500, "Something went wrong: Failed to open the database"));
+        assert!(!Scan::is_not_found(502, message));
+        assert!(!Scan::is_not_found(429, ""));
+    }
+
+    #[test]
+    fn test_scan_cache() {
+        let rng = &mut TestRng::default();
+        let path = std::env::temp_dir().join(format!("snarkos-scan-cache-{}.json", u64::rand(rng)));
+
+        // Ensure a missing cache is empty.
+        let mut cache = ScanCache::open(&path).unwrap();
+        assert_eq!(cache, ScanCache::default());
+
+        // Ensure the resume height requires a previous scan of every account.
+        let (a, b) = (Address::<CurrentNetwork>::rand(rng), Address::<CurrentNetwork>::rand(rng));
+        cache.account_mut(&a).extend(10, 20, std::iter::empty());
+        assert_eq!(cache.resume_height([a].into_iter()), Some(10));
+        assert_eq!(cache.resume_height([a, b].into_iter()), None);
+        cache.account_mut(&b).extend(5, 20, std::iter::empty());
+        assert_eq!(cache.resume_height([a, b].into_iter()), Some(5));
+
+        // Ensure the cache round-trips through the file.
+        cache.save(&path).unwrap();
+        assert_eq!(ScanCache::open(&path).unwrap(), cache);
+
+        std::fs::remove_file(&path).unwrap();
     }
 }
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentNetwork, Developer};
+use crate::helpers::progress;
+
+use snarkvm::prelude::{block::ConfirmedTransaction, Network};
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use std::time::{Duration, Instant};
+
+/// Queries the status of a transaction, optionally waiting until it is confirmed.
+#[derive(Debug, Parser)]
+pub struct Status {
+    /// The ID of the transaction.
+    #[clap(short, long = "tx-id")]
+    tx_id: <CurrentNetwork as Network>::TransactionID,
+    /// The endpoint to query node state from.
+    #[clap(short, long)]
+    query: String,
+    /// Waits until the transaction is confirmed.
+    #[clap(short, long)]
+    wait: bool,
+    /// The maximum number of seconds to wait for the transaction to be confirmed.
+    #[clap(long, default_value = "60", requires = "wait")]
+    timeout: u64,
+    /// The number of seconds between queries while waiting.
+    #[clap(long, default_value = "2", requires = "wait")]
+    interval: u64,
+}
+
+impl Status {
+    /// Fetches the status of the transaction.
+    pub fn parse(self) -> Result<String> {
+        // Initialize a timer.
+        let timer = Instant::now();
+
+        if self.wait {
+            progress!("⏳ Waiting for transaction {} to be confirmed...\n", self.tx_id);
+        }
+
+        loop {
+            // Return the status if the transaction is confirmed.
+            if let Some(status) = self.fetch_status()? {
+                return Ok(status);
+            }
+            // If not waiting, return that the transaction is not yet confirmed.
+            if !self.wait {
+                return Ok(format!("⏳ Transaction {} is not yet confirmed", self.tx_id));
+            }
+            // Ensure the timeout has not elapsed.
+            if timer.elapsed() >= Duration::from_secs(self.timeout) {
+                bail!("❌ Transaction {} was not confirmed within {} seconds", self.tx_id, self.timeout);
+            }
+            std::thread::sleep(Duration::from_secs(self.interval.max(1)));
+        }
+    }
+
+    /// Returns the status of the transaction, or `None` if the transaction is not yet confirmed.
+    fn fetch_status(&self) -> Result<Option<String>> {
+        let (endpoint, tx_id) = (&self.query, &self.tx_id);
+
+        // Find the block containing the transaction.
+        let block_hash: Option<<CurrentNetwork as Network>::BlockHash> =
+            Developer::fetch_json(&format!("{endpoint}/testnet3/find/blockHash/{tx_id}"))?;
+        let Some(block_hash) = block_hash else {
+            return Ok(None);
+        };
+        // Fetch the height of the block.
+        let height: u32 = Developer::fetch_json(&format!("{endpoint}/testnet3/height/{block_hash}"))?;
+        // Fetch the confirmed transaction.
+        let confirmed: ConfirmedTransaction<CurrentNetwork> =
+            Developer::fetch_json(&format!("{endpoint}/testnet3/transaction/confirmed/{tx_id}"))?;
+
+        // Output the status.
+        match confirmed.is_accepted() {
+            true => Ok(Some(format!("✅ Transaction {tx_id} was accepted in block {block_hash} (height {height})"))),
+            false => Ok(Some(format!("❌ Transaction {tx_id} was rejected in block {block_hash} (height {height})"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::{Field, TestRng, Uniform};
+
+    #[test]
+    fn test_parse_status() {
+        let mut rng = TestRng::default();
+        let tx_id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(&mut rng)).to_string();
+
+        // Ensure the status may be queried with or without waiting.
+        let status = Status::try_parse_from(["snarkos", "--tx-id", &tx_id, "--query", ""].iter()).unwrap();
+        assert!(!status.wait);
+        let status =
+            Status::try_parse_from(["snarkos", "--tx-id", &tx_id, "--query", "", "--wait", "--timeout", "5"].iter())
+                .unwrap();
+        assert!(status.wait);
+        assert_eq!(status.timeout, 5);
+
+        // Ensure the timeout requires waiting.
+        assert!(Status::try_parse_from(["snarkos", "--tx-id", &tx_id, "--query", "", "--timeout", "5"].iter()).is_err());
+        // Ensure the transaction ID is validated.
+        assert!(Status::try_parse_from(["snarkos", "--tx-id", "at1", "--query", ""].iter()).is_err());
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::{CurrentNetwork, Developer};
+use crate::helpers::progress;
 
 use snarkvm::prelude::{
     query::Query,
@@ -78,7 +79,7 @@ impl TransferPrivate {
         // Retrieve the private key.
         let private_key = PrivateKey::from_str(&self.private_key)?;
 
-        println!("📦 Creating private transfer of {} microcredits to {}...\n", self.amount, self.recipient);
+        progress!("📦 Creating private transfer of {} microcredits to {}...\n", self.amount, self.recipient);
 
         // Generate the transfer_private transaction.
         let transaction = {
@@ -113,7 +114,7 @@ impl TransferPrivate {
             )?
         };
         let locator = Locator::<CurrentNetwork>::from_str("credits.aleo/transfer_private")?;
-        println!("✅ Created private transfer of {} microcredits to {}\n", &self.amount, self.recipient);
+        progress!("✅ Created private transfer of {} microcredits to {}\n", &self.amount, self.recipient);
 
         // Determine if the transaction should be broadcast, stored, or displayed to user.
         Developer::handle_transaction(self.broadcast, self.dry_run, self.store, transaction, locator.to_string())
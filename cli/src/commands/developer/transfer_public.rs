@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ensure_nonzero_amount, CreditsOptions, CurrentNetwork};
+use crate::helpers::progress;
+
+use snarkvm::prelude::{Address, Value};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use std::str::FromStr;
+
+/// Executes the `transfer_public` function in the `credits.aleo` program.
+#[derive(Debug, Parser)]
+pub struct TransferPublic {
+    /// The recipient address.
+    #[clap(long)]
+    recipient: Address<CurrentNetwork>,
+    /// The number of microcredits to transfer.
+    #[clap(long)]
+    amount: u64,
+    /// The options for the transaction.
+    #[clap(flatten)]
+    options: CreditsOptions,
+}
+
+impl TransferPublic {
+    /// Creates a public transfer with the provided inputs.
+    pub fn parse(self) -> Result<String> {
+        // Validate the inputs.
+        self.options.ensure_action()?;
+        ensure_nonzero_amount(self.amount)?;
+        let sender = Address::try_from(self.options.private_key()?)?;
+        ensure!(sender != self.recipient, "❌ The recipient must be different from the sender");
+
+        progress!("📦 Creating public transfer of {} microcredits to {}...\n", self.amount, self.recipient);
+
+        // Prepare the inputs for the transfer.
+        let inputs =
+            vec![Value::from_str(&format!("{}", self.recipient))?, Value::from_str(&format!("{}u64", self.amount))?];
+        // Create the transaction, and broadcast, store, or display it.
+        self.options.execute("transfer_public", inputs, self.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, Developer, CLI};
+    use snarkvm::prelude::PrivateKey;
+
+    #[test]
+    fn clap_snarkos_transfer_public() {
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "transfer-public",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--query",
+            "QUERY",
+            "--recipient",
+            "aleo1uxl69laseuv3876ksh8k0nd7tvpgjt6ccrgccedpjk9qwyfensxst9ftg5",
+            "--amount",
+            "100",
+            "--priority-fee",
+            "7",
+            "--dry-run",
+        ];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Developer(Developer::TransferPublic(transfer)) = cli.command {
+            assert_eq!(transfer.amount, 100);
+            assert_eq!(transfer.options.private_key, "PRIVATE_KEY");
+            assert_eq!(transfer.options.query, "QUERY");
+            assert_eq!(transfer.options.priority_fee, Some(7));
+            assert_eq!(transfer.options.fee_record, None);
+            assert!(transfer.options.dry_run);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_transfer_public_validation() {
+        let private_key = "APrivateKey1zkp2n22c19hNdGF8wuEoQcuiyuWbquY6up4CtG5DYKqPX2X";
+        let parse = |args: &[&str]| {
+            let base = ["snarkos", "--private-key", private_key];
+            TransferPublic::try_parse_from(base.iter().chain(args.iter())).unwrap().parse().unwrap_err().to_string()
+        };
+        let recipient = "aleo1zecnqchckrzw7dlsyf65g6z5le2rmys403ecwmcafrag0e030yxqrnlg8j";
+
+        // Ensure an action is required.
+        let error = parse(&["--query", "", "--recipient", recipient, "--amount", "1"]);
+        assert!(error.contains("Please specify one of the following actions"), "{error}");
+        // Ensure the amount is nonzero.
+        let error = parse(&["--query", "", "--recipient", recipient, "--amount", "0", "--dry-run"]);
+        assert!(error.contains("The amount of microcredits must be greater than zero"), "{error}");
+        // Ensure the recipient is not the sender.
+        let sender = Address::<CurrentNetwork>::try_from(PrivateKey::<CurrentNetwork>::from_str(private_key).unwrap())
+            .unwrap()
+            .to_string();
+        let error = parse(&["--query", "", "--recipient", &sender, "--amount", "1", "--dry-run"]);
+        assert!(error.contains("The recipient must be different from the sender"), "{error}");
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ensure_nonzero_amount, CreditsOptions};
+use crate::helpers::progress;
+
+use snarkvm::prelude::Value;
+
+use anyhow::Result;
+use clap::Parser;
+use std::str::FromStr;
+
+/// Executes the `unbond_public` function in the `credits.aleo` program.
+#[derive(Debug, Parser)]
+pub struct UnbondPublic {
+    /// The number of microcredits to unbond.
+    #[clap(long)]
+    amount: u64,
+    /// The options for the transaction.
+    #[clap(flatten)]
+    options: CreditsOptions,
+}
+
+impl UnbondPublic {
+    /// Creates an unbond with the provided inputs.
+    pub fn parse(self) -> Result<String> {
+        // Validate the inputs.
+        self.options.ensure_action()?;
+        ensure_nonzero_amount(self.amount)?;
+
+        progress!("📦 Creating unbond of {} microcredits...\n", self.amount);
+
+        // Prepare the inputs for the unbond.
+        let inputs = vec![Value::from_str(&format!("{}u64", self.amount))?];
+        // Create the transaction, and broadcast, store, or display it.
+        self.options.execute("unbond_public", inputs, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, Developer, CLI};
+
+    #[test]
+    fn clap_snarkos_unbond_public() {
+        let arg_vec = vec![
+            "snarkos",
+            "developer",
+            "unbond-public",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--query",
+            "QUERY",
+            "--amount",
+            "1000000",
+            "--priority-fee",
+            "7",
+            "--broadcast",
+            "ENDPOINT",
+        ];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Developer(Developer::UnbondPublic(unbond)) = cli.command {
+            assert_eq!(unbond.amount, 1000000);
+            assert_eq!(unbond.options.private_key, "PRIVATE_KEY");
+            assert_eq!(unbond.options.priority_fee, Some(7));
+            assert_eq!(unbond.options.broadcast, Some("ENDPOINT".into()));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_unbond_public_validation() {
+        let parse = |args: &[&str]| {
+            let base = [
+                "snarkos",
+                "--private-key",
+                "APrivateKey1zkp2n22c19hNdGF8wuEoQcuiyuWbquY6up4CtG5DYKqPX2X",
+                "--query",
+                "",
+            ];
+            UnbondPublic::try_parse_from(base.iter().chain(args.iter())).unwrap().parse().unwrap_err().to_string()
+        };
+
+        // Ensure an action is required.
+        let error = parse(&["--amount", "1"]);
+        assert!(error.contains("Please specify one of the following actions"), "{error}");
+        // Ensure the amount is nonzero.
+        let error = parse(&["--amount", "0", "--dry-run"]);
+        assert!(error.contains("The amount of microcredits must be greater than zero"), "{error}");
+    }
+}
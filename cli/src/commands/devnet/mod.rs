@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod run;
+pub use run::*;
+
+mod status;
+pub use status::*;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// The default directory for the logs and the state of the devnet.
+const DEFAULT_DEVNET_DIR: &str = ".logs-devnet";
+/// The name of the file holding the state of a running devnet.
+const STATE_FILE: &str = "devnet.json";
+
+/// Commands to run a local development network.
+#[derive(Debug, Parser)]
+pub enum Devnet {
+    /// Launch a local devnet, until it reaches a target height or Ctrl-C is pressed.
+    Run(DevnetRun),
+    /// Show the status of each node in a running devnet.
+    Status(DevnetStatus),
+}
+
+impl Devnet {
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::Run(run) => run.parse(),
+            Self::Status(status) => status.parse(),
+        }
+    }
+}
+
+/// The type of a node in the devnet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum DevnetNodeType {
+    Validator,
+    Client,
+    Prover,
+}
+
+impl DevnetNodeType {
+    /// Returns the name of the node type.
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Validator => "validator",
+            Self::Client => "client",
+            Self::Prover => "prover",
+        }
+    }
+}
+
+/// A node in the devnet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct DevnetNode {
+    /// The name of the node, made of its type and its index among the nodes of that type.
+    name: String,
+    /// The type of the node.
+    node_type: DevnetNodeType,
+    /// The development ID of the node.
+    dev: u16,
+    /// The process ID of the node, once launched.
+    pid: Option<u32>,
+    /// The log file of the node.
+    logfile: PathBuf,
+}
+
+impl DevnetNode {
+    /// Returns the nodes of a devnet, with the validators first, followed by the clients and the provers.
+    fn layout(validators: u16, clients: u16, provers: u16, dir: &Path) -> Vec<Self> {
+        let node_types = [
+            (DevnetNodeType::Validator, validators),
+            (DevnetNodeType::Client, clients),
+            (DevnetNodeType::Prover, provers),
+        ];
+        let mut dev = 0;
+        let mut nodes = Vec::new();
+        for (node_type, count) in node_types {
+            for index in 0..count {
+                let name = format!("{}-{index}", node_type.as_str());
+                let logfile = dir.join(format!("{name}.log"));
+                nodes.push(Self { name, node_type, dev, pid: None, logfile });
+                dev += 1;
+            }
+        }
+        nodes
+    }
+
+    /// Returns the arguments to start the node.
+    fn start_args(&self, num_validators: u16, verbosity: u8) -> Vec<String> {
+        vec![
+            "start".to_string(),
+            "--nodisplay".to_string(),
+            format!("--{}", self.node_type.as_str()),
+            "--dev".to_string(),
+            self.dev.to_string(),
+            "--dev-num-validators".to_string(),
+            num_validators.to_string(),
+            "--verbosity".to_string(),
+            verbosity.to_string(),
+            "--logfile".to_string(),
+            self.logfile.display().to_string(),
+        ]
+    }
+
+    /// Returns the REST port of the node, if it serves the REST API.
+    fn rest_port(&self) -> Option<u16> {
+        match self.node_type {
+            DevnetNodeType::Prover => None,
+            _ => Some(3030 + self.dev),
+        }
+    }
+
+    /// Returns the status of the node, using its REST API.
+    /// If it is not known whether the node is running, it is deemed running if its REST API responds.
+    fn status(&self, running: Option<bool>) -> NodeStatus {
+        let (height, peers) = match self.rest_port() {
+            Some(port) => {
+                let agent = ureq::AgentBuilder::new().timeout(Duration::from_secs(2)).build();
+                let get = |path: &str| -> Option<String> {
+                    agent.get(&format!("http://127.0.0.1:{port}/testnet3/{path}")).call().ok()?.into_string().ok()
+                };
+                let height = get("latest/height").and_then(|height| height.trim().parse().ok());
+                let peers = get("peers/count").and_then(|peers| peers.trim().parse().ok());
+                (height, peers)
+            }
+            None => (None, None),
+        };
+        let running = running.unwrap_or(height.is_some());
+        NodeStatus { name: self.name.clone(), pid: self.pid, running, height, peers }
+    }
+}
+
+/// The state of a running devnet.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DevnetState {
+    /// The nodes of the devnet.
+    nodes: Vec<DevnetNode>,
+}
+
+impl DevnetState {
+    /// Reads the state of the devnet from the given directory.
+    fn open(dir: &Path) -> Result<Self> {
+        let path = dir.join(STATE_FILE);
+        let state =
+            std::fs::read_to_string(&path).with_context(|| format!("No devnet is running in '{}'", dir.display()))?;
+        Ok(serde_json::from_str(&state)?)
+    }
+
+    /// Writes the state of the devnet into the given directory.
+    fn save(&self, dir: &Path) -> Result<()> {
+        Ok(std::fs::write(dir.join(STATE_FILE), serde_json::to_string_pretty(self)?)?)
+    }
+
+    /// Removes the state of the devnet from the given directory.
+    fn remove(dir: &Path) -> Result<()> {
+        Ok(std::fs::remove_file(dir.join(STATE_FILE))?)
+    }
+}
+
+/// The status of a node in the devnet.
+#[derive(Debug, Serialize)]
+struct NodeStatus {
+    /// The name of the node.
+    name: String,
+    /// The process ID of the node.
+    pid: Option<u32>,
+    /// Whether the node is running.
+    running: bool,
+    /// The latest block height of the node.
+    height: Option<u32>,
+    /// The number of peers connected to the node.
+    peers: Option<usize>,
+}
+
+/// Formats the status of the nodes as a table.
+fn format_statuses(statuses: &[NodeStatus]) -> String {
+    let unknown = || "-".to_string();
+    let mut table = format!("{:<14} {:>8} {:>8} {:>8} {:>6}", "NODE", "PID", "STATE", "HEIGHT", "PEERS");
+    for status in statuses {
+        table.push_str(&format!(
+            "\n{:<14} {:>8} {:>8} {:>8} {:>6}",
+            status.name,
+            status.pid.map(|pid| pid.to_string()).unwrap_or_else(unknown),
+            if status.running { "running" } else { "stopped" },
+            status.height.map(|height| height.to_string()).unwrap_or_else(unknown),
+            status.peers.map(|peers| peers.to_string()).unwrap_or_else(unknown),
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout() {
+        let nodes = DevnetNode::layout(4, 1, 1, Path::new("logs"));
+        assert_eq!(nodes.len(), 6);
+        assert_eq!(nodes[0].name, "validator-0");
+        assert_eq!(nodes[3].name, "validator-3");
+        assert_eq!((nodes[4].name.as_str(), nodes[4].dev), ("client-0", 4));
+        assert_eq!((nodes[5].name.as_str(), nodes[5].dev), ("prover-0", 5));
+        assert_eq!(nodes[5].logfile, PathBuf::from("logs").join("prover-0.log"));
+
+        // Ensure only validators and clients serve the REST API.
+        assert_eq!(nodes[0].rest_port(), Some(3030));
+        assert_eq!(nodes[4].rest_port(), Some(3034));
+        assert_eq!(nodes[5].rest_port(), None);
+
+        let args = nodes[4].start_args(4, 1);
+        assert_eq!(&args[..5], ["start", "--nodisplay", "--client", "--dev", "4"]);
+    }
+
+    #[test]
+    fn test_devnet_state() {
+        let dir = std::env::temp_dir().join(format!("snarkos-devnet-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(DevnetState::open(&dir).is_err());
+
+        let state = DevnetState { nodes: DevnetNode::layout(4, 0, 0, &dir) };
+        state.save(&dir).unwrap();
+        assert_eq!(DevnetState::open(&dir).unwrap(), state);
+
+        DevnetState::remove(&dir).unwrap();
+        assert!(DevnetState::open(&dir).is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{format_statuses, DevnetNode, DevnetState, DEFAULT_DEVNET_DIR, STATE_FILE};
+use crate::{
+    commands::{Clean, DEVELOPMENT_MODE_NUM_GENESIS_COMMITTEE_MEMBERS},
+    helpers::{format_output, progress},
+};
+
+use snarkvm::prelude::Network;
+
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+/// The time given to the nodes to shut down gracefully, before they are killed.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Launches a local devnet, until it reaches a target height or Ctrl-C is pressed.
+#[derive(Debug, Parser)]
+pub struct DevnetRun {
+    /// The number of validators to launch.
+    #[clap(long, default_value = "4")]
+    validators: u16,
+    /// The number of clients to launch.
+    #[clap(long, default_value = "0")]
+    clients: u16,
+    /// The number of provers to launch.
+    #[clap(long, default_value = "0")]
+    provers: u16,
+    /// The directory for the logs of the nodes and the state of the devnet.
+    #[clap(long, default_value = DEFAULT_DEVNET_DIR)]
+    dir: PathBuf,
+    /// Removes the ledgers and logs of the nodes before launching them.
+    #[clap(long)]
+    clean: bool,
+    /// Stops the devnet once every node reaches the given height.
+    #[clap(long)]
+    height: Option<u32>,
+    /// Fails if the target height is not reached within the given number of seconds.
+    #[clap(long, requires = "height")]
+    timeout: Option<u64>,
+    /// The number of seconds between the status updates of the nodes.
+    #[clap(long, default_value = "5")]
+    interval: u64,
+    /// The verbosity of the node logs [options: 0, 1, 2, 3, 4]
+    #[clap(long, default_value = "1")]
+    verbosity: u8,
+}
+
+impl DevnetRun {
+    /// Runs the devnet.
+    pub fn parse(self) -> Result<String> {
+        ensure!(
+            self.validators >= DEVELOPMENT_MODE_NUM_GENESIS_COMMITTEE_MEMBERS,
+            "A devnet requires at least {DEVELOPMENT_MODE_NUM_GENESIS_COMMITTEE_MEMBERS} validators"
+        );
+        ensure!(
+            !self.dir.join(STATE_FILE).exists(),
+            "A devnet is already running in '{}' - if it is not, remove '{}'",
+            self.dir.display(),
+            self.dir.join(STATE_FILE).display()
+        );
+
+        let mut nodes = DevnetNode::layout(self.validators, self.clients, self.provers, &self.dir);
+        // Remove the ledgers and logs of the nodes, if requested.
+        if self.clean {
+            for node in &nodes {
+                Clean::remove_ledger(CurrentNetwork::ID, Some(node.dev))?;
+                if node.logfile.exists() {
+                    std::fs::remove_file(&node.logfile)?;
+                }
+            }
+        }
+        std::fs::create_dir_all(&self.dir)?;
+
+        // Launch the nodes.
+        let stop = Self::ctrl_c_flag()?;
+        let exe = std::env::current_exe()?;
+        let mut children = Vec::with_capacity(nodes.len());
+        for node in nodes.iter_mut() {
+            match Self::launch(&exe, node, self.validators, self.verbosity) {
+                Ok(child) => {
+                    node.pid = Some(child.id());
+                    children.push(child);
+                }
+                Err(error) => {
+                    Self::shut_down(&mut children);
+                    bail!("Failed to launch the node '{}' - {error}", node.name);
+                }
+            }
+            progress!("🚀 Launched the node '{}' (logs in '{}')", node.name, node.logfile.display());
+        }
+
+        // Supervise the nodes, and stop them once done.
+        let state = DevnetState { nodes };
+        let result = state.save(&self.dir).and_then(|_| self.supervise(&state.nodes, &mut children, &stop));
+        progress!("\n🛑 Stopping the devnet...");
+        Self::shut_down(&mut children);
+        DevnetState::remove(&self.dir)?;
+        result
+    }
+
+    /// Returns a flag that is set once Ctrl-C is pressed.
+    fn ctrl_c_flag() -> Result<Arc<AtomicBool>> {
+        let stop = Arc::new(AtomicBool::new(false));
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+        let stop_clone = stop.clone();
+        std::thread::spawn(move || {
+            runtime.block_on(async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    stop_clone.store(true, Ordering::SeqCst);
+                }
+            })
+        });
+        Ok(stop)
+    }
+
+    /// Launches the given node, writing its logs and errors into its log file.
+    fn launch(exe: &Path, node: &DevnetNode, num_validators: u16, verbosity: u8) -> Result<Child> {
+        let mut command = Command::new(exe);
+        command.args(node.start_args(num_validators, verbosity));
+        command.stdin(Stdio::null()).stdout(Stdio::null());
+        command.stderr(File::options().create(true).append(true).open(&node.logfile)?);
+        // Launch the node in its own process group, so that Ctrl-C is handled by the devnet,
+        // which then stops every node, including ones that are still starting up.
+        #[cfg(target_family = "unix")]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        Ok(command.spawn()?)
+    }
+
+    /// Waits until Ctrl-C is pressed or the nodes reach the target height, reporting their status.
+    fn supervise(&self, nodes: &[DevnetNode], children: &mut [Child], stop: &AtomicBool) -> Result<String> {
+        let start = Instant::now();
+        let mut last_report: Option<Instant> = None;
+        loop {
+            if stop.load(Ordering::SeqCst) {
+                let statuses = nodes.iter().map(|node| node.status(Some(true))).collect::<Vec<_>>();
+                return format_output(&statuses, || "✅ Stopped the devnet".to_string());
+            }
+            // Ensure every node is still running.
+            for (node, child) in nodes.iter().zip(children.iter_mut()) {
+                if let Some(exit_status) = child.try_wait()? {
+                    bail!("The node '{}' exited ({exit_status}), see '{}'", node.name, node.logfile.display());
+                }
+            }
+            // Report the status of the nodes, and check if they reached the target height.
+            if last_report.map_or(true, |report| report.elapsed() >= Duration::from_secs(self.interval)) {
+                let statuses = nodes.iter().map(|node| node.status(Some(true))).collect::<Vec<_>>();
+                progress!("\n{}", format_statuses(&statuses));
+                if let Some(height) = self.height {
+                    // Provers do not serve the REST API, so their height is not known.
+                    let reached = nodes
+                        .iter()
+                        .zip(&statuses)
+                        .filter(|(node, _)| node.rest_port().is_some())
+                        .all(|(_, status)| status.height >= Some(height));
+                    if reached {
+                        return format_output(&statuses, || format!("✅ The devnet reached block {height}"));
+                    }
+                }
+                last_report = Some(Instant::now());
+            }
+            if let (Some(height), Some(timeout)) = (self.height, self.timeout) {
+                ensure!(
+                    start.elapsed() < Duration::from_secs(timeout),
+                    "The devnet did not reach block {height} within {timeout} seconds"
+                );
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+    }
+
+    /// Stops the given nodes, giving them time to shut down gracefully before killing them.
+    fn shut_down(children: &mut [Child]) {
+        for child in children.iter_mut() {
+            #[cfg(target_family = "unix")]
+            {
+                use nix::{
+                    sys::signal::{kill, Signal},
+                    unistd::Pid,
+                };
+                let _ = kill(Pid::from_raw(child.id() as i32), Signal::SIGINT);
+            }
+            #[cfg(not(target_family = "unix"))]
+            let _ = child.kill();
+        }
+        let deadline = Instant::now() + SHUTDOWN_TIMEOUT;
+        for child in children.iter_mut() {
+            while matches!(child.try_wait(), Ok(None)) && Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command as SnarkOSCommand, Devnet, CLI};
+
+    #[test]
+    fn clap_snarkos_devnet_run() {
+        let cli = CLI::parse_from(["snarkos", "devnet", "run", "--clients", "1", "--height", "10", "--timeout", "600"]);
+        if let SnarkOSCommand::Devnet(Devnet::Run(run)) = cli.command {
+            assert_eq!(run.validators, 4);
+            assert_eq!(run.clients, 1);
+            assert_eq!(run.provers, 0);
+            assert_eq!(run.dir, PathBuf::from(DEFAULT_DEVNET_DIR));
+            assert!(!run.clean);
+            assert_eq!(run.height, Some(10));
+            assert_eq!(run.timeout, Some(600));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        // Ensure the timeout requires a target height.
+        assert!(CLI::try_parse_from(["snarkos", "devnet", "run", "--timeout", "600"]).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{format_statuses, DevnetState, DEFAULT_DEVNET_DIR};
+use crate::helpers::format_output;
+
+use anyhow::Result;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Shows the status of each node in a running devnet.
+#[derive(Debug, Parser)]
+pub struct DevnetStatus {
+    /// The directory of the devnet, as given to 'snarkos devnet run'.
+    #[clap(long, default_value = DEFAULT_DEVNET_DIR)]
+    dir: PathBuf,
+}
+
+impl DevnetStatus {
+    /// Returns the status of each node in the devnet.
+    pub fn parse(self) -> Result<String> {
+        let state = DevnetState::open(&self.dir)?;
+        let statuses =
+            state.nodes.iter().map(|node| node.status(node.pid.and_then(is_process_running))).collect::<Vec<_>>();
+        format_output(&statuses, || format_statuses(&statuses))
+    }
+}
+
+/// Returns whether the process with the given ID is running, if this can be determined on the platform.
+#[cfg(target_family = "unix")]
+fn is_process_running(pid: u32) -> Option<bool> {
+    use nix::{sys::signal::kill, unistd::Pid};
+    // Sending no signal only checks that the process exists.
+    Some(kill(Pid::from_raw(pid as i32), None).is_ok())
+}
+
+/// Returns whether the process with the given ID is running, if this can be determined on the platform.
+#[cfg(not(target_family = "unix"))]
+fn is_process_running(_pid: u32) -> Option<bool> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, Devnet, CLI};
+
+    #[test]
+    fn clap_snarkos_devnet_status() {
+        let cli = CLI::parse_from(["snarkos", "devnet", "status"]);
+        if let Command::Devnet(Devnet::Status(status)) = cli.command {
+            assert_eq!(status.dir, PathBuf::from(DEFAULT_DEVNET_DIR));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
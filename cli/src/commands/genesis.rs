@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{format_output, progress};
+
+use snarkvm::{
+    console::account::{Address, PrivateKey},
+    ledger::{
+        committee::{Committee, MIN_VALIDATOR_STAKE},
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    },
+    prelude::{Network, ToBytes},
+    synthesizer::VM,
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use clap::Parser;
+use core::str::FromStr;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+/// A member of the genesis committee, as given in the committee file.
+#[derive(Debug, Deserialize)]
+struct CommitteeMember {
+    /// The stake of the member, in microcredits.
+    stake: u64,
+    /// Whether the member is open to stakers.
+    #[serde(default = "CommitteeMember::default_is_open")]
+    is_open: bool,
+}
+
+impl CommitteeMember {
+    /// Returns the default of whether a member is open to stakers.
+    const fn default_is_open() -> bool {
+        true
+    }
+}
+
+/// Generates a genesis block for a private network, from a committee and public balances.
+#[derive(Debug, Parser)]
+pub struct Genesis {
+    /// The private key of the account creating the genesis block.
+    #[clap(long = "private-key")]
+    private_key: String,
+    /// The JSON file mapping each validator address to its stake in microcredits,
+    /// e.g. {"aleo1...": {"stake": 10000000000000, "is_open": true}}.
+    #[clap(long)]
+    committee: PathBuf,
+    /// The JSON file mapping each address to its public balance in microcredits, e.g. {"aleo1...": 1000000}.
+    #[clap(long)]
+    balances: PathBuf,
+    /// The file to write the genesis block to.
+    #[clap(long, default_value = "genesis.block")]
+    out: PathBuf,
+}
+
+impl Genesis {
+    /// Generates the genesis block.
+    pub fn parse(self) -> Result<String> {
+        ensure!(!self.out.exists(), "The file '{}' already exists", self.out.display());
+
+        // Parse the inputs.
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(self.private_key.trim())?;
+        let committee = Self::parse_committee(&std::fs::read_to_string(&self.committee)?)
+            .with_context(|| format!("Invalid committee file '{}'", self.committee.display()))?;
+        let public_balances = Self::parse_balances(&std::fs::read_to_string(&self.balances)?)
+            .with_context(|| format!("Invalid balances file '{}'", self.balances.display()))?;
+        Self::check_supply(&committee, &public_balances)?;
+
+        // Compute the genesis block.
+        progress!("📦 Computing the genesis block for {} validators...\n", committee.members().len());
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None)?)?;
+        let block = vm.genesis_quorum(&private_key, committee, public_balances, &mut rand::thread_rng())?;
+        std::fs::write(&self.out, block.to_bytes_le()?)?;
+
+        let output = serde_json::json!({ "path": self.out, "hash": block.hash().to_string() });
+        format_output(&output, || format!("✅ Wrote the genesis block {} to '{}'", block.hash(), self.out.display()))
+    }
+
+    /// Parses the genesis committee from the contents of a committee file.
+    fn parse_committee(committee: &str) -> Result<Committee<CurrentNetwork>> {
+        let members: IndexMap<String, CommitteeMember> = serde_json::from_str(committee)?;
+        let members = members
+            .into_iter()
+            .map(|(address, member)| {
+                let address = Address::from_str(&address)?;
+                ensure!(
+                    member.stake >= MIN_VALIDATOR_STAKE,
+                    "The stake of {address} is below the minimum of {MIN_VALIDATOR_STAKE} microcredits"
+                );
+                Ok((address, (member.stake, member.is_open)))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+        Committee::new(0u64, members)
+    }
+
+    /// Parses the public balances from the contents of a balances file.
+    fn parse_balances(balances: &str) -> Result<IndexMap<Address<CurrentNetwork>, u64>> {
+        let balances: IndexMap<String, u64> = serde_json::from_str(balances)?;
+        balances.into_iter().map(|(address, balance)| Ok((Address::from_str(&address)?, balance))).collect()
+    }
+
+    /// Ensures the committee stakes and the public balances add up to the starting supply.
+    fn check_supply(
+        committee: &Committee<CurrentNetwork>,
+        public_balances: &IndexMap<Address<CurrentNetwork>, u64>,
+    ) -> Result<()> {
+        let total =
+            public_balances.values().try_fold(committee.total_stake(), |total, balance| total.checked_add(*balance));
+        match total {
+            Some(total) if total == CurrentNetwork::STARTING_SUPPLY => Ok(()),
+            Some(total) => bail!(
+                "The committee stakes and public balances add up to {total} microcredits, instead of the starting supply of {}",
+                CurrentNetwork::STARTING_SUPPLY
+            ),
+            None => bail!("The committee stakes and public balances overflow"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+    use snarkvm::prelude::TestRng;
+
+    /// Returns the given number of new addresses.
+    fn sample_addresses(num_addresses: usize) -> Vec<Address<CurrentNetwork>> {
+        let rng = &mut TestRng::default();
+        (0..num_addresses)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn clap_snarkos_genesis() {
+        let cli = CLI::parse_from([
+            "snarkos",
+            "genesis",
+            "--private-key",
+            "PRIVATE_KEY",
+            "--committee",
+            "committee.json",
+            "--balances",
+            "balances.json",
+        ]);
+        if let Command::Genesis(genesis) = cli.command {
+            assert_eq!(genesis.private_key, "PRIVATE_KEY");
+            assert_eq!(genesis.committee, PathBuf::from("committee.json"));
+            assert_eq!(genesis.balances, PathBuf::from("balances.json"));
+            assert_eq!(genesis.out, PathBuf::from("genesis.block"));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_parse_genesis_inputs() {
+        let addresses = sample_addresses(5);
+        let stake = CurrentNetwork::STARTING_SUPPLY / 8;
+
+        // Parse the committee, with the members open to stakers by default.
+        let committee = format!(
+            r#"{{"{}": {{"stake": {stake}}}, "{}": {{"stake": {stake}}}, "{}": {{"stake": {stake}}}, "{}": {{"stake": {stake}, "is_open": false}}}}"#,
+            addresses[0], addresses[1], addresses[2], addresses[3]
+        );
+        let committee = Genesis::parse_committee(&committee).unwrap();
+        assert_eq!(committee.members().len(), 4);
+        assert_eq!(committee.members().get(&addresses[0]), Some(&(stake, true)));
+        assert_eq!(committee.members().get(&addresses[3]), Some(&(stake, false)));
+
+        // Ensure the supply must be fully allocated.
+        let remaining = CurrentNetwork::STARTING_SUPPLY - committee.total_stake();
+        let balances = Genesis::parse_balances(&format!(r#"{{"{}": {remaining}}}"#, addresses[4])).unwrap();
+        assert!(Genesis::check_supply(&committee, &balances).is_ok());
+        let balances = Genesis::parse_balances(&format!(r#"{{"{}": {}}}"#, addresses[4], remaining - 1)).unwrap();
+        assert!(Genesis::check_supply(&committee, &balances).is_err());
+
+        // Ensure invalid members are rejected.
+        assert!(Genesis::parse_committee(&format!(r#"{{"{}": {{"stake": 1}}}}"#, addresses[0])).is_err());
+        assert!(Genesis::parse_committee(r#"{"aleo1invalid": {"stake": 10000000000000}}"#).is_err());
+        assert!(Genesis::parse_balances(r#"{"aleo1invalid": 1}"#).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentNetwork, Ledger};
+use crate::helpers::{format_output, progress};
+
+use snarkos_node_cdn::{write_blocks, write_latest_state, LatestState, BLOCKS_PER_FILE};
+use snarkvm::prelude::{store::ConsensusStorage, Ledger as SnarkVMLedger};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The name of the file indexing the block files in an export directory.
+pub(super) const INDEX_FILE: &str = "index.json";
+
+/// An entry in the index of an export directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct IndexEntry {
+    /// The name of the block file.
+    pub(super) file: String,
+    /// The height of the first block in the file.
+    pub(super) start: u32,
+    /// The height of the last block in the file.
+    pub(super) end: u32,
+}
+
+/// Exports blocks from the ledger into the CDN block file format.
+#[derive(Debug, Parser)]
+pub struct Export {
+    /// The height of the first block to export, rounded down to a multiple of 50.
+    #[clap(long, default_value = "0")]
+    start: u32,
+    /// The height of the last block to export. Defaults to the latest height.
+    #[clap(long)]
+    end: Option<u32>,
+    /// The directory to write the block files to.
+    #[clap(long)]
+    dir: PathBuf,
+    /// Enables development mode, specify the unique ID of the local node.
+    #[clap(long)]
+    dev: Option<u16>,
+}
+
+impl Export {
+    /// Exports the blocks from the ledger.
+    pub fn parse(self) -> Result<String> {
+        // Open the ledger.
+        let ledger = Ledger::open(self.dev, None)?;
+
+        // Determine the range of blocks to export.
+        let start = self.start - (self.start % BLOCKS_PER_FILE);
+        let end = self.end.unwrap_or_else(|| ledger.latest_height());
+        ensure!(start <= end, "The given export range is invalid (start = {start}, end = {end})");
+        ensure!(end <= ledger.latest_height(), "The ledger does not contain block {end}");
+
+        progress!("📦 Exporting blocks {start} to {end} into '{}'...\n", self.dir.display());
+        let index = Self::export_blocks(&ledger, &self.dir, start, end)?;
+
+        let output = serde_json::json!({ "start": start, "end": end, "files": index.len() });
+        format_output(&output, || {
+            format!("✅ Exported blocks {start} to {end} into {} files in '{}'", index.len(), self.dir.display())
+        })
+    }
+
+    /// Writes the blocks in the given range (inclusive) into block files, with their index
+    /// and the latest state, and returns the index.
+    pub(super) fn export_blocks<C: ConsensusStorage<CurrentNetwork>>(
+        ledger: &SnarkVMLedger<CurrentNetwork, C>,
+        dir: &Path,
+        start: u32,
+        end: u32,
+    ) -> Result<Vec<IndexEntry>> {
+        std::fs::create_dir_all(dir)?;
+
+        // Write the block files.
+        let mut index = Vec::new();
+        for file_start in (start..=end).step_by(BLOCKS_PER_FILE as usize) {
+            // Retrieve the blocks in the file.
+            let file_end = end.min(file_start + BLOCKS_PER_FILE - 1);
+            let blocks = (file_start..=file_end).map(|height| ledger.get_block(height)).collect::<Result<Vec<_>>>()?;
+            // Write the block file.
+            let path = write_blocks(dir, file_start, &blocks)?;
+            progress!("Exported blocks {file_start} to {file_end}");
+
+            let file = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            index.push(IndexEntry { file, start: file_start, end: file_end });
+        }
+
+        // Write the index.
+        std::fs::write(dir.join(INDEX_FILE), serde_json::to_string_pretty(&index)?)?;
+        // Write the latest state.
+        let hash = ledger.get_hash(end)?.to_string();
+        write_latest_state(dir, &LatestState { exclusive_height: end + 1, inclusive_height: end, hash })?;
+
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+
+    #[test]
+    fn clap_snarkos_ledger_export() {
+        let cli = CLI::parse_from(["snarkos", "ledger", "export", "--start", "100", "--dir", "blocks"]);
+        if let Command::Ledger(Ledger::Export(export)) = cli.command {
+            assert_eq!(export.start, 100);
+            assert_eq!(export.end, None);
+            assert_eq!(export.dir, PathBuf::from("blocks"));
+            assert_eq!(export.dev, None);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
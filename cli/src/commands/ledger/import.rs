@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    export::{IndexEntry, INDEX_FILE},
+    CurrentNetwork,
+    Ledger,
+};
+use crate::helpers::{format_output, progress};
+
+use snarkos_node_cdn::sync_ledger_with_cdn;
+use snarkvm::prelude::{block::Block, store::ConsensusStorage, Ledger as SnarkVMLedger};
+
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use std::path::{Path, PathBuf};
+
+/// Imports blocks into the ledger, from a directory in the CDN block file format.
+#[derive(Debug, Parser)]
+pub struct Import {
+    /// The directory to read the block files from, as written by `snarkos ledger export`.
+    #[clap(long)]
+    dir: PathBuf,
+    /// Enables development mode, specify the unique ID of the local node.
+    #[clap(long)]
+    dev: Option<u16>,
+}
+
+impl Import {
+    /// Imports the blocks into the ledger.
+    pub fn parse(self) -> Result<String> {
+        // Read the index of the block files.
+        let index: Vec<IndexEntry> = serde_json::from_str(&std::fs::read_to_string(self.dir.join(INDEX_FILE))?)?;
+        let (Some(first), Some(last)) = (index.first(), index.last()) else {
+            bail!("The directory '{}' does not contain any block files", self.dir.display());
+        };
+
+        // Open the ledger, initializing an empty ledger with the exported genesis block.
+        let genesis = match first.start {
+            0 => Some(Self::read_genesis(&self.dir, first)?),
+            _ => None,
+        };
+        let ledger = Ledger::open(self.dev, genesis)?;
+        let start = ledger.latest_height() + 1;
+
+        // Ensure the block files continue the ledger.
+        if start > last.end {
+            bail!("The ledger is already at height {}, which includes the exported blocks", start - 1);
+        }
+        if start < first.start {
+            bail!("The block files start at height {}, but the ledger is only at height {}", first.start, start - 1);
+        }
+
+        progress!("📦 Importing blocks {start} to {} from '{}'...\n", last.end, self.dir.display());
+
+        // Load the blocks into the ledger.
+        let height = Self::import_blocks(&self.dir, ledger)?;
+
+        let output = serde_json::json!({ "start": start, "end": height });
+        format_output(&output, || format!("✅ Imported blocks {start} to {height} into the ledger"))
+    }
+
+    /// Loads the blocks from the given directory into the ledger, and returns the height of the last imported block.
+    pub(super) fn import_blocks<C: ConsensusStorage<CurrentNetwork>>(
+        dir: &Path,
+        ledger: SnarkVMLedger<CurrentNetwork, C>,
+    ) -> Result<u32> {
+        let base_url = format!("file://{}", dir.canonicalize()?.display());
+        let rt = tokio::runtime::Runtime::new()?;
+        rt.block_on(sync_ledger_with_cdn(&base_url, ledger))
+            .map_err(|(height, error)| anyhow!("Failed to import the blocks after height {height} - {error}"))
+    }
+
+    /// Reads the genesis block from the first block file.
+    fn read_genesis(dir: &Path, entry: &IndexEntry) -> Result<Block<CurrentNetwork>> {
+        let blocks: Vec<Block<CurrentNetwork>> = bincode::deserialize(&std::fs::read(dir.join(&entry.file))?)?;
+        match blocks.into_iter().next() {
+            Some(block) if block.height() == 0 => Ok(block),
+            _ => bail!("The block file '{}' does not start with the genesis block", entry.file),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{
+        ledger::{test_helpers::sample_ledger, Export},
+        Command,
+        CLI,
+    };
+    use snarkvm::prelude::{TestRng, Uniform};
+
+    #[test]
+    fn clap_snarkos_ledger_import() {
+        let cli = CLI::parse_from(["snarkos", "ledger", "import", "--dir", "blocks", "--dev", "1"]);
+        if let Command::Ledger(Ledger::Import(import)) = cli.command {
+            assert_eq!(import.dir, PathBuf::from("blocks"));
+            assert_eq!(import.dev, Some(1));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let rng = &mut TestRng::default();
+        let ledger = sample_ledger(3, rng);
+        let dir = std::env::temp_dir().join(format!("snarkos-ledger-export-{}", u64::rand(rng)));
+
+        // Export the blocks.
+        let index = Export::export_blocks(&ledger, &dir, 0, 3).unwrap();
+        assert_eq!(index, vec![IndexEntry { file: index[0].file.clone(), start: 0, end: 3 }]);
+
+        // Import the blocks into a new ledger, initialized with the exported genesis block.
+        let genesis = Import::read_genesis(&dir, &index[0]).unwrap();
+        assert_eq!(genesis, ledger.get_block(0).unwrap());
+        let imported = SnarkVMLedger::<CurrentNetwork, _>::load(genesis, None).unwrap();
+        assert_eq!(Import::import_blocks(&dir, imported.clone()).unwrap(), 3);
+
+        // Ensure the new ledger contains the same blocks.
+        assert_eq!(imported.latest_height(), 3);
+        for height in 0..=3 {
+            assert_eq!(imported.get_block(height).unwrap(), ledger.get_block(height).unwrap());
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod export;
+pub use export::*;
+
+mod import;
+pub use import::*;
+
+mod rollback;
+pub use rollback::*;
+
+mod snapshot;
+pub use snapshot::*;
+
+mod verify;
+pub use verify::*;
+
+use snarkvm::prelude::{
+    block::Block,
+    store::{helpers::rocksdb::ConsensusDB, ConsensusStore},
+    FromBytes,
+    Ledger as SnarkVMLedger,
+    Network,
+};
+
+use anyhow::Result;
+use clap::Parser;
+use std::path::{Path, PathBuf};
+
+type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+/// The ledger of the local node.
+type LocalLedger = SnarkVMLedger<CurrentNetwork, ConsensusDB<CurrentNetwork>>;
+
+/// Returns the paths next to the given ledger directory, which hold the leftovers
+/// of interrupted rollbacks and snapshot restores.
+pub(crate) fn ledger_leftovers(ledger_dir: &Path) -> Vec<PathBuf> {
+    [ROLLBACK_EXTENSION, ROLLBACK_BACKUP_EXTENSION, SNAPSHOT_STAGING_EXTENSION, SNAPSHOT_DOWNLOAD_EXTENSION]
+        .into_iter()
+        .map(|extension| ledger_dir.with_extension(extension))
+        .collect()
+}
+
+/// Commands to manage the ledger of the local node.
+#[derive(Debug, Parser)]
+pub enum Ledger {
+    /// Export blocks from the ledger into block files.
+    Export(Export),
+    /// Import blocks from block files into the ledger.
+    Import(Import),
+    /// Roll back the ledger to a given height.
+    Rollback(Rollback),
+    /// Create a snapshot of the ledger storage.
+    Snapshot(Snapshot),
+    /// Re-verify the blocks in the ledger, and report the first invalid block.
+    Verify(Verify),
+}
+
+impl Ledger {
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::Export(export) => export.parse(),
+            Self::Import(import) => import.parse(),
+            Self::Rollback(rollback) => rollback.parse(),
+            Self::Snapshot(snapshot) => snapshot.parse(),
+            Self::Verify(verify) => verify.parse(),
+        }
+    }
+
+    /// Opens the ledger of the local node from storage. If the ledger is empty, it is initialized
+    /// with the given genesis block, or else with the genesis block of the network.
+    fn open(dev: Option<u16>, genesis: Option<Block<CurrentNetwork>>) -> Result<LocalLedger> {
+        // Load the genesis block from storage, as it is custom in development mode.
+        let stored_genesis = {
+            let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev)?;
+            match store.block_store().get_block_hash(0)? {
+                Some(hash) => store.block_store().get_block(&hash)?,
+                None => None,
+            }
+        };
+        let genesis = match stored_genesis.or(genesis) {
+            Some(genesis) => genesis,
+            None => Block::from_bytes_le(CurrentNetwork::genesis_bytes())?,
+        };
+        // Load the ledger.
+        LocalLedger::load(genesis, dev)
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::CurrentNetwork;
+    use snarkvm::prelude::{
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+        Ledger,
+        PrivateKey,
+        TestRng,
+        VM,
+    };
+
+    /// Returns an in-memory ledger with the given number of blocks after the genesis block.
+    pub(super) fn sample_ledger(
+        num_blocks: u32,
+        rng: &mut TestRng,
+    ) -> Ledger<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
+        let ledger = Ledger::load(genesis, None).unwrap();
+        for _ in 0..num_blocks {
+            let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+            ledger.advance_to_next_block(&block).unwrap();
+        }
+        ledger
+    }
+}
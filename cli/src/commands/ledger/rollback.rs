@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentNetwork, Export, Ledger};
+use crate::helpers::{format_output, progress};
+
+use snarkvm::prelude::Network;
+
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+/// The extension of the directory next to the ledger, holding the blocks to keep during a rollback.
+pub(super) const ROLLBACK_EXTENSION: &str = "rollback";
+/// The extension of the directory next to the ledger, holding the previous ledger until the rolled back one is verified.
+pub(super) const ROLLBACK_BACKUP_EXTENSION: &str = "rollback.backup";
+
+/// Rolls back the ledger to the given height, removing the later blocks and their finalize effects.
+/// This also clears the BFT storage, which is kept in the same database as the ledger.
+#[derive(Debug, Parser)]
+pub struct Rollback {
+    /// The height of the last block to keep in the ledger.
+    #[clap(long)]
+    to: u32,
+    /// Enables development mode, specify the unique ID of the local node.
+    #[clap(long)]
+    dev: Option<u16>,
+}
+
+impl Rollback {
+    /// Rolls back the ledger.
+    ///
+    /// The ledger does not support reverting finalize effects, so the blocks up to the target height
+    /// are exported, and re-imported into a new ledger, which replays finalize. The previous ledger
+    /// is kept aside until the new ledger is verified, and is restored if the rollback fails.
+    pub fn parse(self) -> Result<String> {
+        let ledger_dir = aleo_std::aleo_ledger_dir(CurrentNetwork::ID, self.dev);
+        let (staging_dir, backup_dir) = Self::staging_dirs(&ledger_dir);
+        ensure!(
+            !backup_dir.exists(),
+            "A previous rollback was interrupted - remove '{}' and move '{}' back to it to restore the ledger",
+            ledger_dir.display(),
+            backup_dir.display()
+        );
+        ensure!(
+            !staging_dir.exists(),
+            "A previous rollback was interrupted - remove '{}' before retrying",
+            staging_dir.display()
+        );
+
+        // Export the blocks to keep.
+        {
+            let ledger = Ledger::open(self.dev, None)?;
+            let latest_height = ledger.latest_height();
+            ensure!(self.to < latest_height, "The ledger is at height {latest_height}, which is not above {}", self.to);
+
+            progress!("📦 Saving blocks 0 to {} into '{}'...\n", self.to, staging_dir.display());
+            Export::export_blocks(&ledger, &staging_dir, 0, self.to)?;
+        }
+
+        progress!("\n📦 Restoring blocks 0 to {}...\n", self.to);
+        Self::replace_ledger(&ledger_dir, &staging_dir, &backup_dir, || self.import_blocks(&staging_dir))?;
+
+        let output = serde_json::json!({ "height": self.to });
+        format_output(&output, || format!("✅ Rolled back the ledger to block {}", self.to))
+    }
+
+    /// Imports the given blocks into a new ledger, and ensures it ends at the target height.
+    /// The blocks are imported in a new process, as the storage cannot be reopened in this one.
+    fn import_blocks(&self, staging_dir: &Path) -> Result<()> {
+        let mut command = Command::new(std::env::current_exe()?);
+        command.args(["--output", "json", "ledger", "import", "--dir"]).arg(staging_dir);
+        if let Some(dev) = self.dev {
+            command.args(["--dev", &dev.to_string()]);
+        }
+        // The progress of the import is shown on stderr, and its result is read from stdout.
+        let output = command.stdout(Stdio::piped()).stderr(Stdio::inherit()).output()?;
+        ensure!(output.status.success(), "The blocks could not be imported");
+
+        let result: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        ensure!(result["end"].as_u64() == Some(u64::from(self.to)), "The new ledger does not end at block {}", self.to);
+        Ok(())
+    }
+
+    /// Replaces the ledger with the one built by `import`. The previous ledger and BFT storage are kept aside
+    /// until the import succeeds, and are restored otherwise. The staging directory is removed in both cases.
+    fn replace_ledger(
+        ledger_dir: &Path,
+        staging_dir: &Path,
+        backup_dir: &Path,
+        import: impl FnOnce() -> Result<()>,
+    ) -> Result<()> {
+        std::fs::rename(ledger_dir, backup_dir)?;
+
+        if let Err(error) = import() {
+            // Restore the previous ledger.
+            if ledger_dir.exists() {
+                std::fs::remove_dir_all(ledger_dir)?;
+            }
+            std::fs::rename(backup_dir, ledger_dir)?;
+            std::fs::remove_dir_all(staging_dir)?;
+            bail!("Failed to roll back the ledger, which was left unchanged - {error}");
+        }
+        std::fs::remove_dir_all(backup_dir)?;
+        std::fs::remove_dir_all(staging_dir)?;
+        Ok(())
+    }
+
+    /// Returns the directories next to the ledger, which hold the blocks to keep and the previous ledger during a rollback.
+    fn staging_dirs(ledger_dir: &Path) -> (PathBuf, PathBuf) {
+        (ledger_dir.with_extension(ROLLBACK_EXTENSION), ledger_dir.with_extension(ROLLBACK_BACKUP_EXTENSION))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{
+        ledger::{test_helpers::sample_ledger, Import},
+        Command as SnarkOSCommand,
+        CLI,
+    };
+    use snarkvm::prelude::{Ledger as SnarkVMLedger, TestRng, Uniform};
+
+    /// Returns a new ledger directory, with its staging and backup directories, in a temporary directory.
+    fn sample_dirs(rng: &mut TestRng) -> (PathBuf, PathBuf, PathBuf) {
+        let ledger_dir = std::env::temp_dir().join(format!("snarkos-ledger-{}", u64::rand(rng)));
+        std::fs::create_dir_all(&ledger_dir).unwrap();
+        std::fs::write(ledger_dir.join("previous"), "").unwrap();
+        let (staging_dir, backup_dir) = Rollback::staging_dirs(&ledger_dir);
+        std::fs::create_dir_all(&staging_dir).unwrap();
+        (ledger_dir, staging_dir, backup_dir)
+    }
+
+    #[test]
+    fn clap_snarkos_ledger_rollback() {
+        let cli = CLI::parse_from(["snarkos", "ledger", "rollback", "--to", "100", "--dev", "2"]);
+        if let SnarkOSCommand::Ledger(Ledger::Rollback(rollback)) = cli.command {
+            assert_eq!(rollback.to, 100);
+            assert_eq!(rollback.dev, Some(2));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_staging_dirs() {
+        let ledger_dir = aleo_std::aleo_ledger_dir(CurrentNetwork::ID, Some(2));
+        let (staging_dir, backup_dir) = Rollback::staging_dirs(&ledger_dir);
+        assert_eq!(staging_dir.parent(), ledger_dir.parent());
+        assert_eq!(backup_dir.parent(), ledger_dir.parent());
+        assert_ne!(staging_dir, ledger_dir);
+        assert_ne!(backup_dir, ledger_dir);
+        assert_ne!(staging_dir, backup_dir);
+    }
+
+    #[test]
+    fn test_rollback_blocks() {
+        let rng = &mut TestRng::default();
+        let ledger = sample_ledger(4, rng);
+        let dir = std::env::temp_dir().join(format!("snarkos-ledger-rollback-{}", u64::rand(rng)));
+
+        // Save the blocks to keep, and restore them into a new ledger.
+        Export::export_blocks(&ledger, &dir, 0, 2).unwrap();
+        let rolled_back = SnarkVMLedger::<CurrentNetwork, _>::load(ledger.get_block(0).unwrap(), None).unwrap();
+        assert_eq!(Import::import_blocks(&dir, rolled_back.clone()).unwrap(), 2);
+
+        // Ensure the new ledger ends at the target height, and does not contain the later blocks.
+        assert_eq!(rolled_back.latest_height(), 2);
+        assert_eq!(rolled_back.latest_hash(), ledger.get_hash(2).unwrap());
+        for height in 3..=4 {
+            assert!(!rolled_back.contains_block_hash(&ledger.get_hash(height).unwrap()).unwrap());
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_replace_ledger() {
+        let rng = &mut TestRng::default();
+        let (ledger_dir, staging_dir, backup_dir) = sample_dirs(rng);
+
+        // Ensure a successful import replaces the ledger, and removes the previous ledger.
+        Rollback::replace_ledger(&ledger_dir, &staging_dir, &backup_dir, || {
+            std::fs::create_dir_all(&ledger_dir)?;
+            Ok(std::fs::write(ledger_dir.join("rolled_back"), "")?)
+        })
+        .unwrap();
+        assert!(ledger_dir.join("rolled_back").exists());
+        assert!(!ledger_dir.join("previous").exists());
+        assert!(!staging_dir.exists());
+        assert!(!backup_dir.exists());
+
+        std::fs::remove_dir_all(&ledger_dir).unwrap();
+    }
+
+    #[test]
+    fn test_replace_ledger_restores_on_failure() {
+        let rng = &mut TestRng::default();
+        let (ledger_dir, staging_dir, backup_dir) = sample_dirs(rng);
+
+        // Ensure a failed import restores the previous ledger, and removes the partial ledger.
+        let error = Rollback::replace_ledger(&ledger_dir, &staging_dir, &backup_dir, || {
+            std::fs::create_dir_all(&ledger_dir)?;
+            std::fs::write(ledger_dir.join("partial"), "")?;
+            bail!("The blocks could not be imported")
+        })
+        .unwrap_err();
+        assert!(error.to_string().contains("which was left unchanged"), "{error}");
+        assert!(ledger_dir.join("previous").exists());
+        assert!(!ledger_dir.join("partial").exists());
+        assert!(!staging_dir.exists());
+        assert!(!backup_dir.exists());
+
+        std::fs::remove_dir_all(&ledger_dir).unwrap();
+    }
+}
@@ -0,0 +1,410 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentNetwork, Ledger};
+use crate::helpers::{format_output, progress};
+
+use snarkvm::prelude::{
+    store::{helpers::rocksdb::ConsensusDB, ConsensusStore},
+    Network,
+};
+
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Component, Path, PathBuf},
+    time::SystemTime,
+};
+
+/// The name of the entry holding the snapshot metadata, which is always the first entry.
+const METADATA_ENTRY: &str = "snapshot.json";
+/// The extension of the file holding the SHA-256 checksum of a snapshot.
+const CHECKSUM_EXTENSION: &str = "sha256";
+/// The extension of the directory next to the ledger, where a snapshot is extracted before being moved into place.
+pub(super) const SNAPSHOT_STAGING_EXTENSION: &str = "snapshot";
+/// The extension of the file next to the ledger, where a remote snapshot is downloaded.
+pub(super) const SNAPSHOT_DOWNLOAD_EXTENSION: &str = "snapshot.download";
+
+/// The metadata of a ledger snapshot.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotMetadata {
+    /// The network ID of the ledger.
+    pub network: u16,
+    /// The height of the latest block in the ledger.
+    pub height: u32,
+    /// The hash of the latest block in the ledger.
+    pub hash: String,
+}
+
+/// Creates a checksummed snapshot of the ledger storage, at its latest height.
+/// The node must be stopped while the snapshot is created.
+#[derive(Debug, Parser)]
+pub struct Snapshot {
+    /// The file to write the snapshot to. Defaults to 'ledger-{network}-{height}.snapshot'.
+    #[clap(long)]
+    out: Option<PathBuf>,
+    /// Enables development mode, specify the unique ID of the local node.
+    #[clap(long)]
+    dev: Option<u16>,
+}
+
+impl Snapshot {
+    /// Creates the snapshot of the ledger.
+    pub fn parse(self) -> Result<String> {
+        let ledger_dir = aleo_std::aleo_ledger_dir(CurrentNetwork::ID, self.dev);
+        ensure!(ledger_dir.exists(), "The ledger '{}' does not exist", ledger_dir.display());
+
+        // Copy the storage next to the ledger before opening it, as an open database
+        // may flush and compact its files while they are being copied.
+        let staging_dir = ledger_dir.with_extension(SNAPSHOT_STAGING_EXTENSION);
+        if staging_dir.exists() {
+            std::fs::remove_dir_all(&staging_dir)?;
+        }
+        let result = copy_storage(&ledger_dir, &staging_dir).and_then(|_| self.write(&staging_dir));
+        std::fs::remove_dir_all(&staging_dir)?;
+        result
+    }
+
+    /// Writes the snapshot of the given copy of the ledger storage.
+    fn write(self, staging_dir: &Path) -> Result<String> {
+        // Open the ledger, which also ensures no node is running on it.
+        let ledger = Ledger::open(self.dev, None)?;
+        let height = ledger.latest_height();
+        let metadata =
+            SnapshotMetadata { network: CurrentNetwork::ID, height, hash: ledger.get_hash(height)?.to_string() };
+
+        // Prepare the snapshot file.
+        let path =
+            self.out.unwrap_or_else(|| PathBuf::from(format!("ledger-{}-{height}.snapshot", CurrentNetwork::ID)));
+        ensure!(!path.exists(), "The file '{}' already exists", path.display());
+
+        progress!("📦 Creating a snapshot of the ledger at block {height} in '{}'...\n", path.display());
+        write_snapshot(staging_dir, &metadata, &path)?;
+
+        // Write the checksum next to the snapshot, in the format of 'sha256sum'.
+        let checksum = sha256_file(&path)?;
+        let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        std::fs::write(checksum_path(&path), format!("{checksum}  {file_name}\n"))?;
+
+        let output = serde_json::json!({ "path": path, "height": height, "hash": metadata.hash, "sha256": checksum });
+        format_output(&output, || {
+            format!("✅ Created a snapshot of the ledger at block {height} ({}) in '{}'", metadata.hash, path.display())
+        })
+    }
+}
+
+/// Restores the ledger storage from the given snapshot file or URL, after verifying its checksum,
+/// and ensures the latest block in the restored ledger has the given trusted hash.
+/// If a ledger already exists, the snapshot is not restored and `None` is returned.
+pub(crate) fn restore_snapshot(source: &str, trusted_hash: &str, dev: Option<u16>) -> Result<Option<SnapshotMetadata>> {
+    let ledger_dir = aleo_std::aleo_ledger_dir(CurrentNetwork::ID, dev);
+    if ledger_dir.exists() {
+        return Ok(None);
+    }
+    // Ensure the trusted hash is a valid block hash.
+    if let Err(error) = trusted_hash.parse::<<CurrentNetwork as Network>::BlockHash>() {
+        bail!("The trusted snapshot hash '{trusted_hash}' is invalid - {error}");
+    }
+
+    // Retrieve the snapshot and its checksum.
+    let is_remote = source.starts_with("http://") || source.starts_with("https://");
+    let path = match is_remote {
+        true => ledger_dir.with_extension(SNAPSHOT_DOWNLOAD_EXTENSION),
+        false => PathBuf::from(source),
+    };
+    let result = extract_snapshot(source, &path, is_remote, &ledger_dir, trusted_hash);
+    // Remove the downloaded snapshot, whether or not it was restored.
+    if is_remote && path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    let metadata = result?;
+
+    // Ensure the restored ledger ends at the trusted block.
+    if let Err(error) = check_restored_ledger(&metadata, trusted_hash, dev) {
+        std::fs::remove_dir_all(&ledger_dir)?;
+        bail!("The restored ledger is invalid - {error}");
+    }
+    Ok(Some(metadata))
+}
+
+/// Retrieves the snapshot from the given source into the given path (if remote), verifies its checksum,
+/// and extracts it into the ledger directory.
+fn extract_snapshot(
+    source: &str,
+    path: &Path,
+    is_remote: bool,
+    ledger_dir: &Path,
+    trusted_hash: &str,
+) -> Result<SnapshotMetadata> {
+    // Ensure the parent directory of the ledger exists, as the download and the staging directory are placed in it.
+    if let Some(parent) = ledger_dir.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let expected_checksum = match is_remote {
+        true => {
+            println!("📦 Downloading the snapshot from '{source}'...");
+            let checksum = ureq::get(&format!("{source}.{CHECKSUM_EXTENSION}")).call()?.into_string()?;
+            let mut file = BufWriter::new(File::create(path)?);
+            std::io::copy(&mut ureq::get(source).call()?.into_reader(), &mut file)?;
+            file.flush()?;
+            checksum
+        }
+        false => std::fs::read_to_string(checksum_path(path))?,
+    };
+
+    // Verify the checksum of the snapshot.
+    let checksum = sha256_file(path)?;
+    let expected_checksum = expected_checksum.split_whitespace().next().unwrap_or_default();
+    if !checksum.eq_ignore_ascii_case(expected_checksum) {
+        bail!("The snapshot checksum is invalid (expected {expected_checksum}, found {checksum})");
+    }
+
+    // Extract the snapshot next to the ledger, and move it into place once complete.
+    println!("📦 Restoring the ledger from the snapshot...");
+    let staging_dir = ledger_dir.with_extension(SNAPSHOT_STAGING_EXTENSION);
+    if staging_dir.exists() {
+        std::fs::remove_dir_all(&staging_dir)?;
+    }
+    let metadata = match read_snapshot(path, &staging_dir, trusted_hash) {
+        Ok(metadata) => metadata,
+        Err(error) => {
+            if staging_dir.exists() {
+                std::fs::remove_dir_all(&staging_dir)?;
+            }
+            return Err(error);
+        }
+    };
+    std::fs::rename(&staging_dir, ledger_dir)?;
+    Ok(metadata)
+}
+
+/// Ensures the latest block in the restored ledger has the trusted hash.
+fn check_restored_ledger(metadata: &SnapshotMetadata, trusted_hash: &str, dev: Option<u16>) -> Result<()> {
+    let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev)?;
+    match store.block_store().get_block_hash(metadata.height)? {
+        Some(hash) if hash.to_string() == trusted_hash => (),
+        Some(hash) => bail!("Block {} has the hash {hash}, which is not trusted", metadata.height),
+        None => bail!("Block {} is missing", metadata.height),
+    }
+    ensure!(
+        store.block_store().get_block_hash(metadata.height + 1)?.is_none(),
+        "Blocks after {} are present",
+        metadata.height
+    );
+    Ok(())
+}
+
+/// Returns the path of the checksum file of the given snapshot.
+fn checksum_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(format!(".{CHECKSUM_EXTENSION}"));
+    PathBuf::from(path)
+}
+
+/// Returns the SHA-256 checksum of the given file, as a hex string.
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut BufReader::new(File::open(path)?), &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Writes the files in the given storage directory into a snapshot.
+///
+/// The snapshot is a gzip stream of entries, each made of the length of its path (u32),
+/// the path, the length of its contents (u64), and the contents, and ends with an empty path.
+fn write_snapshot(storage_dir: &Path, metadata: &SnapshotMetadata, path: &Path) -> Result<()> {
+    let mut encoder = GzEncoder::new(BufWriter::new(File::create(path)?), Compression::default());
+
+    // Write the metadata.
+    let metadata = serde_json::to_vec(metadata)?;
+    write_entry_header(&mut encoder, METADATA_ENTRY, metadata.len() as u64)?;
+    encoder.write_all(&metadata)?;
+
+    // Write the storage files.
+    let mut files = Vec::new();
+    collect_files(storage_dir, storage_dir, &mut files)?;
+    for (name, file) in files {
+        let mut file = File::open(file)?;
+        write_entry_header(&mut encoder, &name, file.metadata()?.len())?;
+        std::io::copy(&mut file, &mut encoder)?;
+    }
+
+    // Write the end of the snapshot.
+    write_entry_header(&mut encoder, "", 0)?;
+    encoder.finish()?.flush()?;
+    Ok(())
+}
+
+/// Copies the files in the given storage directory into the staging directory, and ensures
+/// the storage was not modified during the copy (e.g. by a running node).
+fn copy_storage(storage_dir: &Path, staging_dir: &Path) -> Result<()> {
+    // Returns the path, length, and modification time of each storage file.
+    let list_files = || -> Result<Vec<(String, u64, SystemTime)>> {
+        let mut files = Vec::new();
+        collect_files(storage_dir, storage_dir, &mut files)?;
+        files
+            .into_iter()
+            .map(|(name, file)| {
+                let metadata = std::fs::metadata(file)?;
+                Ok((name, metadata.len(), metadata.modified()?))
+            })
+            .collect()
+    };
+
+    let listing = list_files()?;
+    let mut files = Vec::new();
+    collect_files(storage_dir, storage_dir, &mut files)?;
+    for (name, file) in files {
+        let staged_file = staging_dir.join(name);
+        if let Some(parent) = staged_file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(file, staged_file)?;
+    }
+    ensure!(list_files()? == listing, "The ledger was modified while it was copied, ensure the node is stopped");
+    Ok(())
+}
+
+/// Collects the files in the given directory, with their paths relative to the storage directory.
+/// The lock and log files of the database are skipped.
+fn collect_files(storage_dir: &Path, dir: &Path, files: &mut Vec<(String, PathBuf)>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(storage_dir, &path, files)?;
+            continue;
+        }
+        let name = path.strip_prefix(storage_dir)?.to_string_lossy().replace('\\', "/");
+        if name != "LOCK" && !name.starts_with("LOG") {
+            files.push((name, path));
+        }
+    }
+    Ok(())
+}
+
+/// Writes the header of a snapshot entry.
+fn write_entry_header(writer: &mut impl Write, name: &str, len: u64) -> Result<()> {
+    writer.write_all(&(name.len() as u32).to_le_bytes())?;
+    writer.write_all(name.as_bytes())?;
+    writer.write_all(&len.to_le_bytes())?;
+    Ok(())
+}
+
+/// Reads the header of a snapshot entry, returning its path and the length of its contents.
+fn read_entry_header(reader: &mut impl Read) -> Result<(String, u64)> {
+    let mut name_len = [0u8; 4];
+    reader.read_exact(&mut name_len)?;
+    let mut name = vec![0u8; u32::from_le_bytes(name_len) as usize];
+    reader.read_exact(&mut name)?;
+    let mut len = [0u8; 8];
+    reader.read_exact(&mut len)?;
+    Ok((String::from_utf8(name)?, u64::from_le_bytes(len)))
+}
+
+/// Extracts the snapshot into the given directory, and returns its metadata.
+/// The metadata is checked against the network and the trusted hash before any file is extracted.
+fn read_snapshot(path: &Path, dir: &Path, trusted_hash: &str) -> Result<SnapshotMetadata> {
+    let mut decoder = GzDecoder::new(BufReader::new(File::open(path)?));
+
+    // Read the metadata.
+    let (name, len) = read_entry_header(&mut decoder)?;
+    ensure!(name == METADATA_ENTRY, "The snapshot does not start with its metadata");
+    let mut metadata = Vec::new();
+    (&mut decoder).take(len).read_to_end(&mut metadata)?;
+    let metadata: SnapshotMetadata = serde_json::from_slice(&metadata)?;
+    ensure!(metadata.network == CurrentNetwork::ID, "The snapshot is for network {}", metadata.network);
+    ensure!(metadata.hash == trusted_hash, "The snapshot ends at block {}, which is not trusted", metadata.hash);
+
+    // Read the storage files.
+    std::fs::create_dir_all(dir)?;
+    loop {
+        let (name, len) = read_entry_header(&mut decoder)?;
+        if name.is_empty() {
+            break;
+        }
+        // Ensure the file remains inside the directory.
+        let relative_path = Path::new(&name);
+        if !relative_path.components().all(|component| matches!(component, Component::Normal(_))) {
+            bail!("The snapshot contains an invalid path '{name}'");
+        }
+        let file_path = dir.join(relative_path);
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = BufWriter::new(File::create(file_path)?);
+        let copied = std::io::copy(&mut (&mut decoder).take(len), &mut file)?;
+        ensure!(copied == len, "The snapshot is truncated at '{name}'");
+        file.flush()?;
+    }
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+
+    #[test]
+    fn clap_snarkos_ledger_snapshot() {
+        let cli = CLI::parse_from(["snarkos", "ledger", "snapshot", "--out", "ledger.snapshot", "--dev", "0"]);
+        if let Command::Ledger(Ledger::Snapshot(snapshot)) = cli.command {
+            assert_eq!(snapshot.out, Some(PathBuf::from("ledger.snapshot")));
+            assert_eq!(snapshot.dev, Some(0));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let root = std::env::temp_dir().join(format!("snarkos-snapshot-{}", std::process::id()));
+        let storage_dir = root.join("storage");
+        std::fs::create_dir_all(storage_dir.join("nested")).unwrap();
+        std::fs::write(storage_dir.join("000001.sst"), b"blocks").unwrap();
+        std::fs::write(storage_dir.join("nested").join("MANIFEST"), b"manifest").unwrap();
+        std::fs::write(storage_dir.join("LOCK"), b"").unwrap();
+
+        // Ensure the storage is copied, except for the lock file.
+        let staging_dir = root.join("staging");
+        copy_storage(&storage_dir, &staging_dir).unwrap();
+        assert_eq!(std::fs::read(staging_dir.join("nested").join("MANIFEST")).unwrap(), b"manifest");
+        assert!(!staging_dir.join("LOCK").exists());
+
+        let metadata = SnapshotMetadata { network: CurrentNetwork::ID, height: 7, hash: "ab1hash".to_string() };
+        let path = root.join("ledger.snapshot");
+        write_snapshot(&staging_dir, &metadata, &path).unwrap();
+
+        // Ensure an untrusted hash is rejected.
+        let restored_dir = root.join("restored");
+        assert!(read_snapshot(&path, &restored_dir, "ab1other").is_err());
+        assert!(!restored_dir.exists());
+
+        // Ensure the files are restored.
+        assert_eq!(read_snapshot(&path, &restored_dir, "ab1hash").unwrap(), metadata);
+        assert_eq!(std::fs::read(restored_dir.join("000001.sst")).unwrap(), b"blocks");
+        assert_eq!(std::fs::read(restored_dir.join("nested").join("MANIFEST")).unwrap(), b"manifest");
+        assert!(!restored_dir.join("LOCK").exists());
+
+        // Ensure the checksum path is next to the snapshot.
+        assert_eq!(checksum_path(&path), root.join("ledger.snapshot.sha256"));
+        assert_eq!(sha256_file(&path).unwrap().len(), 64);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentNetwork, Ledger};
+use crate::helpers::{format_output, progress};
+
+use snarkvm::prelude::{store::helpers::memory::ConsensusMemory, Ledger as SnarkVMLedger};
+
+use anyhow::{anyhow, ensure, Result};
+use clap::Parser;
+
+/// The number of blocks to verify between progress reports.
+const BLOCKS_PER_PROGRESS: u32 = 256;
+
+/// The scratch ledger, which re-applies the blocks of the local ledger in memory.
+type ScratchLedger = SnarkVMLedger<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
+
+/// Re-verifies the blocks stored in the ledger, as a node verifies the next block, i.e. their headers,
+/// links to the previous blocks, certificates, and the proofs of their transactions (in parallel).
+///
+/// The blocks are re-applied to a scratch ledger in memory, starting from the genesis block;
+/// the blocks before `--from` are re-applied without being verified.
+#[derive(Debug, Parser)]
+pub struct Verify {
+    /// The height of the first block to verify.
+    #[clap(long, default_value = "1")]
+    from: u32,
+    /// The height of the last block to verify. Defaults to the latest height.
+    #[clap(long)]
+    to: Option<u32>,
+    /// Enables development mode, specify the unique ID of the local node.
+    #[clap(long)]
+    dev: Option<u16>,
+}
+
+impl Verify {
+    /// Verifies the blocks in the ledger, and reports the first invalid height.
+    pub fn parse(self) -> Result<String> {
+        // Open the ledger.
+        let ledger = Ledger::open(self.dev, None)?;
+
+        // Determine the range of blocks to verify.
+        // Note: The genesis block is the starting point of the scratch ledger, so it is not verified.
+        let (from, to) = (self.from.max(1), self.to.unwrap_or_else(|| ledger.latest_height()));
+        ensure!(from <= to, "The given range is invalid (from = {from}, to = {to})");
+        ensure!(to <= ledger.latest_height(), "The ledger does not contain block {to}");
+
+        // Initialize the scratch ledger with the genesis block of the ledger.
+        let scratch = ScratchLedger::load(ledger.get_block(0)?, None)?;
+
+        // Re-apply the blocks before the range, without verifying them.
+        if from > 1 {
+            progress!("📦 Re-applying blocks 1 to {}...\n", from - 1);
+            for height in 1..from {
+                scratch.advance_to_next_block(&ledger.get_block(height)?)?;
+            }
+        }
+
+        progress!("🔍 Verifying blocks {from} to {to}...\n");
+
+        // Verify each block against the state before it, then apply it to verify the next block.
+        let rng = &mut rand::thread_rng();
+        for height in from..=to {
+            let block = ledger.get_block(height)?;
+            ensure!(block.height() == height, "❌ The block at height {height} is stored at the wrong height");
+            scratch
+                .check_next_block(&block, rng)
+                .and_then(|_| scratch.advance_to_next_block(&block))
+                .map_err(|error| anyhow!("❌ The ledger is invalid at block {height} - {error}"))?;
+            if (height - from + 1) % BLOCKS_PER_PROGRESS == 0 {
+                progress!("Verified blocks {from} to {height}");
+            }
+        }
+
+        let output = serde_json::json!({ "from": from, "to": to });
+        format_output(&output, || format!("✅ Verified blocks {from} to {to}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+
+    #[test]
+    fn clap_snarkos_ledger_verify() {
+        let cli = CLI::parse_from(["snarkos", "ledger", "verify", "--from", "10", "--to", "20"]);
+        if let Command::Ledger(Ledger::Verify(verify)) = cli.command {
+            assert_eq!(verify.from, 10);
+            assert_eq!(verify.to, Some(20));
+            assert_eq!(verify.dev, None);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
@@ -21,12 +21,26 @@ pub use clean::*;
 mod developer;
 pub use developer::*;
 
+mod devnet;
+pub use devnet::*;
+
+mod genesis;
+pub use genesis::*;
+
+mod ledger;
+pub use ledger::*;
+
 mod start;
 pub use start::*;
 
+mod status;
+pub use status::*;
+
 mod update;
 pub use update::*;
 
+use crate::helpers::OutputFormat;
+
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
 use clap::{builder::Styles, Parser};
@@ -44,6 +58,9 @@ pub struct CLI {
     /// Specify the verbosity [options: 0, 1, 2, 3]
     #[clap(default_value = "2", short, long)]
     pub verbosity: u8,
+    /// Specify the output format of the command [options: text, json]
+    #[clap(default_value = "text", long, global = true, value_enum)]
+    pub output: OutputFormat,
     /// Specify a subcommand.
     #[clap(subcommand)]
     pub command: Command,
@@ -57,8 +74,16 @@ pub enum Command {
     Clean(Clean),
     #[clap(subcommand)]
     Developer(Developer),
+    #[clap(subcommand)]
+    Devnet(Devnet),
+    #[clap(name = "genesis")]
+    Genesis(Genesis),
+    #[clap(subcommand)]
+    Ledger(Ledger),
     #[clap(name = "start")]
     Start(Box<Start>),
+    #[clap(name = "status")]
+    Status(Status),
     #[clap(name = "update")]
     Update(Update),
 }
@@ -70,7 +95,11 @@ impl Command {
             Self::Account(command) => command.parse(),
             Self::Clean(command) => command.parse(),
             Self::Developer(command) => command.parse(),
+            Self::Devnet(command) => command.parse(),
+            Self::Genesis(command) => command.parse(),
+            Self::Ledger(command) => command.parse(),
             Self::Start(command) => command.parse(),
+            Self::Status(command) => command.parse(),
             Self::Update(command) => command.parse(),
         }
     }
@@ -86,4 +115,26 @@ mod tests {
         use clap::CommandFactory;
         CLI::command().debug_assert()
     }
+
+    #[test]
+    fn clap_snarkos_output() {
+        // Ensure the output format defaults to text.
+        let cli = CLI::parse_from(["snarkos", "clean"]);
+        assert_eq!(cli.output, OutputFormat::Text);
+        // Ensure the output format may be specified before or after the subcommand.
+        let cli = CLI::parse_from(["snarkos", "--output", "json", "clean"]);
+        assert_eq!(cli.output, OutputFormat::Json);
+        let cli = CLI::parse_from([
+            "snarkos",
+            "developer",
+            "decrypt",
+            "-c",
+            "record1",
+            "-v",
+            "AViewKey1",
+            "--output",
+            "json",
+        ]);
+        assert_eq!(cli.output, OutputFormat::Json);
+    }
 }
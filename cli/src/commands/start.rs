@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkos_account::Account;
+use super::restore_snapshot;
+
+#[cfg(target_family = "unix")]
+use snarkos_account::RemoteSigner;
+use snarkos_account::{Account, Signer};
 use snarkos_display::Display;
-use snarkos_node::{bft::MEMORY_POOL_PORT, router::messages::NodeType, Node};
+use snarkos_node::{
+    bft::MEMORY_POOL_PORT,
+    router::{messages::NodeType, BanTarget, SubnetLimits},
+    Node,
+};
 use snarkvm::{
     console::{
         account::{Address, PrivateKey},
@@ -31,13 +39,13 @@ use snarkvm::{
     utilities::to_bytes_le,
 };
 
-use anyhow::{bail, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use clap::Parser;
 use colored::Colorize;
 use core::str::FromStr;
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
-use std::{net::SocketAddr, path::PathBuf};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
 use tokio::runtime::{self, Runtime};
 
 /// The recommended minimum number of 'open files' limit for a validator.
@@ -48,7 +56,7 @@ const RECOMMENDED_MIN_NOFILES_LIMIT: u64 = 2048;
 /// The development mode RNG seed.
 const DEVELOPMENT_MODE_RNG_SEED: u64 = 1234567890u64;
 /// The development mode number of genesis committee members.
-const DEVELOPMENT_MODE_NUM_GENESIS_COMMITTEE_MEMBERS: u16 = 4;
+pub(crate) const DEVELOPMENT_MODE_NUM_GENESIS_COMMITTEE_MEMBERS: u16 = 4;
 
 /// Starts the snarkOS node.
 #[derive(Clone, Debug, Parser)]
@@ -73,6 +81,9 @@ pub struct Start {
     /// Specify the path to a file containing the account private key of the node
     #[clap(long = "private-key-file")]
     pub private_key_file: Option<PathBuf>,
+    /// Specify the path to the Unix socket of a remote signer holding the account private key (validators only)
+    #[clap(long = "signer", conflicts_with_all = ["private_key", "private_key_file"])]
+    pub signer: Option<PathBuf>,
 
     /// Specify the IP address and port for the node server
     #[clap(default_value = "0.0.0.0:4133", long = "node")]
@@ -86,6 +97,18 @@ pub struct Start {
     /// Specify the IP address and port of the validator(s) to connect to
     #[clap(default_value = "", long = "validators")]
     pub validators: String,
+    /// Specify the IP address(es), IP range(s) in CIDR notation, or Aleo address(es) to ban
+    #[clap(default_value = "", long = "banned-peers")]
+    pub banned_peers: String,
+    /// Specify the maximum number of connected peers in an IPv4 /16 subnet
+    #[clap(long = "max-peers-per-ipv4-16")]
+    pub max_peers_per_ipv4_16: Option<usize>,
+    /// Specify the maximum number of connected peers in an IPv4 /24 subnet
+    #[clap(long = "max-peers-per-ipv4-24")]
+    pub max_peers_per_ipv4_24: Option<usize>,
+    /// Specify the maximum number of connected peers in an IPv6 /48 subnet
+    #[clap(long = "max-peers-per-ipv6-48")]
+    pub max_peers_per_ipv6_48: Option<usize>,
 
     /// Specify the IP address and port for the REST server
     #[clap(default_value = "0.0.0.0:3033", long = "rest")]
@@ -93,6 +116,10 @@ pub struct Start {
     /// If the flag is set, the node will not initialize the REST server
     #[clap(long)]
     pub norest: bool,
+    /// If the flag is set, the REST server will serve a web dashboard at '/dashboard', which also streams the logs,
+    /// to the local machine only
+    #[clap(long, conflicts_with_all = ["norest", "prover"])]
+    pub dashboard: bool,
 
     /// If the flag is set, the node will not render the display
     #[clap(long)]
@@ -110,6 +137,16 @@ pub struct Start {
     /// If the flag is set, the node will not prefresh from a CDN
     #[clap(long)]
     pub nocdn: bool,
+    /// Restores the ledger from a snapshot file or URL before starting, if no ledger exists yet
+    #[clap(long, requires = "snapshot_hash")]
+    pub snapshot: Option<String>,
+    /// Specify the trusted hash of the latest block in the snapshot
+    #[clap(long, requires = "snapshot")]
+    pub snapshot_hash: Option<String>,
+
+    /// Specify the path to the genesis block of a private network, as written by 'snarkos genesis'
+    #[clap(long, conflicts_with = "dev_num_validators")]
+    pub genesis: Option<PathBuf>,
 
     /// Enables development mode, specify a unique ID for this node
     #[clap(long)]
@@ -122,8 +159,18 @@ pub struct Start {
 impl Start {
     /// Starts the snarkOS node.
     pub fn parse(self) -> Result<String> {
+        // Restore the ledger from the snapshot, if one is given.
+        if let (Some(snapshot), Some(snapshot_hash)) = (&self.snapshot, &self.snapshot_hash) {
+            match restore_snapshot(snapshot, snapshot_hash, self.dev)? {
+                Some(metadata) => println!("✅ Restored the ledger at block {} from the snapshot\n", metadata.height),
+                None => println!("📦 Skipping the snapshot, as the ledger already exists\n"),
+            }
+        }
+        // Enable the web dashboard before the REST server starts, so the logs are sent to it.
+        let dashboard_sender = self.dashboard.then(snarkos_node_rest::enable_dashboard);
         // Initialize the logger.
-        let log_receiver = crate::helpers::initialize_logger(self.verbosity, self.nodisplay, self.logfile.clone());
+        let (log_receiver, set_verbosity) =
+            crate::helpers::initialize_logger(self.verbosity, self.nodisplay, self.logfile.clone(), dashboard_sender);
         // Initialize the runtime.
         Self::runtime().block_on(async move {
             // Clone the configurations.
@@ -136,7 +183,8 @@ impl Start {
                     // If the display is enabled, render the display.
                     if !cli.nodisplay {
                         // Initialize the display.
-                        Display::start(node, log_receiver).expect("Failed to initialize the display");
+                        Display::start(node, log_receiver, cli.verbosity, set_verbosity)
+                            .expect("Failed to initialize the display");
                     }
                 }
                 _ => panic!("Invalid network ID specified"),
@@ -187,6 +235,32 @@ impl Start {
         }
     }
 
+    /// Returns the IP addresses, IP ranges, and Aleo addresses to ban, from the given configurations.
+    /// Unlike the peers, a malformed ban target is an error, so that an intended ban is never skipped.
+    fn parse_banned_peers<N: Network>(&self) -> Result<Vec<BanTarget<N>>> {
+        match self.banned_peers.is_empty() {
+            true => Ok(vec![]),
+            false => self
+                .banned_peers
+                .split(',')
+                .map(|target| {
+                    BanTarget::from_str(target)
+                        .with_context(|| format!("The target supplied to --banned-peers ('{target}') is malformed"))
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the maximum number of connected peers per subnet, from the given configurations.
+    fn parse_subnet_limits(&self) -> SubnetLimits {
+        let defaults = SubnetLimits::default();
+        SubnetLimits {
+            ipv4_16: self.max_peers_per_ipv4_16.unwrap_or(defaults.ipv4_16),
+            ipv4_24: self.max_peers_per_ipv4_24.unwrap_or(defaults.ipv4_24),
+            ipv6_48: self.max_peers_per_ipv6_48.unwrap_or(defaults.ipv6_48),
+        }
+    }
+
     /// Returns the CDN to prefetch initial blocks from, from the given configurations.
     fn parse_cdn(&self) -> Option<String> {
         // Determine if the node type is not declared.
@@ -197,7 +271,14 @@ impl Start {
         //  2. The user has explicitly disabled CDN.
         //  3. The node is a prover (no need to sync).
         //  4. The node type is not declared (defaults to client) (no need to sync).
-        if self.dev.is_some() || self.cdn.is_empty() || self.nocdn || self.prover || is_no_node_type {
+        //  5. The node is on a private network (the CDN only serves the public network).
+        if self.dev.is_some()
+            || self.cdn.is_empty()
+            || self.nocdn
+            || self.prover
+            || is_no_node_type
+            || self.genesis.is_some()
+        {
             None
         }
         // Enable the CDN otherwise.
@@ -207,18 +288,20 @@ impl Start {
     }
 
     /// Read the private key directly from an argument or from a filesystem location,
-    /// returning the Aleo account.
-    fn parse_private_key<N: Network>(&self) -> Result<Account<N>> {
+    /// returning the Aleo account, or `None` if the private key is held by a remote signer.
+    fn parse_private_key<N: Network>(&self) -> Result<Option<Account<N>>> {
         match self.dev {
             None => match (&self.private_key, &self.private_key_file) {
                 // Parse the private key directly.
-                (Some(private_key), None) => Account::from_str(private_key.trim()),
+                (Some(private_key), None) => Account::from_str(private_key.trim()).map(Some),
                 // Parse the private key from a file.
-                (None, Some(path)) => Account::from_str(std::fs::read_to_string(path)?.trim()),
-                // Ensure the private key is provided to the CLI, except for clients or nodes in development mode.
-                (None, None) => match self.client {
-                    true => Account::new(&mut rand::thread_rng()),
-                    false => bail!("Missing the '--private-key' or '--private-key-file' argument"),
+                (None, Some(path)) => Account::from_str(std::fs::read_to_string(path)?.trim()).map(Some),
+                // Ensure the private key is provided to the CLI, except for clients, nodes with a remote signer,
+                // or nodes in development mode.
+                (None, None) => match (self.client, self.signer.is_some()) {
+                    (true, _) => Account::new(&mut rand::thread_rng()).map(Some),
+                    (false, true) => Ok(None),
+                    (false, false) => bail!("Missing the '--private-key' or '--private-key-file' argument"),
                 },
                 // Ensure only one private key flag is provided to the CLI.
                 (Some(_), Some(_)) => {
@@ -238,10 +321,30 @@ impl Start {
                     println!("🔑 Your development private key for node {dev} is {}\n", private_key.to_string().bold());
                     private_key
                 })
+                .map(Some)
             }
         }
     }
 
+    /// Connects to the remote signer of the validator, if one is specified.
+    fn parse_signer<N: Network>(&self) -> Result<Option<Arc<dyn Signer<N>>>> {
+        match &self.signer {
+            #[cfg(target_family = "unix")]
+            Some(path) => {
+                ensure!(self.validator, "The '--signer' flag is only supported for validators");
+                Ok(Some(Arc::new(RemoteSigner::<N>::connect(path)?)))
+            }
+            #[cfg(not(target_family = "unix"))]
+            Some(_) => bail!("The '--signer' flag is only supported on Unix"),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the given account, which is only absent for validators with a remote signer.
+    fn require_account<N: Network>(account: Option<Account<N>>) -> Result<Account<N>> {
+        account.ok_or_else(|| anyhow!("Missing the '--private-key' or '--private-key-file' argument"))
+    }
+
     /// Updates the configurations if the node is in development mode.
     fn parse_development(
         &mut self,
@@ -282,10 +385,14 @@ impl Start {
         Ok(())
     }
 
-    /// Returns an alternative genesis block if the node is in development mode.
-    /// Otherwise, returns the actual genesis block.
+    /// Returns the given genesis block if one is specified, or an alternative genesis block
+    /// if the node is in development mode. Otherwise, returns the actual genesis block.
     fn parse_genesis<N: Network>(&self) -> Result<Block<N>> {
-        if self.dev.is_some() {
+        if let Some(path) = &self.genesis {
+            let buffer = std::fs::read(path)
+                .with_context(|| format!("Failed to read the genesis block '{}'", path.display()))?;
+            Block::from_bytes_le(&buffer).with_context(|| format!("Invalid genesis block '{}'", path.display()))
+        } else if self.dev.is_some() {
             // Determine the number of genesis committee members.
             let num_committee_members = match self.dev_num_validators {
                 Some(num_committee_members) => num_committee_members,
@@ -378,6 +485,8 @@ impl Start {
         let mut trusted_validators = self.parse_trusted_validators()?;
         // Parse the development configurations.
         self.parse_development(&mut trusted_peers, &mut trusted_validators)?;
+        // Parse the peers to ban.
+        let banned_peers = self.parse_banned_peers::<N>()?;
 
         // Parse the CDN.
         let cdn = self.parse_cdn();
@@ -386,6 +495,14 @@ impl Start {
         let genesis = self.parse_genesis::<N>()?;
         // Parse the private key of the node.
         let account = self.parse_private_key::<N>()?;
+        // Parse the remote signer of the node.
+        let signer = self.parse_signer::<N>()?;
+        // Determine the Aleo address of the node.
+        let address = match (&signer, &account) {
+            (Some(signer), _) => signer.address(),
+            (None, Some(account)) => account.address(),
+            (None, None) => bail!("Missing the '--private-key' or '--private-key-file' argument"),
+        };
         // Parse the node type.
         let node_type = self.parse_node_type();
 
@@ -398,7 +515,7 @@ impl Start {
         // If the display is not enabled, render the welcome message.
         if self.nodisplay {
             // Print the Aleo address.
-            println!("🪪 Your Aleo address is {}.\n", account.address().to_string().bold());
+            println!("🪪 Your Aleo address is {}.\n", address.to_string().bold());
             // Print the node type and network.
             println!(
                 "🧭 Starting {} on {} {} at {}.\n",
@@ -412,8 +529,11 @@ impl Start {
             if node_type.is_validator() {
                 if let Some(rest_ip) = rest_ip {
                     println!("🌐 Starting the REST server at {}.\n", rest_ip.to_string().bold());
+                    if self.dashboard {
+                        println!("📊 Serving the dashboard at {}.\n", format!("http://{rest_ip}/dashboard").bold());
+                    }
 
-                    if let Ok(jwt_token) = snarkos_node_rest::Claims::new(account.address()).to_jwt_string() {
+                    if let Ok(jwt_token) = snarkos_node_rest::Claims::new(address).to_jwt_string() {
                         println!("🔑 Your one-time JWT token is {}\n", jwt_token.dimmed());
                     }
                 }
@@ -430,11 +550,18 @@ impl Start {
 
         // Initialize the node.
         let bft_ip = if self.dev.is_some() { self.bft } else { None };
-        match node_type {
-            NodeType::Validator => Node::new_validator(self.node, rest_ip, bft_ip, account, &trusted_peers, &trusted_validators, genesis, cdn, self.dev).await,
-            NodeType::Prover => Node::new_prover(self.node, account, &trusted_peers, genesis, self.dev).await,
-            NodeType::Client => Node::new_client(self.node, rest_ip, account, &trusted_peers, genesis, cdn, self.dev).await,
+        let node = match node_type {
+            NodeType::Validator => Node::new_validator(self.node, rest_ip, bft_ip, account, signer, &trusted_peers, &trusted_validators, genesis, cdn, self.dev).await,
+            NodeType::Prover => Node::new_prover(self.node, Self::require_account(account)?, &trusted_peers, genesis, self.dev).await,
+            NodeType::Client => Node::new_client(self.node, rest_ip, Self::require_account(account)?, &trusted_peers, genesis, cdn, self.dev).await,
+        }?;
+        // Set the subnet limits.
+        node.router().set_subnet_limits(self.parse_subnet_limits());
+        // Ban the configured peers.
+        for target in banned_peers {
+            node.router().ban(target, None);
         }
+        Ok(node)
     }
 
     /// Returns a runtime for the node.
@@ -582,6 +709,41 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_parse_banned_peers() {
+        let config = Start::try_parse_from(["snarkos", "--banned-peers", "1.2.3.4,10.0.0.0/8"].iter()).unwrap();
+        let banned_peers = config.parse_banned_peers::<CurrentNetwork>().unwrap();
+        assert_eq!(banned_peers.len(), 2);
+        assert_eq!(banned_peers[1].to_string(), "10.0.0.0/8");
+
+        let config = Start::try_parse_from(["snarkos", "--banned-peers", "1.2.3.4,10.0.0.0/33"].iter()).unwrap();
+        assert!(config.parse_banned_peers::<CurrentNetwork>().is_err());
+    }
+
+    #[test]
+    fn test_parse_private_key_with_signer() {
+        // Ensure a validator with a remote signer does not require the private key.
+        let config = Start::try_parse_from(["snarkos", "--validator", "--signer", "signer.sock"].iter()).unwrap();
+        assert!(config.parse_private_key::<CurrentNetwork>().unwrap().is_none());
+        // Ensure a validator without a remote signer requires the private key.
+        let config = Start::try_parse_from(["snarkos", "--validator"].iter()).unwrap();
+        assert!(config.parse_private_key::<CurrentNetwork>().is_err());
+        // Ensure the private key cannot be given alongside a remote signer.
+        assert!(Start::try_parse_from(
+            ["snarkos", "--validator", "--signer", "signer.sock", "--private-key", "aleo1xx"].iter()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_subnet_limits() {
+        let config = Start::try_parse_from(["snarkos"].iter()).unwrap();
+        assert_eq!(config.parse_subnet_limits(), SubnetLimits::default());
+
+        let config = Start::try_parse_from(["snarkos", "--max-peers-per-ipv4-24", "2"].iter()).unwrap();
+        assert_eq!(config.parse_subnet_limits(), SubnetLimits { ipv4_24: 2, ..Default::default() });
+    }
+
     #[test]
     fn test_parse_cdn() {
         // Validator (Prod)
@@ -788,4 +950,50 @@ mod tests {
             panic!("Unexpected result of clap parsing!");
         }
     }
+
+    #[test]
+    fn test_parse_genesis_file() {
+        let prod_genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let path = std::env::temp_dir().join(format!("snarkos-genesis-{}.block", std::process::id()));
+        std::fs::write(&path, prod_genesis.to_bytes_le().unwrap()).unwrap();
+
+        // Ensure the genesis block is loaded from the file, and the CDN is disabled.
+        let config =
+            Start::try_parse_from(["snarkos", "--validator", "--genesis", path.to_str().unwrap()].iter()).unwrap();
+        assert_eq!(config.parse_genesis::<CurrentNetwork>().unwrap(), prod_genesis);
+        assert!(config.parse_cdn().is_none());
+
+        // Ensure an invalid genesis block is rejected.
+        std::fs::write(&path, b"invalid").unwrap();
+        assert!(config.parse_genesis::<CurrentNetwork>().is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn clap_snarkos_start_snapshot() {
+        let cli = CLI::parse_from(["snarkos", "start", "--snapshot", "ledger.snapshot", "--snapshot-hash", "HASH"]);
+        if let Command::Start(start) = cli.command {
+            assert_eq!(start.snapshot.as_deref(), Some("ledger.snapshot"));
+            assert_eq!(start.snapshot_hash.as_deref(), Some("HASH"));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        // Ensure the snapshot requires a trusted hash.
+        assert!(CLI::try_parse_from(["snarkos", "start", "--snapshot", "ledger.snapshot"]).is_err());
+    }
+
+    #[test]
+    fn clap_snarkos_start_dashboard() {
+        let cli = CLI::parse_from(["snarkos", "start", "--client", "--dashboard"]);
+        if let Command::Start(start) = cli.command {
+            assert!(start.dashboard);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        // Ensure the dashboard requires the REST server.
+        assert!(CLI::try_parse_from(["snarkos", "start", "--dashboard", "--norest"]).is_err());
+        assert!(CLI::try_parse_from(["snarkos", "start", "--prover", "--dashboard"]).is_err());
+    }
 }
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{format_output, OutputFormat};
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use crossterm::{
+    cursor::MoveTo,
+    execute,
+    terminal::{Clear, ClearType},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{fmt::Write, io::stdout, thread, time::Duration};
+
+/// The timeout for requests to the REST server.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Shows the status of a running node, using its REST server.
+#[derive(Debug, Parser)]
+pub struct Status {
+    /// Specify the REST server of the node, as an IP:port or a URL
+    #[clap(default_value = "127.0.0.1:3033", long)]
+    rest: String,
+    /// Refresh the status every N seconds (defaults to 5), until interrupted
+    #[clap(long, value_name = "SECONDS", num_args = 0..=1, default_missing_value = "5")]
+    watch: Option<u64>,
+}
+
+/// The status of a node, as reported by its REST server.
+#[derive(Debug, Serialize, Deserialize)]
+struct StatusReport {
+    node_type: String,
+    height: u32,
+    hash: String,
+    is_synced: bool,
+    peers: PeerCounts,
+    mempool: Option<MempoolSizes>,
+    bft: Option<BftStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PeerCounts {
+    total: usize,
+    validators: usize,
+    provers: usize,
+    clients: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MempoolSizes {
+    transmissions: usize,
+    solutions: usize,
+    transactions: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BftStatus {
+    round: u64,
+    leader: Option<String>,
+}
+
+impl Status {
+    /// Returns the status of the node, refreshing it periodically if requested.
+    pub fn parse(self) -> Result<String> {
+        let agent = ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build();
+        let url = self.status_url();
+
+        let interval = match self.watch {
+            Some(0) => bail!("The watch interval must be at least one second"),
+            Some(seconds) => Duration::from_secs(seconds),
+            None => return Self::format_report(&Self::fetch(&agent, &url)?),
+        };
+
+        loop {
+            // A node that is restarting should not end the watch, so errors are shown in place of the report.
+            let output = match Self::fetch(&agent, &url) {
+                Ok(report) => Self::format_report(&report)?,
+                Err(error) => Self::format_error(&error)?,
+            };
+            if OutputFormat::is_json() {
+                // Print one JSON document per line, so the output can be streamed.
+                println!("{output}");
+            } else {
+                execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0))?;
+                println!("{output}\n\nRefreshing every {}s, press Ctrl-C to exit.", interval.as_secs());
+            }
+            thread::sleep(interval);
+        }
+    }
+
+    /// Returns the URL of the status endpoint of the node.
+    fn status_url(&self) -> String {
+        let rest = self.rest.trim_end_matches('/');
+        match rest.starts_with("http://") || rest.starts_with("https://") {
+            true => format!("{rest}/testnet3/node/status"),
+            false => format!("http://{rest}/testnet3/node/status"),
+        }
+    }
+
+    /// Fetches the status report from the given URL.
+    fn fetch(agent: &ureq::Agent, url: &str) -> Result<StatusReport> {
+        match agent.get(url).call() {
+            Ok(response) => Ok(response.into_json()?),
+            Err(error) => bail!("Failed to fetch the node status from '{url}' - {error}"),
+        }
+    }
+
+    /// Formats the error in the requested output format.
+    fn format_error(error: &anyhow::Error) -> Result<String> {
+        format_output(&json!({ "error": error.to_string() }), || error.to_string())
+    }
+
+    /// Formats the status report in the requested output format.
+    fn format_report(report: &StatusReport) -> Result<String> {
+        format_output(report, || {
+            let mut output = String::new();
+            let synced = if report.is_synced { "synced" } else { "syncing" };
+            // Writing to a string cannot fail.
+            let _ = writeln!(output, "Node type  {}", report.node_type);
+            let _ = writeln!(output, "Height     {} ({synced})", report.height);
+            let _ = writeln!(output, "Hash       {}", report.hash);
+            let peers = &report.peers;
+            let _ = write!(
+                output,
+                "Peers      {} ({} validators, {} provers, {} clients)",
+                peers.total, peers.validators, peers.provers, peers.clients
+            );
+            if let Some(mempool) = &report.mempool {
+                let _ = write!(
+                    output,
+                    "\nMempool    {} transmissions ({} solutions, {} transactions)",
+                    mempool.transmissions, mempool.solutions, mempool.transactions
+                );
+            }
+            if let Some(bft) = &report.bft {
+                let leader = bft.leader.as_deref().unwrap_or("unknown");
+                let _ = write!(output, "\nBFT round  {} (leader {leader})", bft.round);
+            }
+            output
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+
+    #[test]
+    fn clap_snarkos_status() {
+        let cli = CLI::parse_from(["snarkos", "status"]);
+        if let Command::Status(status) = cli.command {
+            assert_eq!(status.rest, "127.0.0.1:3033");
+            assert_eq!(status.watch, None);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        let cli = CLI::parse_from(["snarkos", "status", "--rest", "10.0.0.1:3030", "--watch"]);
+        if let Command::Status(status) = cli.command {
+            assert_eq!(status.status_url(), "http://10.0.0.1:3030/testnet3/node/status");
+            assert_eq!(status.watch, Some(5));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        let cli = CLI::parse_from(["snarkos", "status", "--rest", "https://node.example.com/", "--watch", "2"]);
+        if let Command::Status(status) = cli.command {
+            assert_eq!(status.status_url(), "https://node.example.com/testnet3/node/status");
+            assert_eq!(status.watch, Some(2));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_parse_status_report() {
+        // A client has neither a memory pool nor a BFT.
+        let report: StatusReport = serde_json::from_str(
+            r#"{"node_type":"Client","height":7,"hash":"ab1","is_synced":true,
+            "peers":{"total":3,"validators":1,"provers":1,"clients":1},"mempool":null,"bft":null}"#,
+        )
+        .unwrap();
+        assert_eq!(report.height, 7);
+        assert!(report.mempool.is_none());
+        assert!(report.bft.is_none());
+    }
+}
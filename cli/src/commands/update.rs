@@ -12,10 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::helpers::Updater;
+use crate::helpers::{OutputFormat, ReleaseSource, Updater};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
+use serde_json::json;
 
 /// Update snarkOS.
 #[derive(Debug, Parser)]
@@ -29,18 +30,49 @@ pub struct Update {
     /// Update to specified version
     #[clap(short = 'v', long)]
     version: Option<String>,
+    /// Roll back to the version replaced by the last update
+    #[clap(long, conflicts_with_all = ["list", "version"])]
+    rollback: bool,
+    /// Use a release mirror, as a URL or a directory with a 'releases.json' index
+    #[clap(long)]
+    source: Option<String>,
+    /// The hex-encoded Ed25519 public key that signs the releases of the mirror
+    #[clap(long, requires = "source")]
+    public_key: Option<String>,
+    /// Install the GitHub releases without verification, as they do not publish signed checksums yet
+    #[clap(long, conflicts_with = "source")]
+    allow_unverified: bool,
 }
 
 impl Update {
     /// Update snarkOS.
     pub fn parse(self) -> Result<String> {
+        // Output the result as JSON, if requested.
+        if OutputFormat::is_json() {
+            return self.parse_json();
+        }
+
+        if self.rollback {
+            return match Updater::rollback() {
+                Ok(version) => Ok(format!("snarkOS has rolled back to version {version}")),
+                Err(error) => Ok(format!("Failed to roll back snarkOS\n{error}\n")),
+            };
+        }
+
+        let source = ReleaseSource::from(self.source);
         match self.list {
-            true => match Updater::show_available_releases() {
+            true => match Updater::show_available_releases(&source) {
                 Ok(output) => Ok(output),
                 Err(error) => Ok(format!("Failed to list the available versions of snarkOS\n{error}\n")),
             },
             false => {
-                let result = Updater::update_to_release(!self.quiet, self.version);
+                let result = Updater::update_to_release(
+                    !self.quiet,
+                    self.version,
+                    &source,
+                    self.public_key.as_deref(),
+                    self.allow_unverified,
+                );
                 if !self.quiet {
                     match result {
                         Ok(status) => {
@@ -60,4 +92,58 @@ impl Update {
             }
         }
     }
+
+    /// Update snarkOS, and returns the result as a JSON document.
+    fn parse_json(self) -> Result<String> {
+        if self.rollback {
+            let version = Updater::rollback().context("Failed to roll back snarkOS")?;
+            return Ok(serde_json::to_string(&json!({ "rolled_back": true, "version": version }))?);
+        }
+
+        let source = ReleaseSource::from(self.source);
+        let output = match self.list {
+            true => {
+                let versions =
+                    Updater::available_releases(&source).context("Failed to list the available versions of snarkOS")?;
+                json!({ "versions": versions })
+            }
+            false => {
+                // Note: The progress of the update is not shown, as it would be interleaved with the document.
+                let public_key = self.public_key.as_deref();
+                let status =
+                    Updater::update_to_release(false, self.version, &source, public_key, self.allow_unverified)
+                        .context("Failed to update snarkOS")?;
+                json!({ "updated": status.updated(), "version": status.version() })
+            }
+        };
+        Ok(serde_json::to_string(&output)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{Command, CLI};
+
+    #[test]
+    fn clap_snarkos_update() {
+        let cli =
+            CLI::parse_from(["snarkos", "update", "--source", "https://mirror.local/snarkos", "--public-key", "00"]);
+        if let Command::Update(update) = cli.command {
+            assert!(!update.rollback);
+            assert_eq!(update.source.as_deref(), Some("https://mirror.local/snarkos"));
+            assert_eq!(update.public_key.as_deref(), Some("00"));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        // Ensure a rollback does not select a version.
+        assert!(CLI::try_parse_from(["snarkos", "update", "--rollback"]).is_ok());
+        assert!(CLI::try_parse_from(["snarkos", "update", "--rollback", "--version", "2.2.5"]).is_err());
+        // Ensure a custom public key requires a mirror.
+        assert!(CLI::try_parse_from(["snarkos", "update", "--public-key", "00"]).is_err());
+        // Ensure an unverified update is only allowed for the GitHub releases.
+        assert!(CLI::try_parse_from(["snarkos", "update", "--allow-unverified"]).is_ok());
+        assert!(CLI::try_parse_from(["snarkos", "update", "--allow-unverified", "--source", "mirror"]).is_err());
+    }
 }
@@ -13,13 +13,15 @@
 // limitations under the License.
 
 use std::io;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 
 pub enum LogWriter {
     /// Writes to stdout.
     Stdout(io::Stdout),
     /// Writes to a channel.
     Sender(mpsc::Sender<Vec<u8>>),
+    /// Writes to the subscribers of a channel.
+    Broadcast(broadcast::Sender<String>),
 }
 
 impl LogWriter {
@@ -39,6 +41,11 @@ impl io::Write for LogWriter {
                 let _ = sender.try_send(log);
                 Ok(buf.len())
             }
+            Self::Broadcast(sender) => {
+                // Sending only fails if there are no subscribers, in which case the log is dropped.
+                let _ = sender.send(String::from_utf8_lossy(buf).into_owned());
+                Ok(buf.len())
+            }
         }
     }
 
@@ -14,16 +14,21 @@
 
 use crate::helpers::LogWriter;
 
+use anyhow::{ensure, Result};
 use crossterm::tty::IsTty;
 use std::{fs::File, io, path::Path};
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use tracing_subscriber::{
     layer::{Layer, SubscriberExt},
+    reload,
     util::SubscriberInitExt,
     EnvFilter,
 };
 
-/// Initializes the logger.
+/// The highest verbosity level, as described in [`log_filter`].
+const MAX_VERBOSITY: u8 = 6;
+
+/// Returns the log filter for the given verbosity level.
 ///
 /// ```ignore
 /// 0 => info
@@ -34,55 +39,72 @@ use tracing_subscriber::{
 /// 5 => info, debug, trace, snarkos_node_router=trace
 /// 6 => info, debug, trace, snarkos_node_tcp=trace
 /// ```
-pub fn initialize_logger<P: AsRef<Path>>(verbosity: u8, nodisplay: bool, logfile: P) -> mpsc::Receiver<Vec<u8>> {
-    match verbosity {
-        0 => std::env::set_var("RUST_LOG", "info"),
-        1 => std::env::set_var("RUST_LOG", "debug"),
-        2.. => std::env::set_var("RUST_LOG", "trace"),
+fn log_filter(verbosity: u8) -> EnvFilter {
+    let filter = match verbosity {
+        0 => EnvFilter::new("info"),
+        1 => EnvFilter::new("debug"),
+        2.. => EnvFilter::new("trace"),
     };
 
-    // Filter out undesirable logs. (unfortunately EnvFilter cannot be cloned)
-    let [filter, filter2] = std::array::from_fn(|_| {
-        let filter = EnvFilter::from_default_env()
-            .add_directive("mio=off".parse().unwrap())
-            .add_directive("tokio_util=off".parse().unwrap())
-            .add_directive("hyper=off".parse().unwrap())
-            .add_directive("reqwest=off".parse().unwrap())
-            .add_directive("want=off".parse().unwrap())
-            .add_directive("warp=off".parse().unwrap());
-
-        let filter = if verbosity >= 2 {
-            filter.add_directive("snarkos_node_sync=trace".parse().unwrap())
-        } else {
-            filter.add_directive("snarkos_node_sync=debug".parse().unwrap())
-        };
-
-        let filter = if verbosity >= 3 {
-            filter
-                .add_directive("snarkos_node_bft=trace".parse().unwrap())
-                .add_directive("snarkos_node_bft::gateway=debug".parse().unwrap())
-        } else {
-            filter.add_directive("snarkos_node_bft=debug".parse().unwrap())
-        };
-
-        let filter = if verbosity >= 4 {
-            filter.add_directive("snarkos_node_bft::gateway=trace".parse().unwrap())
-        } else {
-            filter.add_directive("snarkos_node_bft::gateway=debug".parse().unwrap())
-        };
-
-        let filter = if verbosity >= 5 {
-            filter.add_directive("snarkos_node_router=trace".parse().unwrap())
-        } else {
-            filter.add_directive("snarkos_node_router=debug".parse().unwrap())
-        };
-
-        if verbosity >= 6 {
-            filter.add_directive("snarkos_node_tcp=trace".parse().unwrap())
-        } else {
-            filter.add_directive("snarkos_node_tcp=off".parse().unwrap())
-        }
-    });
+    // Filter out undesirable logs.
+    let filter = filter
+        .add_directive("mio=off".parse().unwrap())
+        .add_directive("tokio_util=off".parse().unwrap())
+        .add_directive("hyper=off".parse().unwrap())
+        .add_directive("reqwest=off".parse().unwrap())
+        .add_directive("want=off".parse().unwrap())
+        .add_directive("warp=off".parse().unwrap());
+
+    let filter = if verbosity >= 2 {
+        filter.add_directive("snarkos_node_sync=trace".parse().unwrap())
+    } else {
+        filter.add_directive("snarkos_node_sync=debug".parse().unwrap())
+    };
+
+    let filter = if verbosity >= 3 {
+        filter
+            .add_directive("snarkos_node_bft=trace".parse().unwrap())
+            .add_directive("snarkos_node_bft::gateway=debug".parse().unwrap())
+    } else {
+        filter.add_directive("snarkos_node_bft=debug".parse().unwrap())
+    };
+
+    let filter = if verbosity >= 4 {
+        filter.add_directive("snarkos_node_bft::gateway=trace".parse().unwrap())
+    } else {
+        filter.add_directive("snarkos_node_bft::gateway=debug".parse().unwrap())
+    };
+
+    let filter = if verbosity >= 5 {
+        filter.add_directive("snarkos_node_router=trace".parse().unwrap())
+    } else {
+        filter.add_directive("snarkos_node_router=debug".parse().unwrap())
+    };
+
+    if verbosity >= 6 {
+        filter.add_directive("snarkos_node_tcp=trace".parse().unwrap())
+    } else {
+        filter.add_directive("snarkos_node_tcp=off".parse().unwrap())
+    }
+}
+
+/// Initializes the logger, with the filter of the given verbosity level (see [`log_filter`]).
+///
+/// If a dashboard sender is given, the logs are also sent to the web dashboard.
+///
+/// Returns the receiver of the logs for the display, and a function to change the verbosity level at runtime.
+pub fn initialize_logger<P: AsRef<Path>>(
+    verbosity: u8,
+    nodisplay: bool,
+    logfile: P,
+    dashboard_sender: Option<broadcast::Sender<String>>,
+) -> (mpsc::Receiver<Vec<u8>>, impl Fn(u8) -> Result<()>) {
+    // The filters are reloadable, so the verbosity can be changed at runtime. (unfortunately EnvFilter cannot be cloned)
+    let (filter, filter_handle) = reload::Layer::new(log_filter(verbosity));
+    let (filter2, filter2_handle) = reload::Layer::new(log_filter(verbosity));
+    let (filter3, filter3_handle) = reload::Layer::new(log_filter(verbosity));
+    // The filter of the dashboard is only reloaded if the dashboard is enabled, as its layer is dropped otherwise.
+    let filter3_handle = dashboard_sender.is_some().then_some(filter3_handle);
 
     // Create the directories tree for a logfile if it doesn't exist.
     let logfile_dir = logfile.as_ref().parent().expect("Root directory passed as a logfile");
@@ -110,7 +132,8 @@ pub fn initialize_logger<P: AsRef<Path>>(verbosity: u8, nodisplay: bool, logfile
             tracing_subscriber::fmt::Layer::default()
                 .with_ansi(log_sender.is_none() && io::stdout().is_tty())
                 .with_writer(move || LogWriter::new(&log_sender))
-                .with_target(verbosity > 2)
+                // The display filters the logs by their target, so it is always included there.
+                .with_target(verbosity > 2 || !nodisplay)
                 .with_filter(filter),
         )
         .with(
@@ -121,9 +144,28 @@ pub fn initialize_logger<P: AsRef<Path>>(verbosity: u8, nodisplay: bool, logfile
                 .with_target(verbosity > 2)
                 .with_filter(filter2),
         )
+        .with(dashboard_sender.map(|sender| {
+            // Add layer sending logs to the web dashboard
+            tracing_subscriber::fmt::Layer::default()
+                .with_ansi(false)
+                .with_writer(move || LogWriter::Broadcast(sender.clone()))
+                .with_target(true)
+                .with_filter(filter3)
+        }))
         .try_init();
 
-    log_receiver
+    // Initialize the function to change the verbosity level.
+    let set_verbosity = move |verbosity: u8| {
+        ensure!(verbosity <= MAX_VERBOSITY, "The verbosity level must be at most {MAX_VERBOSITY}");
+        filter_handle.reload(log_filter(verbosity))?;
+        filter2_handle.reload(log_filter(verbosity))?;
+        if let Some(filter3_handle) = &filter3_handle {
+            filter3_handle.reload(log_filter(verbosity))?;
+        }
+        Ok(())
+    };
+
+    (log_receiver, set_verbosity)
 }
 
 /// Returns the welcome message as a string.
@@ -21,6 +21,10 @@ use log_writer::*;
 pub mod logger;
 pub use logger::*;
 
+mod output;
+pub(crate) use output::progress;
+pub use output::*;
+
 pub mod updater;
 pub use updater::*;
 
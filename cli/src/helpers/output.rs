@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::UpdaterError;
+
+use anyhow::Error;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::json;
+use std::sync::OnceLock;
+
+/// The output format of the CLI, as selected with the global `--output` option.
+static OUTPUT_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// The format in which the CLI emits the result of a command.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text, with colors.
+    #[default]
+    Text,
+    /// A single JSON document on stdout, with progress messages on stderr.
+    Json,
+}
+
+impl OutputFormat {
+    /// Sets the output format for the lifetime of the process.
+    pub fn initialize(self) {
+        // Disable colors, so that no escape codes leak into the document.
+        if self == Self::Json {
+            colored::control::set_override(false);
+        }
+        let _ = OUTPUT_FORMAT.set(self);
+    }
+
+    /// Returns `true` if the CLI should emit JSON.
+    pub fn is_json() -> bool {
+        OUTPUT_FORMAT.get().copied().unwrap_or_default() == Self::Json
+    }
+
+    /// Returns `true` if the given arguments request JSON output.
+    /// This is used to report argument errors, before the arguments are parsed.
+    pub fn is_requested_in<I: IntoIterator<Item = String>>(args: I) -> bool {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--output=json" || (arg == "--output" && args.next().as_deref() == Some("json")) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// The stable codes of the errors reported in JSON output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The arguments could not be parsed.
+    InvalidArguments,
+    /// A request to a node or to the release server failed.
+    Network,
+    /// A file could not be read or written.
+    Io,
+    /// A JSON document could not be parsed.
+    Parse,
+    /// A downloaded release failed its signature or checksum verification.
+    Verification,
+    /// The command failed for any other reason.
+    CommandFailed,
+}
+
+impl ErrorCode {
+    /// Classifies the given error, from the first recognized cause in its chain.
+    pub fn of(error: &Error) -> Self {
+        for cause in error.chain() {
+            if let Some(UpdaterError::Verification(_)) = cause.downcast_ref::<UpdaterError>() {
+                return Self::Verification;
+            } else if cause.is::<ureq::Error>() || cause.is::<UpdaterError>() {
+                return Self::Network;
+            } else if cause.is::<std::io::Error>() {
+                return Self::Io;
+            } else if cause.is::<serde_json::Error>() {
+                return Self::Parse;
+            }
+        }
+        Self::CommandFailed
+    }
+}
+
+/// Returns the JSON document for the successful result of a command.
+/// The result is embedded as JSON if it is a JSON document, and as a string otherwise.
+pub fn json_success(result: &str) -> String {
+    let result = serde_json::from_str::<serde_json::Value>(result).unwrap_or_else(|_| json!(result.trim()));
+    json!({ "status": "ok", "result": result }).to_string()
+}
+
+/// Returns the JSON document for the failure of a command.
+pub fn json_error(code: ErrorCode, message: &str) -> String {
+    json!({ "status": "error", "error": { "code": code, "message": message.trim() } }).to_string()
+}
+
+/// Returns the given value as a JSON document if the CLI emits JSON, and as text otherwise.
+pub fn format_output<T: Serialize>(value: &T, text: impl FnOnce() -> String) -> anyhow::Result<String> {
+    match OutputFormat::is_json() {
+        true => Ok(serde_json::to_string(value)?),
+        false => Ok(text()),
+    }
+}
+
+/// Prints a progress message. In JSON mode, the message is printed to stderr,
+/// so that stdout only contains the resulting JSON document.
+macro_rules! progress {
+    ($($arg:tt)*) => {
+        if $crate::helpers::OutputFormat::is_json() {
+            eprintln!($($arg)*)
+        } else {
+            println!($($arg)*)
+        }
+    };
+}
+pub(crate) use progress;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_is_requested_in() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert!(OutputFormat::is_requested_in(args(&["snarkos", "--output", "json", "clean"])));
+        assert!(OutputFormat::is_requested_in(args(&["snarkos", "clean", "--output=json"])));
+        assert!(!OutputFormat::is_requested_in(args(&["snarkos", "clean", "--output", "text"])));
+        assert!(!OutputFormat::is_requested_in(args(&["snarkos", "clean", "json"])));
+    }
+
+    #[test]
+    fn test_error_code() {
+        let io = anyhow!(std::io::Error::new(std::io::ErrorKind::NotFound, "missing")).context("Failed to read");
+        assert_eq!(ErrorCode::of(&io), ErrorCode::Io);
+        let parse = anyhow!(serde_json::from_str::<u8>("{").unwrap_err());
+        assert_eq!(ErrorCode::of(&parse), ErrorCode::Parse);
+        assert_eq!(ErrorCode::of(&anyhow!("Invalid amount")), ErrorCode::CommandFailed);
+        let verification =
+            anyhow!(UpdaterError::Verification("Invalid signature".to_string())).context("Failed to update");
+        assert_eq!(ErrorCode::of(&verification), ErrorCode::Verification);
+        let network = anyhow!(UpdaterError::Crate("ureq", "Connection refused".to_string()));
+        assert_eq!(ErrorCode::of(&network), ErrorCode::Network);
+    }
+
+    #[test]
+    fn test_json_documents() {
+        assert_eq!(json_success(r#"{"a":1}"#), r#"{"status":"ok","result":{"a":1}}"#);
+        assert_eq!(json_success("at1abc\n"), r#"{"status":"ok","result":"at1abc"}"#);
+        assert_eq!(
+            json_error(ErrorCode::Io, "Failed to read\n"),
+            r#"{"status":"error","error":{"code":"io","message":"Failed to read"}}"#
+        );
+    }
+}
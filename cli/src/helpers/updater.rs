@@ -13,8 +13,81 @@
 // limitations under the License.
 
 use colored::Colorize;
-use self_update::{backends::github, version::bump_is_greater, Status};
-use std::fmt::Write;
+use ring::signature::{UnparsedPublicKey, ED25519};
+use self_update::{backends::github, update::Release, version::bump_is_greater, Status};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+/// The name of the release asset listing the SHA-256 checksum of every other asset.
+const RELEASE_MANIFEST: &str = "SHA256SUMS";
+/// The name of the release asset holding the detached signature of the manifest.
+const RELEASE_MANIFEST_SIGNATURE: &str = "SHA256SUMS.sig";
+/// The name of the index of a custom release source.
+const RELEASE_INDEX: &str = "releases.json";
+
+/// The source of the snarkOS releases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReleaseSource {
+    /// The GitHub releases of the snarkOS repository.
+    GitHub,
+    /// A mirror, as a URL or a directory, with a 'releases.json' index of the releases (newest first),
+    /// and the assets of each release in the '{version}/' subdirectory.
+    Mirror(String),
+}
+
+impl From<Option<String>> for ReleaseSource {
+    fn from(source: Option<String>) -> Self {
+        match source {
+            Some(source) => Self::Mirror(source.trim_end_matches('/').to_string()),
+            None => Self::GitHub,
+        }
+    }
+}
+
+/// A release in the index of a mirror.
+#[derive(Debug, Deserialize)]
+struct MirrorRelease {
+    /// The version of the release.
+    version: String,
+    /// The names of the assets of the release.
+    assets: Vec<String>,
+}
+
+/// A release of snarkOS, with the names and locations of its assets.
+struct ReleaseInfo {
+    version: String,
+    assets: Vec<(String, String)>,
+}
+
+impl ReleaseInfo {
+    /// Returns the location of the asset with the given name.
+    fn asset(&self, name: &str) -> Result<&str, UpdaterError> {
+        match self.assets.iter().find(|(asset, _)| asset == name) {
+            Some((_, location)) => Ok(location),
+            None => Err(UpdaterError::MissingAsset(self.version.clone(), name.to_string())),
+        }
+    }
+
+    /// Returns the name and location of the binary asset for the given target.
+    fn binary_for(&self, target: &str) -> Result<(&str, &str), UpdaterError> {
+        self.assets
+            .iter()
+            .find(|(name, _)| name.contains(target) && !name.starts_with(RELEASE_MANIFEST))
+            .map(|(name, location)| (name.as_str(), location.as_str()))
+            .ok_or_else(|| UpdaterError::MissingAsset(self.version.clone(), target.to_string()))
+    }
+}
+
+impl From<Release> for ReleaseInfo {
+    fn from(release: Release) -> Self {
+        let assets = release.assets.into_iter().map(|asset| (asset.name, asset.download_url)).collect();
+        Self { version: release.version, assets }
+    }
+}
 
 pub struct Updater;
 
@@ -23,40 +96,62 @@ impl Updater {
     const SNARKOS_REPO_NAME: &'static str = "snarkOS";
     const SNARKOS_REPO_OWNER: &'static str = "AleoHQ";
 
-    /// Show all available releases for `snarkos`.
-    pub fn show_available_releases() -> Result<String, UpdaterError> {
-        let releases = github::ReleaseList::configure()
-            .repo_owner(Self::SNARKOS_REPO_OWNER)
-            .repo_name(Self::SNARKOS_REPO_NAME)
-            .build()?
-            .fetch()?;
+    /// Returns the versions of all available releases for `snarkos`.
+    pub fn available_releases(source: &ReleaseSource) -> Result<Vec<String>, UpdaterError> {
+        match source {
+            ReleaseSource::GitHub => {
+                let releases = github::ReleaseList::configure()
+                    .repo_owner(Self::SNARKOS_REPO_OWNER)
+                    .repo_name(Self::SNARKOS_REPO_NAME)
+                    .build()?
+                    .fetch()?;
+                Ok(releases.into_iter().map(|release| release.version).collect())
+            }
+            ReleaseSource::Mirror(url) => {
+                Ok(Self::mirror_releases(url)?.into_iter().map(|release| release.version).collect())
+            }
+        }
+    }
 
+    /// Show all available releases for `snarkos`.
+    pub fn show_available_releases(source: &ReleaseSource) -> Result<String, UpdaterError> {
         let mut output = "List of available versions\n".to_string();
-        for release in releases {
-            let _ = writeln!(output, "  * {}", release.version);
+        for version in Self::available_releases(source)? {
+            let _ = writeln!(output, "  * {version}");
         }
         Ok(output)
     }
 
-    /// Update `snarkOS` to the specified release.
-    pub fn update_to_release(show_output: bool, version: Option<String>) -> Result<Status, UpdaterError> {
-        let mut update_builder = github::Update::configure();
-
-        update_builder
-            .repo_owner(Self::SNARKOS_REPO_OWNER)
-            .repo_name(Self::SNARKOS_REPO_NAME)
-            .bin_name(Self::SNARKOS_BIN_NAME)
-            .current_version(env!("CARGO_PKG_VERSION"))
-            .show_download_progress(show_output)
-            .no_confirm(true)
-            .show_output(show_output);
+    /// Update `snarkOS` to the specified release, or to the latest release if it is newer.
+    ///
+    /// The binary of a mirror is only replaced once the release manifest is verified against the given public key,
+    /// and the binary against its checksum in the manifest. As the GitHub releases do not publish a signed manifest,
+    /// they are only installed (without verification) if `allow_unverified` is set.
+    /// The replaced binary is kept, for `snarkos update --rollback`.
+    pub fn update_to_release(
+        show_output: bool,
+        version: Option<String>,
+        source: &ReleaseSource,
+        public_key: Option<&str>,
+        allow_unverified: bool,
+    ) -> Result<Status, UpdaterError> {
+        let public_key = Self::release_public_key(source, public_key, allow_unverified)?;
+        Self::update_binary(show_output, version, source, public_key, &std::env::current_exe()?, &Self::previous_dir())
+    }
 
-        let status = match version {
-            None => update_builder.build()?.update()?,
-            Some(v) => update_builder.target_version_tag(&v).build()?.update()?,
+    /// Swaps the current binary with the one replaced by the last update, and returns the restored version.
+    pub fn rollback() -> Result<String, UpdaterError> {
+        let Some((previous_version, previous_path)) = Self::previous_binary()? else {
+            return Err(UpdaterError::Crate("rollback", "No previous binary was kept by an update".to_string()));
         };
-
-        Ok(status)
+        // Keep the current binary, so the rollback can itself be undone.
+        let current_binary = std::fs::read(std::env::current_exe()?)?;
+        self_update::self_replace::self_replace(&previous_path)?;
+        std::fs::remove_file(previous_path)?;
+        let current_path =
+            Self::previous_dir().join(format!("{}-{}", Self::SNARKOS_BIN_NAME, env!("CARGO_PKG_VERSION")));
+        std::fs::write(current_path, current_binary)?;
+        Ok(previous_version)
     }
 
     /// Check if there is an available update for `aleo` and return the newest release.
@@ -91,6 +186,239 @@ impl Updater {
     }
 }
 
+impl Updater {
+    /// Returns the public key to verify the releases from the given source with, or `None` to skip the verification.
+    /// A mirror must be verified with its public key, while the GitHub releases (which are not signed yet)
+    /// may only be installed without verification if it is explicitly allowed.
+    fn release_public_key<'a>(
+        source: &ReleaseSource,
+        public_key: Option<&'a str>,
+        allow_unverified: bool,
+    ) -> Result<Option<&'a str>, UpdaterError> {
+        match (source, public_key) {
+            (ReleaseSource::Mirror(_), Some(public_key)) => Ok(Some(public_key)),
+            (ReleaseSource::Mirror(_), None) => {
+                Err(UpdaterError::Verification("A release mirror requires the public key that signs it".to_string()))
+            }
+            (ReleaseSource::GitHub, Some(_)) => {
+                Err(UpdaterError::Verification("A custom public key is only trusted for a release mirror".to_string()))
+            }
+            (ReleaseSource::GitHub, None) if allow_unverified => Ok(None),
+            (ReleaseSource::GitHub, None) => Err(UpdaterError::Verification(
+                "The GitHub releases are not signed yet, use '--allow-unverified' to install them".to_string(),
+            )),
+        }
+    }
+
+    /// Replaces the given binary with the specified release, or with the latest release if it is newer.
+    /// If a public key is given, the release is verified with it before the binary is replaced.
+    fn update_binary(
+        show_output: bool,
+        version: Option<String>,
+        source: &ReleaseSource,
+        public_key: Option<&str>,
+        current_exe: &Path,
+        previous_dir: &Path,
+    ) -> Result<Status, UpdaterError> {
+        let current_version = env!("CARGO_PKG_VERSION");
+
+        // Retrieve the release.
+        let release = Self::release(source, version.as_deref())?;
+        if version.is_none() && !bump_is_greater(current_version, &release.version)? {
+            return Ok(Status::UpToDate(current_version.to_string()));
+        }
+        let (binary_name, binary_location) = release.binary_for(&self_update::get_target())?;
+
+        let binary = match public_key {
+            // Verify the release manifest, and the checksum of the binary.
+            Some(public_key) => {
+                if show_output {
+                    println!("Verifying the snarkOS {} release ({binary_name})...", release.version);
+                }
+                let manifest = Self::fetch(release.asset(RELEASE_MANIFEST)?)?;
+                let signature = Self::fetch(release.asset(RELEASE_MANIFEST_SIGNATURE)?)?;
+                Self::verify_manifest(&manifest, &signature, public_key)?;
+                let binary = Self::fetch(binary_location)?;
+                Self::verify_checksum(&manifest, binary_name, &binary)?;
+                binary
+            }
+            None => {
+                if show_output {
+                    println!(
+                        "Downloading the snarkOS {} release ({binary_name}) without verification...",
+                        release.version
+                    );
+                }
+                Self::fetch(binary_location)?
+            }
+        };
+
+        // Extract the binary next to the current one.
+        let temp_dir = std::env::temp_dir().join(format!("snarkos-update-{}", std::process::id()));
+        std::fs::create_dir_all(&temp_dir)?;
+        let result = Self::install(&temp_dir, binary_name, &binary, current_version, current_exe, previous_dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        result?;
+
+        if show_output {
+            println!("Replaced snarkOS v{current_version} with v{}", release.version);
+        }
+        Ok(Status::Updated(release.version))
+    }
+
+    /// Returns the specified release, or the latest release, from the given source.
+    fn release(source: &ReleaseSource, version: Option<&str>) -> Result<ReleaseInfo, UpdaterError> {
+        match source {
+            ReleaseSource::GitHub => {
+                let updater = github::Update::configure()
+                    .repo_owner(Self::SNARKOS_REPO_OWNER)
+                    .repo_name(Self::SNARKOS_REPO_NAME)
+                    .bin_name(Self::SNARKOS_BIN_NAME)
+                    .current_version(env!("CARGO_PKG_VERSION"))
+                    .build()?;
+                let release = match version {
+                    Some(version) => updater.get_release_version(version)?,
+                    None => updater.get_latest_release()?,
+                };
+                Ok(release.into())
+            }
+            ReleaseSource::Mirror(url) => {
+                let releases = Self::mirror_releases(url)?;
+                let release = match version {
+                    Some(version) => {
+                        let version = version.trim_start_matches('v');
+                        releases.into_iter().find(|release| release.version.trim_start_matches('v') == version)
+                    }
+                    None => releases.into_iter().next(),
+                };
+                let Some(release) = release else {
+                    return Err(UpdaterError::Crate("mirror", format!("No matching release was found in '{url}'")));
+                };
+                let assets = release
+                    .assets
+                    .into_iter()
+                    .map(|name| {
+                        let location = format!("{url}/{}/{name}", release.version);
+                        (name, location)
+                    })
+                    .collect();
+                Ok(ReleaseInfo { version: release.version, assets })
+            }
+        }
+    }
+
+    /// Returns the releases in the index of the given mirror.
+    fn mirror_releases(url: &str) -> Result<Vec<MirrorRelease>, UpdaterError> {
+        let index = Self::fetch(&format!("{url}/{RELEASE_INDEX}"))?;
+        serde_json::from_slice(&index).map_err(|error| UpdaterError::Crate("mirror", error.to_string()))
+    }
+
+    /// Returns the contents at the given URL or path.
+    fn fetch(location: &str) -> Result<Vec<u8>, UpdaterError> {
+        if location.starts_with("http://") || location.starts_with("https://") {
+            let response = ureq::get(location).set("Accept", "application/octet-stream").call()?;
+            let mut bytes = Vec::new();
+            std::io::Read::read_to_end(&mut response.into_reader(), &mut bytes)?;
+            Ok(bytes)
+        } else {
+            Ok(std::fs::read(location)?)
+        }
+    }
+
+    /// Ensures the manifest is signed by the given hex-encoded Ed25519 public key.
+    /// The signature is either the raw 64 bytes, or their hex encoding.
+    fn verify_manifest(manifest: &[u8], signature: &[u8], public_key: &str) -> Result<(), UpdaterError> {
+        let public_key = hex::decode(public_key.trim())
+            .map_err(|_| UpdaterError::Verification("The public key is not hex-encoded".to_string()))?;
+        let signature = match signature.len() {
+            64 => signature.to_vec(),
+            _ => hex::decode(String::from_utf8_lossy(signature).trim())
+                .map_err(|_| UpdaterError::Verification("The manifest signature is malformed".to_string()))?,
+        };
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(manifest, &signature)
+            .map_err(|_| UpdaterError::Verification("The manifest signature is invalid".to_string()))
+    }
+
+    /// Ensures the SHA-256 checksum of the given asset matches the one in the manifest.
+    fn verify_checksum(manifest: &[u8], name: &str, asset: &[u8]) -> Result<(), UpdaterError> {
+        // Each line of the manifest is in the format of 'sha256sum', i.e. the checksum and the name.
+        let manifest = String::from_utf8_lossy(manifest);
+        let expected = manifest.lines().find_map(|line| {
+            let (checksum, file) = line.split_once(char::is_whitespace)?;
+            (file.trim().trim_start_matches('*') == name).then_some(checksum)
+        });
+        let Some(expected) = expected else {
+            return Err(UpdaterError::Verification(format!("The manifest does not list '{name}'")));
+        };
+        let checksum = hex::encode(Sha256::digest(asset));
+        match checksum.eq_ignore_ascii_case(expected) {
+            true => Ok(()),
+            false => Err(UpdaterError::Verification(format!("The checksum of '{name}' does not match the manifest"))),
+        }
+    }
+
+    /// Replaces the current binary with the given release asset, keeping the current binary in the previous directory.
+    fn install(
+        temp_dir: &Path,
+        name: &str,
+        asset: &[u8],
+        current_version: &str,
+        current_exe: &Path,
+        previous_dir: &Path,
+    ) -> Result<(), UpdaterError> {
+        // Write the asset in its own directory, as it may have the same name as the binary.
+        let asset_path = temp_dir.join("asset").join(name);
+        std::fs::create_dir_all(temp_dir.join("asset"))?;
+        std::fs::write(&asset_path, asset)?;
+        self_update::Extract::from_source(&asset_path).extract_file(temp_dir, Self::SNARKOS_BIN_NAME)?;
+        let binary_path = temp_dir.join(Self::SNARKOS_BIN_NAME);
+        #[cfg(target_family = "unix")]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&binary_path, std::fs::Permissions::from_mode(0o755))?;
+        }
+
+        // Keep only the current binary as the previous one.
+        if previous_dir.exists() {
+            std::fs::remove_dir_all(previous_dir)?;
+        }
+        std::fs::create_dir_all(previous_dir)?;
+        std::fs::copy(current_exe, previous_dir.join(format!("{}-{current_version}", Self::SNARKOS_BIN_NAME)))?;
+
+        // Replace the binary. The running binary must be replaced in place, as it is in use.
+        match current_exe == std::env::current_exe()? {
+            true => self_update::self_replace::self_replace(&binary_path)?,
+            false => {
+                std::fs::copy(&binary_path, current_exe)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the directory holding the binary replaced by the last update.
+    fn previous_dir() -> PathBuf {
+        aleo_std::aleo_dir().join("update").join("previous")
+    }
+
+    /// Returns the version and path of the binary replaced by the last update, if any.
+    fn previous_binary() -> Result<Option<(String, PathBuf)>, UpdaterError> {
+        let previous_dir = Self::previous_dir();
+        if !previous_dir.exists() {
+            return Ok(None);
+        }
+        let prefix = format!("{}-", Self::SNARKOS_BIN_NAME);
+        for entry in std::fs::read_dir(previous_dir)? {
+            let path = entry?.path();
+            let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            if let Some(version) = name.strip_prefix(&prefix) {
+                return Ok(Some((version.to_string(), path)));
+            }
+        }
+        Ok(None)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum UpdaterError {
     #[error("{}: {}", _0, _1)]
@@ -98,6 +426,12 @@ pub enum UpdaterError {
 
     #[error("The current version {} is more recent than the release version {}", _0, _1)]
     OldReleaseVersion(String, String),
+
+    #[error("The release {} has no '{}' asset", _0, _1)]
+    MissingAsset(String, String),
+
+    #[error("Failed to verify the release: {}", _0)]
+    Verification(String),
 }
 
 impl From<self_update::errors::Error> for UpdaterError {
@@ -105,3 +439,124 @@ impl From<self_update::errors::Error> for UpdaterError {
         UpdaterError::Crate("self_update", error.to_string())
     }
 }
+
+impl From<std::io::Error> for UpdaterError {
+    fn from(error: std::io::Error) -> Self {
+        UpdaterError::Crate("io", error.to_string())
+    }
+}
+
+impl From<ureq::Error> for UpdaterError {
+    fn from(error: ureq::Error) -> Self {
+        UpdaterError::Crate("ureq", error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::{
+        rand::SystemRandom,
+        signature::{Ed25519KeyPair, KeyPair},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_verify_release() {
+        let binary = b"snarkos binary";
+        let name = "snarkos-x86_64-unknown-linux-gnu";
+        let manifest = format!("{}  {name}\n", hex::encode(Sha256::digest(binary)));
+
+        // Sign the manifest.
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let public_key = hex::encode(key_pair.public_key().as_ref());
+        let signature = key_pair.sign(manifest.as_bytes());
+
+        // Ensure the signature is verified, in raw or hex encoding.
+        Updater::verify_manifest(manifest.as_bytes(), signature.as_ref(), &public_key).unwrap();
+        Updater::verify_manifest(manifest.as_bytes(), hex::encode(signature.as_ref()).as_bytes(), &public_key).unwrap();
+        // Ensure a tampered manifest is rejected.
+        let tampered = manifest.replace(name, "snarkos-other");
+        assert!(Updater::verify_manifest(tampered.as_bytes(), signature.as_ref(), &public_key).is_err());
+
+        // Ensure the checksum of the binary is verified.
+        Updater::verify_checksum(manifest.as_bytes(), name, binary).unwrap();
+        assert!(Updater::verify_checksum(manifest.as_bytes(), name, b"tampered binary").is_err());
+        assert!(Updater::verify_checksum(manifest.as_bytes(), "snarkos-other", binary).is_err());
+
+        // Ensure a custom public key is only trusted for a mirror, which must be verified.
+        let mirror = ReleaseSource::Mirror("https://mirror.local/snarkos".to_string());
+        assert_eq!(Updater::release_public_key(&mirror, Some(&public_key), false).unwrap(), Some(public_key.as_str()));
+        assert!(Updater::release_public_key(&mirror, None, true).is_err());
+        assert!(Updater::release_public_key(&ReleaseSource::GitHub, Some(&public_key), false).is_err());
+        // Ensure the GitHub releases are only installed without verification if it is explicitly allowed.
+        assert!(Updater::release_public_key(&ReleaseSource::GitHub, None, false).is_err());
+        assert_eq!(Updater::release_public_key(&ReleaseSource::GitHub, None, true).unwrap(), None);
+    }
+
+    #[test]
+    fn test_update_from_mirror() {
+        let dir = std::env::temp_dir().join(format!("snarkos-update-mirror-{}", std::process::id()));
+        let (mirror, previous_dir) = (dir.join("mirror"), dir.join("previous"));
+        std::fs::create_dir_all(mirror.join("99.0.0")).unwrap();
+
+        // Publish a signed release of the binary for the current target.
+        let (binary, name) = (b"new snarkos binary", format!("snarkos-{}", self_update::get_target()));
+        let manifest = format!("{}  {name}\n", hex::encode(Sha256::digest(binary)));
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let public_key = hex::encode(key_pair.public_key().as_ref());
+        let index = json!([{ "version": "99.0.0", "assets": [&name, RELEASE_MANIFEST, RELEASE_MANIFEST_SIGNATURE] }]);
+        std::fs::write(mirror.join(RELEASE_INDEX), index.to_string()).unwrap();
+        std::fs::write(mirror.join("99.0.0").join(&name), binary).unwrap();
+        std::fs::write(mirror.join("99.0.0").join(RELEASE_MANIFEST), &manifest).unwrap();
+        std::fs::write(mirror.join("99.0.0").join(RELEASE_MANIFEST_SIGNATURE), key_pair.sign(manifest.as_bytes()))
+            .unwrap();
+
+        // Initialize the current binary.
+        let current_exe = dir.join("snarkos");
+        std::fs::write(&current_exe, b"old snarkos binary").unwrap();
+        let source = ReleaseSource::from(Some(mirror.display().to_string()));
+
+        // Ensure a release that fails the verification is not installed.
+        let other_pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let other_key = hex::encode(Ed25519KeyPair::from_pkcs8(other_pkcs8.as_ref()).unwrap().public_key().as_ref());
+        assert!(Updater::update_binary(false, None, &source, Some(&other_key), &current_exe, &previous_dir).is_err());
+        assert_eq!(std::fs::read(&current_exe).unwrap(), b"old snarkos binary");
+        assert!(!previous_dir.exists());
+
+        // Ensure a verified release replaces the binary, and the current binary is kept.
+        let status = Updater::update_binary(false, None, &source, Some(&public_key), &current_exe, &previous_dir);
+        assert_eq!(status.unwrap().version(), "99.0.0");
+        assert_eq!(std::fs::read(&current_exe).unwrap(), binary);
+        let previous_binary = previous_dir.join(format!("snarkos-{}", env!("CARGO_PKG_VERSION")));
+        assert_eq!(std::fs::read(previous_binary).unwrap(), b"old snarkos binary");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_mirror_release() {
+        let dir = std::env::temp_dir().join(format!("snarkos-mirror-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let index = r#"[{"version": "2.2.7", "assets": ["snarkos-x86_64-unknown-linux-gnu", "SHA256SUMS"]}, {"version": "2.2.6", "assets": []}]"#;
+        std::fs::write(dir.join(RELEASE_INDEX), index).unwrap();
+
+        let source = ReleaseSource::from(Some(format!("{}/", dir.display())));
+        assert_eq!(Updater::available_releases(&source).unwrap(), ["2.2.7", "2.2.6"]);
+
+        // Ensure the latest release is the first one, and the assets are in its subdirectory.
+        let release = Updater::release(&source, None).unwrap();
+        assert_eq!(release.version, "2.2.7");
+        let (name, location) = release.binary_for("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(name, "snarkos-x86_64-unknown-linux-gnu");
+        assert_eq!(location, format!("{}/2.2.7/snarkos-x86_64-unknown-linux-gnu", dir.display()));
+        assert!(release.asset(RELEASE_MANIFEST_SIGNATURE).is_err());
+
+        // Ensure a release is found by its version.
+        assert_eq!(Updater::release(&source, Some("v2.2.6")).unwrap().version, "2.2.6");
+        assert!(Updater::release(&source, Some("2.2.5")).is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -17,46 +17,11 @@ if [[ $build_binary == "y" ]]; then
   cargo install --path . || exit 1
 fi
 
-# Clear the ledger logs for each validator if the user chooses to clear logs
+# Clear the ledgers and logs of the validators if the user chooses to clear logs
+clean_flag=""
 if [[ $clear_logs == "y" ]]; then
-  # Create an array to store background processes
-  clean_processes=()
-
-  for ((validator_index = 0; validator_index < total_validators; validator_index++)); do
-    # Run 'snarkos clean' for each validator in the background
-    snarkos clean --dev $validator_index &
-
-    # Store the process ID of the background task
-    clean_processes+=($!)
-  done
-
-  # Wait for all 'snarkos clean' processes to finish
-  for process_id in "${clean_processes[@]}"; do
-    wait "$process_id"
-  done
+  clean_flag="--clean"
 fi
 
-# Create a timestamp-based directory for log files
-log_dir=".logs-$(date +"%Y%m%d%H%M%S")"
-mkdir -p "$log_dir"
-
-# Create a new tmux session named "devnet"
-tmux new-session -d -s "devnet" -n "window0"
-
-# Generate validator indices from 0 to (total_validators - 1)
-validator_indices=($(seq 0 $((total_validators - 1))))
-
-# Loop through the list of validator indices and create a new window for each
-for validator_index in "${validator_indices[@]}"; do
-  # Generate a unique and incrementing log file name based on the validator index
-  log_file="$log_dir/validator-$validator_index.log"
-
-  # Create a new window with a unique name
-  tmux new-window -t "devnet:$validator_index" -n "window$validator_index"
-
-  # Send the command to start the validator to the new window and capture output to the log file
-  tmux send-keys -t "devnet:window$validator_index" "snarkos start --nodisplay --dev $validator_index --dev-num-validators $total_validators --validator --logfile $log_file" C-m
-done
-
-# Attach to the tmux session to view and interact with the windows
-tmux attach-session -t "devnet"
+# Run the devnet until Ctrl-C is pressed, with the logs in '.logs-devnet'
+snarkos devnet run --validators $total_validators $clean_flag
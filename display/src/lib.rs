@@ -52,13 +52,24 @@ pub struct Display<N: Network> {
     tick_rate: Duration,
     /// The state of the tabs.
     tabs: Tabs,
+    /// The overview tab.
+    overview: Overview,
+    /// The peers tab.
+    peers: Peers,
     /// The logs tab.
     logs: Logs,
 }
 
 impl<N: Network> Display<N> {
     /// Initializes a new display.
-    pub fn start(node: Node<N>, log_receiver: Receiver<Vec<u8>>) -> Result<()> {
+    ///
+    /// The given function changes the verbosity level of the node's logs, which starts at the given level.
+    pub fn start(
+        node: Node<N>,
+        log_receiver: Receiver<Vec<u8>>,
+        verbosity: u8,
+        set_verbosity: impl Fn(u8) -> Result<()> + 'static,
+    ) -> Result<()> {
         // Initialize the display.
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -71,7 +82,9 @@ impl<N: Network> Display<N> {
             node,
             tick_rate: Duration::from_secs(1),
             tabs: Tabs::new(PAGES.to_vec()),
-            logs: Logs::new(log_receiver),
+            overview: Overview::new(),
+            peers: Peers::new(),
+            logs: Logs::new(log_receiver, verbosity, Box::new(set_verbosity)),
         };
 
         // Render the display.
@@ -103,6 +116,11 @@ impl<N: Network> Display<N> {
 
             if event::poll(timeout)? {
                 if let Event::Key(key) = event::read()? {
+                    // While a field is being edited on the logs page, it handles all the keys.
+                    if self.tabs.index == 3 && self.logs.is_editing() {
+                        self.logs.on_key(key.code);
+                        continue;
+                    }
                     match key.code {
                         KeyCode::Esc => {
                             // // TODO (howardwu): @ljedrz to implement a wrapping scope for Display within Node/Server.
@@ -114,7 +132,12 @@ impl<N: Network> Display<N> {
                         }
                         KeyCode::Left => self.tabs.previous(),
                         KeyCode::Right => self.tabs.next(),
-                        _ => {}
+                        // The remaining keys are handled by the page that is shown.
+                        code => match self.tabs.index {
+                            1 => self.peers.on_key(code),
+                            3 => self.logs.on_key(code),
+                            _ => {}
+                        },
                     }
                 }
             }
@@ -170,8 +193,10 @@ impl<N: Network> Display<N> {
 
         // Initialize the page.
         match self.tabs.index {
-            0 => Overview.draw(f, chunks[1], &self.node),
-            1 => self.logs.draw(f, chunks[1]),
+            0 => self.overview.draw(f, chunks[1], &self.node),
+            1 => self.peers.draw(f, chunks[1], &self.node),
+            2 => Consensus.draw(f, chunks[1], &self.node),
+            3 => self.logs.draw(f, chunks[1]),
             _ => unreachable!(),
         };
     }
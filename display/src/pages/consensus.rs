@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkos_node::{bft::BFT, Node};
+use snarkvm::prelude::{Address, Network};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+/// The number of recent rounds for which the certificates are shown.
+const NUM_RECENT_ROUNDS: u64 = 10;
+
+pub(crate) struct Consensus;
+
+impl Consensus {
+    pub(crate) fn draw<B: Backend, N: Network>(&self, f: &mut Frame<B>, area: Rect, node: &Node<N>) {
+        // The memory pool and the BFT are only maintained by validators.
+        let validator = match node {
+            Node::Validator(validator) => validator,
+            _ => {
+                let text = format!(
+                    "The memory pool and consensus are only available on a validator, not a {}",
+                    node.node_type()
+                );
+                let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Consensus"));
+                f.render_widget(paragraph, area);
+                return;
+            }
+        };
+        let consensus = validator.consensus();
+        let bft = consensus.bft();
+        let num_workers = bft.primary().workers().len() as u16;
+
+        // Initialize the layout of the page.
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [Constraint::Length(5), Constraint::Length(num_workers + 3), Constraint::Length(6), Constraint::Min(0)]
+                    .as_ref(),
+            )
+            .split(area);
+
+        /* Memory pool */
+
+        let text = vec![
+            Spans::from(vec![label("Transmissions"), Span::raw(consensus.num_unconfirmed_transmissions().to_string())]),
+            Spans::from(vec![label("Solutions"), Span::raw(consensus.num_unconfirmed_solutions().to_string())]),
+            Spans::from(vec![label("Transactions"), Span::raw(consensus.num_unconfirmed_transactions().to_string())]),
+        ];
+        let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Memory pool"));
+        f.render_widget(paragraph, chunks[0]);
+
+        /* Workers */
+
+        let header = Row::new(vec!["Worker", "Ready", "Solutions", "Transactions"])
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+        let rows = bft.primary().workers().iter().map(|worker| {
+            Row::new(vec![
+                Cell::from(worker.id().to_string()),
+                Cell::from(worker.num_transmissions().to_string()),
+                Cell::from(worker.num_solutions().to_string()),
+                Cell::from(worker.num_transactions().to_string()),
+            ])
+        });
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title("Workers"))
+            .widths(&[Constraint::Length(8), Constraint::Length(8), Constraint::Length(10), Constraint::Length(14)]);
+        f.render_widget(table, chunks[1]);
+
+        /* Rounds */
+
+        let committee = bft.primary().ledger().current_committee().ok();
+        let proposal = match bft.primary().proposed_batch().read().as_ref() {
+            Some(proposal) => {
+                let quorum = match &committee {
+                    Some(committee) if proposal.is_quorum_threshold_reached(committee) => " (quorum reached)",
+                    _ => "",
+                };
+                let num_members = committee.as_ref().map(|committee| committee.num_members().to_string());
+                let signers = proposal.signers().iter().map(short_address).collect::<Vec<_>>().join(", ");
+                format!(
+                    "round {} with {} transmissions, signed by {}/{}{quorum}: {signers}",
+                    proposal.round(),
+                    proposal.transmissions().len(),
+                    proposal.signers().len(),
+                    num_members.as_deref().unwrap_or("?"),
+                )
+            }
+            None => "-".to_string(),
+        };
+        let leader = bft.leader().as_ref().map(short_address).unwrap_or_else(|| "-".to_string());
+        let text = vec![
+            Spans::from(vec![label("Current round"), Span::raw(bft.primary().current_round().to_string())]),
+            Spans::from(vec![label("Committed"), Span::raw(bft.last_committed_round().to_string())]),
+            Spans::from(vec![label("Leader"), Span::raw(leader)]),
+            Spans::from(vec![label("Proposal"), Span::raw(proposal)]),
+        ];
+        let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Rounds"));
+        f.render_widget(paragraph, chunks[2]);
+
+        /* Certificates */
+
+        let header = Row::new(vec!["Round", "Certificates", "Authors"])
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+        let table = Table::new(Self::certificate_rows(bft))
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title("Recent certificates"))
+            .widths(&[Constraint::Length(10), Constraint::Length(14), Constraint::Min(0)]);
+        f.render_widget(table, chunks[3]);
+    }
+
+    /// Returns the rows of certificates of the most recent rounds, starting with the current round.
+    fn certificate_rows<N: Network>(bft: &BFT<N>) -> Vec<Row<'static>> {
+        let storage = bft.storage();
+        let current_round = storage.current_round();
+        let oldest_round = current_round.saturating_sub(NUM_RECENT_ROUNDS - 1).max(storage.gc_round());
+        (oldest_round..=current_round)
+            .rev()
+            .map(|round| {
+                let certificates = storage.get_certificates_for_round(round);
+                let authors = certificates.iter().map(|certificate| short_address(&certificate.author()));
+                Row::new(vec![
+                    Cell::from(round.to_string()),
+                    Cell::from(certificates.len().to_string()),
+                    Cell::from(authors.collect::<Vec<_>>().join(", ")),
+                ])
+            })
+            .collect()
+    }
+}
+
+/// Returns the styled label of a field.
+fn label(name: &str) -> Span<'static> {
+    Span::styled(format!("{name:<15}"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+}
+
+/// Returns the abbreviated form of an address, which is enough to tell the committee members apart.
+fn short_address<N: Network>(address: &Address<N>) -> String {
+    let address = address.to_string();
+    format!("{}..{}", &address[..9], &address[address.len() - 4..])
+}
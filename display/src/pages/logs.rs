@@ -12,63 +12,424 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::Result;
+use crossterm::event::KeyCode;
 use std::collections::VecDeque;
 use tokio::sync::mpsc;
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
+/// The levels of the logs, from the most to the least severe.
+const LEVELS: [&str; 5] = ["ERROR", "WARN", "INFO", "DEBUG", "TRACE"];
+
+/// A line of the logs.
+struct LogLine {
+    /// The index of the level in `LEVELS`, if the line has one.
+    level: Option<usize>,
+    /// The target module of the log, if the line has one.
+    target: Option<String>,
+    /// The text of the line.
+    text: String,
+}
+
+impl LogLine {
+    /// Parses the lines of a log, which is formatted as `{timestamp} {level} {spans}: {target}: {message}`.
+    /// The lines after the first one are continuations of the message, and share its level and target.
+    fn parse(log: &str) -> Vec<Self> {
+        let mut lines = log.lines();
+        let Some(first) = lines.next() else {
+            return vec![];
+        };
+
+        let mut tokens = first.split_whitespace();
+        let level = tokens.by_ref().take(2).find_map(|token| LEVELS.iter().position(|level| *level == token));
+        // The target is the first token ending with a colon, after the spans of the log.
+        let target = match level {
+            Some(_) => tokens
+                .take_while(|token| token.ends_with(':'))
+                .map(|token| token.trim_end_matches(':'))
+                .find(|token| !token.is_empty() && token.chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':'))
+                .map(|target| target.to_string()),
+            None => None,
+        };
+
+        std::iter::once(first)
+            .chain(lines)
+            .map(|text| Self { level, target: target.clone(), text: text.to_string() })
+            .collect()
+    }
+}
+
+/// The field that is being edited on the page.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Input {
+    Search,
+    Target,
+}
+
 pub(crate) struct Logs {
     log_receiver: mpsc::Receiver<Vec<u8>>,
-    log_cache: VecDeque<String>,
+    log_cache: VecDeque<LogLine>,
     log_limit: usize,
+    /// Whether the logs of each level in `LEVELS` are shown.
+    levels: [bool; 5],
+    /// The prefix of the target modules of the logs that are shown.
+    target: String,
+    /// The text that is searched for in the logs.
+    search: String,
+    /// The field that is being edited, if any.
+    input: Option<Input>,
+    /// Whether the logs are paused, in which case new logs do not move the view.
+    paused: bool,
+    /// The number of lines scrolled back from the latest log.
+    scroll: usize,
+    /// The verbosity level of the node.
+    verbosity: u8,
+    /// The function to change the verbosity level of the node.
+    set_verbosity: Box<dyn Fn(u8) -> Result<()>>,
+    /// The result of the last change of the verbosity level, if it failed.
+    error: Option<String>,
 }
 
 impl Logs {
-    pub(crate) fn new(log_receiver: mpsc::Receiver<Vec<u8>>) -> Self {
-        let log_limit = 128; // an arbitrary number fitting the testing terminal room
+    pub(crate) fn new(
+        log_receiver: mpsc::Receiver<Vec<u8>>,
+        verbosity: u8,
+        set_verbosity: Box<dyn Fn(u8) -> Result<()>>,
+    ) -> Self {
+        let log_limit = 4096; // an arbitrary number leaving room to scroll back
+
+        Self {
+            log_receiver,
+            log_cache: VecDeque::with_capacity(log_limit),
+            log_limit,
+            levels: [true; 5],
+            target: String::new(),
+            search: String::new(),
+            input: None,
+            paused: false,
+            scroll: 0,
+            verbosity,
+            set_verbosity,
+            error: None,
+        }
+    }
+
+    /// Returns `true` if a field is being edited, in which case the page handles all the keys.
+    pub(crate) fn is_editing(&self) -> bool {
+        self.input.is_some()
+    }
 
-        Self { log_receiver, log_cache: VecDeque::with_capacity(log_limit), log_limit }
+    /// Handles a key press while the page is shown.
+    pub(crate) fn on_key(&mut self, key: KeyCode) {
+        // Edit the search text or the target filter.
+        if let Some(input) = self.input {
+            let field = match input {
+                Input::Search => &mut self.search,
+                Input::Target => &mut self.target,
+            };
+            match key {
+                KeyCode::Char(c) => field.push(c),
+                KeyCode::Backspace => {
+                    field.pop();
+                }
+                KeyCode::Esc => {
+                    field.clear();
+                    self.input = None;
+                }
+                KeyCode::Enter => self.input = None,
+                _ => {}
+            }
+            self.scroll = 0;
+            return;
+        }
+
+        match key {
+            KeyCode::Char('/') => self.input = Some(Input::Search),
+            KeyCode::Char('f') => self.input = Some(Input::Target),
+            KeyCode::Char('c') => {
+                self.levels = [true; 5];
+                self.target.clear();
+                self.search.clear();
+            }
+            KeyCode::Char(c @ ('e' | 'w' | 'i' | 'd' | 't')) => {
+                if let Some(index) = LEVELS.iter().position(|level| level.starts_with(c.to_ascii_uppercase())) {
+                    self.levels[index] = !self.levels[index];
+                }
+            }
+            KeyCode::Char('p') | KeyCode::Char(' ') => {
+                self.paused = !self.paused;
+                if !self.paused {
+                    self.scroll = 0;
+                }
+            }
+            // Scrolling back pauses the logs, so the view stays in place.
+            KeyCode::Up => self.scroll_back(1),
+            KeyCode::PageUp => self.scroll_back(20),
+            KeyCode::Home => self.scroll_back(self.log_limit),
+            KeyCode::Down => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_sub(20),
+            KeyCode::End => self.scroll = 0,
+            KeyCode::Char('+') => self.change_verbosity(self.verbosity.saturating_add(1)),
+            KeyCode::Char('-') => self.change_verbosity(self.verbosity.saturating_sub(1)),
+            _ => {}
+        }
+    }
+
+    /// Scrolls back by the given number of lines, and pauses the logs.
+    fn scroll_back(&mut self, lines: usize) {
+        self.paused = true;
+        self.scroll = self.scroll.saturating_add(lines);
+    }
+
+    /// Changes the verbosity level of the node. The levels above the highest one are rejected by the logger.
+    fn change_verbosity(&mut self, verbosity: u8) {
+        match (self.set_verbosity)(verbosity) {
+            Ok(()) => {
+                self.verbosity = verbosity;
+                self.error = None;
+            }
+            Err(error) => self.error = Some(format!("Failed to change the verbosity - {error}")),
+        }
+    }
+
+    /// Returns `true` if the line passes the level, target, and search filters.
+    fn is_shown(&self, line: &LogLine) -> bool {
+        // Lines without a level are always shown, as they cannot be told apart.
+        let level = line.level.map_or(true, |level| self.levels[level]);
+        let target = self.target.is_empty() || line.target.as_ref().map_or(false, |t| t.starts_with(&self.target));
+        let search =
+            self.search.is_empty() || line.text.to_ascii_lowercase().contains(&self.search.to_ascii_lowercase());
+        level && target && search
+    }
+
+    /// Receives the new logs, and drops the oldest ones above the limit.
+    fn receive(&mut self) {
+        while let Ok(log) = self.log_receiver.try_recv() {
+            let lines = LogLine::parse(&String::from_utf8_lossy(&log));
+            // Keep the view in place while the logs are paused.
+            if self.paused {
+                let num_shown = lines.iter().filter(|line| self.is_shown(line)).count();
+                self.scroll += num_shown;
+            }
+            self.log_cache.extend(lines);
+        }
+        while self.log_cache.len() > self.log_limit {
+            self.log_cache.pop_front();
+        }
     }
 
     pub(crate) fn draw<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
         // Initialize the layout of the page.
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(100)].as_ref())
+            .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
             .split(area);
 
-        let mut new_logs = Vec::new();
-        while let Ok(log) = self.log_receiver.try_recv() {
-            new_logs.push(match String::from_utf8(log) {
-                Ok(log) => log,
-                _ => String::new(),
-            });
+        /* Logs */
+
+        self.receive();
+
+        // Select the lines in view, scrolling back from the latest one.
+        let lines = self.log_cache.iter().filter(|line| self.is_shown(line)).collect::<Vec<_>>();
+        let height = chunks[0].height.saturating_sub(2) as usize;
+        let scroll = self.scroll.min(lines.len().saturating_sub(height));
+        let end = lines.len() - scroll;
+        let start = end.saturating_sub(height);
+        let text = lines[start..end].iter().map(|line| self.styled_line(line)).collect::<Vec<_>>();
+        self.scroll = scroll;
+
+        let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(self.title()));
+        f.render_widget(paragraph, chunks[0]);
+
+        /* Help */
+
+        let help = match (self.input, &self.error) {
+            (Some(Input::Search), _) => format!("Search: {}_  (Enter to apply, Esc to clear)", self.search),
+            (Some(Input::Target), _) => format!("Target: {}_  (Enter to apply, Esc to clear)", self.target),
+            (None, Some(error)) => error.clone(),
+            (None, None) => "e/w/i/d/t toggle levels, f filter target, / search, c clear filters, p pause, \
+                            Up/Down/PgUp/PgDn scroll, +/- verbosity"
+                .to_string(),
+        };
+        let help = Paragraph::new(Span::styled(help, Style::default().fg(Color::White)))
+            .block(Block::default().borders(Borders::ALL).title("Help"));
+        f.render_widget(help, chunks[1]);
+    }
+
+    /// Returns the title of the logs, describing the filters in effect.
+    fn title(&self) -> String {
+        let levels = LEVELS
+            .iter()
+            .zip(self.levels)
+            .map(|(level, shown)| match shown {
+                true => level.to_string(),
+                false => level.to_lowercase(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut title = format!("Logs [verbosity {}] [{levels}]", self.verbosity);
+        if !self.target.is_empty() {
+            title += &format!(" [target {}]", self.target);
+        }
+        if !self.search.is_empty() {
+            title += &format!(" [search \"{}\"]", self.search);
         }
+        if self.paused {
+            title += &format!(" [paused, {} lines back]", self.scroll);
+        }
+        title
+    }
 
-        let all_logs = self.log_cache.len() + new_logs.len();
-        if all_logs > self.log_limit {
-            let remaining_room = self.log_limit - self.log_cache.len();
-            let overflow = all_logs - self.log_cache.len();
-
-            if overflow > self.log_limit {
-                self.log_cache.clear();
-            } else {
-                let missing_room = all_logs - remaining_room;
-                for _ in 0..missing_room {
-                    self.log_cache.pop_front();
-                }
-            }
+    /// Returns the line styled by its level, with the matches of the search highlighted.
+    fn styled_line(&self, line: &LogLine) -> Spans<'static> {
+        let style = match line.level {
+            Some(0) => Style::default().fg(Color::Red),
+            Some(1) => Style::default().fg(Color::Yellow),
+            _ => Style::default(),
         };
+        if self.search.is_empty() {
+            return Spans::from(Span::styled(line.text.clone(), style));
+        }
+
+        // The lowercase text has the same byte offsets as the text, as only ASCII characters are changed.
+        let lowercase = line.text.to_ascii_lowercase();
+        let search = self.search.to_ascii_lowercase();
+        let highlight = style.bg(Color::Yellow).fg(Color::Black).add_modifier(Modifier::BOLD);
+        let mut spans = Vec::new();
+        let mut position = 0;
+        for (index, _) in lowercase.match_indices(&search) {
+            spans.push(Span::styled(line.text[position..index].to_string(), style));
+            spans.push(Span::styled(line.text[index..index + search.len()].to_string(), highlight));
+            position = index + search.len();
+        }
+        spans.push(Span::styled(line.text[position..].to_string(), style));
+        Spans::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the logs page, without any logs received.
+    fn sample_logs() -> Logs {
+        let (_, log_receiver) = mpsc::channel(1);
+        Logs::new(log_receiver, 1, Box::new(|_| Ok(())))
+    }
+
+    /// Returns the first line of the given log.
+    fn sample_line(log: &str) -> LogLine {
+        LogLine::parse(log).remove(0)
+    }
+
+    /// Returns the contents of the spans, and whether each one is highlighted.
+    fn contents(spans: &Spans) -> Vec<(String, bool)> {
+        spans.0.iter().map(|span| (span.content.to_string(), span.style.bg == Some(Color::Yellow))).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        // Ensure the level and target are parsed.
+        let log = "2023-10-18T16:21:24.123456Z  INFO snarkos_node_router::heartbeat: Connected to 3 peers";
+        let line = sample_line(log);
+        assert_eq!(line.level, Some(2));
+        assert_eq!(line.target.as_deref(), Some("snarkos_node_router::heartbeat"));
+        assert_eq!(line.text, log);
+
+        // Ensure the spans are skipped before the target.
+        let line = sample_line(
+            "2023-10-18T16:21:24.123456Z  WARN tcp{name=\"0.0.0.0:4130\"}:router: snarkos_node_tcp::tcp: Failed to connect",
+        );
+        assert_eq!(line.level, Some(1));
+        assert_eq!(line.target.as_deref(), Some("snarkos_node_tcp::tcp"));
+
+        // Ensure a line without a target has none.
+        let line = sample_line("2023-10-18T16:21:24.123456Z ERROR Failed to start the node");
+        assert_eq!(line.level, Some(0));
+        assert_eq!(line.target, None);
+
+        // Ensure a line without a level has neither a level nor a target.
+        let line = sample_line("Welcome: to Aleo");
+        assert_eq!(line.level, None);
+        assert_eq!(line.target, None);
+
+        assert!(LogLine::parse("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_multiline() {
+        // Ensure the continuation lines share the level and target of the first line.
+        let lines = LogLine::parse(
+            "2023-10-18T16:21:24.123456Z DEBUG snarkos_node::validator: Status:\n  height: 5\n  peers: 3",
+        );
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            assert_eq!(line.level, Some(3));
+            assert_eq!(line.target.as_deref(), Some("snarkos_node::validator"));
+        }
+        assert_eq!(lines[1].text, "  height: 5");
+        assert_eq!(lines[2].text, "  peers: 3");
+    }
+
+    #[test]
+    fn test_is_shown() {
+        let mut logs = sample_logs();
+        let router =
+            sample_line("2023-10-18T16:21:24.123456Z  INFO snarkos_node_router::heartbeat: Connected to 3 peers");
+        let tcp = sample_line("2023-10-18T16:21:24.123456Z  WARN snarkos_node_tcp::tcp: Failed to connect");
+        let untargeted = sample_line("2023-10-18T16:21:24.123456Z  INFO Starting the node");
+        let unleveled = sample_line("Welcome to Aleo");
+        assert!([&router, &tcp, &untargeted, &unleveled].iter().all(|line| logs.is_shown(line)));
+
+        // Ensure the target filter matches the prefix of the target, and hides the lines without a target.
+        logs.target = "snarkos_node_router".to_string();
+        assert!(logs.is_shown(&router));
+        assert!(!logs.is_shown(&tcp));
+        assert!(!logs.is_shown(&untargeted));
+        assert!(!logs.is_shown(&unleveled));
+        logs.target.clear();
+
+        // Ensure the level filter hides the lines of the level, but not the lines without a level.
+        logs.levels[2] = false;
+        assert!(!logs.is_shown(&router));
+        assert!(logs.is_shown(&tcp));
+        assert!(logs.is_shown(&unleveled));
+        logs.levels[2] = true;
+
+        // Ensure the search is case-insensitive.
+        logs.search = "FAILED".to_string();
+        assert!(logs.is_shown(&tcp));
+        assert!(!logs.is_shown(&router));
+    }
 
-        self.log_cache.extend(new_logs.into_iter().take(self.log_limit));
+    #[test]
+    fn test_styled_line() {
+        let mut logs = sample_logs();
+        let line = sample_line("2023-10-18T16:21:24.123456Z ERROR snarkos: Peers and peer");
 
-        let combined_logs = self.log_cache.iter().map(|s| s.as_str()).collect::<String>();
+        // Ensure a line is a single span styled by its level, without a search.
+        let spans = logs.styled_line(&line);
+        assert_eq!(contents(&spans), vec![(line.text.clone(), false)]);
+        assert_eq!(spans.0[0].style.fg, Some(Color::Red));
 
-        let combined_logs = Paragraph::new(combined_logs).block(Block::default().borders(Borders::ALL).title("Logs"));
-        f.render_widget(combined_logs, chunks[0]);
+        // Ensure the matches of the search are highlighted at their offsets, regardless of case.
+        logs.search = "peer".to_string();
+        let spans = logs.styled_line(&line);
+        assert_eq!(contents(&spans), vec![
+            ("2023-10-18T16:21:24.123456Z ERROR snarkos: ".to_string(), false),
+            ("Peer".to_string(), true),
+            ("s and ".to_string(), false),
+            ("peer".to_string(), true),
+            ("".to_string(), false),
+        ]);
+        // Ensure the spans cover the text.
+        assert_eq!(spans.0.iter().map(|span| span.content.as_ref()).collect::<String>(), line.text);
     }
 }
@@ -15,38 +15,130 @@
 use snarkos_node::Node;
 use snarkvm::prelude::Network;
 
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::Span,
-    widgets::{canvas::Canvas, Block, Borders},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{canvas::Canvas, Block, Borders, Paragraph},
     Frame,
 };
 
-pub(crate) struct Overview;
+/// The window of time over which the block rate is measured.
+const BLOCK_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+pub(crate) struct Overview {
+    /// The recently observed block heights, with the time at which they were observed.
+    heights: VecDeque<(Instant, u32)>,
+}
 
 impl Overview {
-    pub(crate) fn draw<B: Backend, N: Network>(&self, f: &mut Frame<B>, area: Rect, _node: &Node<N>) {
+    pub(crate) fn new() -> Self {
+        Self { heights: VecDeque::new() }
+    }
+
+    pub(crate) fn draw<B: Backend, N: Network>(&mut self, f: &mut Frame<B>, area: Rect, node: &Node<N>) {
         // Initialize the layout of the page.
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(10), Constraint::Percentage(70), Constraint::Max(2)].as_ref())
+            .constraints(
+                [Constraint::Length(6), Constraint::Length(7), Constraint::Length(5), Constraint::Min(2)].as_ref(),
+            )
             .split(area);
 
-        let canvas = Canvas::default().block(Block::default().borders(Borders::ALL).title("Block")).paint(|_ctx| {
-            // ctx.draw(&ball);
-        });
-        f.render_widget(canvas, chunks[0]);
+        /* Block */
 
-        let canvas = Canvas::default().block(Block::default().borders(Borders::ALL).title("Peers")).paint(|_ctx| {
-            // ctx.draw(&ball);
-        });
-        f.render_widget(canvas, chunks[1]);
+        let height = node.latest_block_height();
+        self.record_height(Instant::now(), height);
+        let hash = node.latest_block_hash().map(|hash| hash.to_string()).unwrap_or_else(|| "-".to_string());
+        let sync_status = match node.is_block_synced() {
+            true => Span::styled("synced", Style::default().fg(Color::Green)),
+            false => Span::styled("syncing", Style::default().fg(Color::Yellow)),
+        };
+        let block_rate = match self.block_rate() {
+            Some(rate) => format!("{rate:.2} blocks/min"),
+            None => "-".to_string(),
+        };
+        let text = vec![
+            Spans::from(vec![label("Height"), Span::raw(height.to_string())]),
+            Spans::from(vec![label("Hash"), Span::raw(hash)]),
+            Spans::from(vec![label("Status"), sync_status]),
+            Spans::from(vec![label("Block rate"), Span::raw(block_rate)]),
+        ];
+        let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Block"));
+        f.render_widget(paragraph, chunks[0]);
+
+        /* Peers */
+
+        let router = node.router();
+        let text = vec![
+            Spans::from(vec![label("Total"), Span::raw(router.number_of_connected_peers().to_string())]),
+            Spans::from(vec![label("Validators"), Span::raw(router.number_of_connected_validators().to_string())]),
+            Spans::from(vec![label("Provers"), Span::raw(router.number_of_connected_provers().to_string())]),
+            Spans::from(vec![label("Clients"), Span::raw(router.number_of_connected_clients().to_string())]),
+            Spans::from(vec![label("Candidates"), Span::raw(router.number_of_candidate_peers().to_string())]),
+        ];
+        let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Peers"));
+        f.render_widget(paragraph, chunks[1]);
+
+        /* Consensus */
+
+        let text = match node {
+            Node::Validator(validator) => {
+                let bft = validator.consensus().bft();
+                let leader = bft.leader().map(|leader| leader.to_string()).unwrap_or_else(|| "-".to_string());
+                vec![
+                    Spans::from(vec![label("Round"), Span::raw(bft.primary().current_round().to_string())]),
+                    Spans::from(vec![label("Leader"), Span::raw(leader)]),
+                ]
+            }
+            _ => vec![Spans::from(Span::raw(format!("Not available on a {} node", node.node_type())))],
+        };
+        let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Consensus"));
+        f.render_widget(paragraph, chunks[2]);
+
+        /* Help */
 
         let canvas = Canvas::default().block(Block::default().borders(Borders::ALL).title("Help")).paint(|ctx| {
             ctx.print(0f64, 0f64, Span::styled("Press ESC to quit", Style::default().fg(Color::White)));
         });
-        f.render_widget(canvas, chunks[2]);
+        f.render_widget(canvas, chunks[3]);
+    }
+
+    /// Records the block height observed at the given time, and drops the observations outside the window.
+    fn record_height(&mut self, now: Instant, height: u32) {
+        // A height is recorded at most once per second, as the page may be redrawn more often.
+        if let Some((last, _)) = self.heights.back() {
+            if now.duration_since(*last) < Duration::from_secs(1) {
+                return;
+            }
+        }
+        self.heights.push_back((now, height));
+        while let Some((first, _)) = self.heights.front() {
+            match now.duration_since(*first) > BLOCK_RATE_WINDOW {
+                true => self.heights.pop_front(),
+                false => break,
+            };
+        }
     }
+
+    /// Returns the number of blocks per minute over the window, if enough heights have been observed.
+    fn block_rate(&self) -> Option<f64> {
+        let (first_time, first_height) = self.heights.front()?;
+        let (last_time, last_height) = self.heights.back()?;
+        let elapsed = last_time.duration_since(*first_time).as_secs_f64();
+        match elapsed > 0.0 {
+            true => Some(last_height.saturating_sub(*first_height) as f64 * 60.0 / elapsed),
+            false => None,
+        }
+    }
+}
+
+/// Returns the styled label of a field.
+fn label(name: &str) -> Span<'static> {
+    Span::styled(format!("{name:<12}"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
 }
@@ -0,0 +1,268 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkos_node::{router::messages::NodeType, tcp::P2P, Node};
+use snarkvm::prelude::Network;
+
+use crossterm::event::KeyCode;
+use std::{
+    cmp::Ordering,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+/// The columns by which the peers may be sorted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SortBy {
+    Ip,
+    NodeType,
+    Version,
+    FirstSeen,
+    LastSeen,
+    Sent,
+    Received,
+}
+
+impl SortBy {
+    /// The sortable columns, in the order in which they are cycled through.
+    const ALL: [SortBy; 7] = [
+        SortBy::Ip,
+        SortBy::NodeType,
+        SortBy::Version,
+        SortBy::FirstSeen,
+        SortBy::LastSeen,
+        SortBy::Sent,
+        SortBy::Received,
+    ];
+
+    /// Returns the next column to sort by.
+    fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|column| *column == self).unwrap_or_default();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Returns the name of the column.
+    fn name(self) -> &'static str {
+        match self {
+            SortBy::Ip => "IP",
+            SortBy::NodeType => "Type",
+            SortBy::Version => "Version",
+            SortBy::FirstSeen => "First seen",
+            SortBy::LastSeen => "Last seen",
+            SortBy::Sent => "Sent",
+            SortBy::Received => "Received",
+        }
+    }
+}
+
+/// The state of a connected peer, as shown in a row of the table.
+struct PeerRow {
+    ip: SocketAddr,
+    node_type: NodeType,
+    version: u32,
+    address: String,
+    first_seen: Duration,
+    last_seen: Duration,
+    /// The number of messages and bytes sent to the peer.
+    sent: (u64, u64),
+    /// The number of messages and bytes received from the peer.
+    received: (u64, u64),
+}
+
+impl PeerRow {
+    /// Compares two rows by the given column.
+    fn compare(&self, other: &Self, sort_by: SortBy) -> Ordering {
+        match sort_by {
+            SortBy::Ip => self.ip.cmp(&other.ip),
+            SortBy::NodeType => (self.node_type as u8).cmp(&(other.node_type as u8)),
+            SortBy::Version => self.version.cmp(&other.version),
+            // The durations are the time elapsed since, so in ascending order the most recent comes first.
+            SortBy::FirstSeen => self.first_seen.cmp(&other.first_seen),
+            SortBy::LastSeen => self.last_seen.cmp(&other.last_seen),
+            SortBy::Sent => self.sent.1.cmp(&other.sent.1),
+            SortBy::Received => self.received.1.cmp(&other.received.1),
+        }
+        // Ties are broken by IP, so the order of the rows is stable between refreshes.
+        .then_with(|| self.ip.cmp(&other.ip))
+    }
+}
+
+pub(crate) struct Peers {
+    /// The column by which the peers are sorted.
+    sort_by: SortBy,
+    /// Whether the peers are sorted in descending order.
+    descending: bool,
+    /// The state of the table, including the selected row.
+    state: TableState,
+}
+
+impl Peers {
+    pub(crate) fn new() -> Self {
+        Self { sort_by: SortBy::Ip, descending: false, state: TableState::default() }
+    }
+
+    /// Handles a key press while the page is shown.
+    pub(crate) fn on_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Up => {
+                let selected = self.state.selected().map(|index| index.saturating_sub(1)).unwrap_or_default();
+                self.state.select(Some(selected));
+            }
+            // The selection is clamped to the number of peers when the page is drawn.
+            KeyCode::Down => self.state.select(Some(self.state.selected().map(|index| index + 1).unwrap_or_default())),
+            KeyCode::Home => self.state.select(Some(0)),
+            KeyCode::End => self.state.select(Some(usize::MAX)),
+            KeyCode::Char('s') => self.sort_by = self.sort_by.next(),
+            KeyCode::Char('r') => self.descending = !self.descending,
+            _ => {}
+        }
+    }
+
+    pub(crate) fn draw<B: Backend, N: Network>(&mut self, f: &mut Frame<B>, area: Rect, node: &Node<N>) {
+        // Initialize the layout of the page.
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+            .split(area);
+
+        /* Peers */
+
+        let mut rows = Self::peer_rows(node);
+        rows.sort_by(|a, b| match self.descending {
+            true => b.compare(a, self.sort_by),
+            false => a.compare(b, self.sort_by),
+        });
+
+        // Clamp the selection to the rows.
+        match rows.len() {
+            0 => self.state.select(None),
+            len => self.state.select(self.state.selected().map(|index| index.min(len - 1))),
+        }
+
+        let header = SortBy::ALL[..3]
+            .iter()
+            .map(|column| self.header_cell(*column))
+            .chain([Cell::from("Address")])
+            .chain(SortBy::ALL[3..].iter().map(|column| self.header_cell(*column)))
+            .collect::<Vec<_>>();
+        let header = Row::new(header).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+
+        let table_rows = rows.iter().map(|row| {
+            Row::new(vec![
+                Cell::from(row.ip.to_string()),
+                Cell::from(row.node_type.to_string()),
+                Cell::from(row.version.to_string()),
+                Cell::from(row.address.clone()),
+                Cell::from(format_elapsed(row.first_seen)),
+                Cell::from(format_elapsed(row.last_seen)),
+                Cell::from(format!("{} / {}", row.sent.0, format_bytes(row.sent.1))),
+                Cell::from(format!("{} / {}", row.received.0, format_bytes(row.received.1))),
+            ])
+        });
+
+        let title = format!("Peers ({})", rows.len());
+        let table = Table::new(table_rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .widths(&[
+                Constraint::Length(22),
+                Constraint::Length(10),
+                Constraint::Length(8),
+                Constraint::Length(64),
+                Constraint::Length(11),
+                Constraint::Length(11),
+                Constraint::Length(16),
+                Constraint::Length(16),
+            ]);
+        f.render_stateful_widget(table, chunks[0], &mut self.state);
+
+        /* Help */
+
+        let order = if self.descending { "descending" } else { "ascending" };
+        let help = format!(
+            "Up/Down to navigate, 's' to change the sort column, 'r' to reverse the order (sorted by {}, {order})",
+            self.sort_by.name()
+        );
+        let help = Paragraph::new(Span::styled(help, Style::default().fg(Color::White)))
+            .block(Block::default().borders(Borders::ALL).title("Help"));
+        f.render_widget(help, chunks[1]);
+    }
+
+    /// Returns the header cell of the given column, marking the column the peers are sorted by.
+    fn header_cell(&self, column: SortBy) -> Cell<'static> {
+        match (column == self.sort_by, self.descending) {
+            (true, true) => Cell::from(format!("{} v", column.name())),
+            (true, false) => Cell::from(format!("{} ^", column.name())),
+            (false, _) => Cell::from(column.name()),
+        }
+    }
+
+    /// Returns the rows of the connected peers, along with their connection statistics.
+    fn peer_rows<N: Network>(node: &Node<N>) -> Vec<PeerRow> {
+        let router = node.router();
+        let now = Instant::now();
+        router
+            .get_connected_peers()
+            .into_iter()
+            .map(|peer| {
+                // The statistics are tracked by the connected address, which may differ from the listener address.
+                let stats =
+                    router.resolve_to_ambiguous(&peer.ip()).and_then(|addr| router.tcp().known_peers().get(addr));
+                PeerRow {
+                    ip: peer.ip(),
+                    node_type: peer.node_type(),
+                    version: peer.version(),
+                    address: peer.address().to_string(),
+                    first_seen: now.saturating_duration_since(peer.first_seen()),
+                    last_seen: now.saturating_duration_since(peer.last_seen()),
+                    sent: stats.as_ref().map(|stats| stats.sent()).unwrap_or_default(),
+                    received: stats.as_ref().map(|stats| stats.received()).unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Formats the time elapsed since an event.
+fn format_elapsed(elapsed: Duration) -> String {
+    match elapsed.as_secs() {
+        secs @ 0..=59 => format!("{secs}s ago"),
+        secs @ 60..=3599 => format!("{}m ago", secs / 60),
+        secs => format!("{}h ago", secs / 3600),
+    }
+}
+
+/// Formats a number of bytes in a human-readable unit.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{bytes} B"),
+        _ => format!("{size:.1} {}", UNITS[unit]),
+    }
+}
@@ -23,7 +23,7 @@ use crate::{
     MAX_TRANSMISSIONS_PER_WORKER_PING,
     MEMORY_POOL_PORT,
 };
-use snarkos_account::Account;
+use snarkos_account::{SignRequest, Signer};
 use snarkos_node_bft_events::{
     BlockRequest,
     BlockResponse,
@@ -49,6 +49,8 @@ use snarkos_node_tcp::{
     Config,
     Connection,
     ConnectionSide,
+    Reputation,
+    ReputationEvent,
     Tcp,
     P2P,
 };
@@ -97,8 +99,8 @@ pub trait Transport<N: Network>: Send + Sync {
 
 #[derive(Clone)]
 pub struct Gateway<N: Network> {
-    /// The account of the node.
-    account: Account<N>,
+    /// The signer of the node.
+    signer: Arc<dyn Signer<N>>,
     /// The ledger service.
     ledger: Arc<dyn LedgerService<N>>,
     /// The TCP stack.
@@ -107,6 +109,8 @@ pub struct Gateway<N: Network> {
     cache: Arc<Cache<N>>,
     /// The resolver.
     resolver: Arc<Resolver<N>>,
+    /// The reputation of the validators, which is used to prefer the reputable validators for block requests.
+    reputation: Arc<Reputation>,
     /// The set of trusted validators.
     trusted_validators: IndexSet<SocketAddr>,
     /// The map of connected peer IPs to their peer handlers.
@@ -129,7 +133,7 @@ pub struct Gateway<N: Network> {
 impl<N: Network> Gateway<N> {
     /// Initializes a new gateway.
     pub fn new(
-        account: Account<N>,
+        signer: Arc<dyn Signer<N>>,
         ledger: Arc<dyn LedgerService<N>>,
         ip: Option<SocketAddr>,
         trusted_validators: &[SocketAddr],
@@ -145,11 +149,12 @@ impl<N: Network> Gateway<N> {
         let tcp = Tcp::new(Config::new(ip, Committee::<N>::MAX_COMMITTEE_SIZE));
         // Return the gateway.
         Ok(Self {
-            account,
+            signer,
             ledger,
             tcp,
             cache: Default::default(),
             resolver: Default::default(),
+            reputation: Default::default(),
             trusted_validators: trusted_validators.iter().copied().collect(),
             connected_peers: Default::default(),
             connecting_peers: Default::default(),
@@ -245,12 +250,22 @@ impl<N: Network> CommunicationService for Gateway<N> {
     async fn send(&self, peer_ip: SocketAddr, message: Self::Message) -> Option<oneshot::Receiver<io::Result<()>>> {
         Transport::send(self, peer_ip, message).await
     }
+
+    /// Returns the reputation score of the given validator, to prefer the reputable validators for block requests.
+    fn peer_score(&self, peer_ip: SocketAddr) -> f64 {
+        self.reputation.score(&peer_ip)
+    }
 }
 
 impl<N: Network> Gateway<N> {
-    /// Returns the account of the node.
-    pub const fn account(&self) -> &Account<N> {
-        &self.account
+    /// Returns the signer of the node.
+    pub fn signer(&self) -> &Arc<dyn Signer<N>> {
+        &self.signer
+    }
+
+    /// Returns the account address of the node.
+    pub fn address(&self) -> Address<N> {
+        self.signer.address()
     }
 
     /// Returns the IP address of this node.
@@ -593,7 +608,9 @@ impl<N: Network> Gateway<N> {
                     // Ensure the block response is well-formed.
                     blocks.ensure_response_is_well_formed(peer_ip, request.start_height, request.end_height)?;
                     // Send the blocks to the sync module.
-                    return sync_sender.advance_with_sync_blocks(peer_ip, blocks.0).await;
+                    sync_sender.advance_with_sync_blocks(peer_ip, blocks.0).await?;
+                    // Raise the reputation of the validator, as the blocks were accepted.
+                    self.reputation.update(peer_ip, ReputationEvent::ValidBlockResponse);
                 }
                 Ok(())
             }
@@ -718,7 +735,7 @@ impl<N: Network> Gateway<N> {
                                 continue;
                             }
                             // Ensure the validator address is not this node.
-                            if self_.account.address() == validator_address {
+                            if self_.address() == validator_address {
                                 continue;
                             }
                             // Ensure the validator IP is not already connected or connecting.
@@ -778,6 +795,21 @@ impl<N: Network> Gateway<N> {
         })
     }
 
+    /// Disconnects from the connected validators that are banned, either by IP address or by Aleo address.
+    pub fn disconnect_banned(&self) {
+        let ban_list = self.tcp.ban_list();
+        for peer_ip in self.connected_peers.read().iter().copied().collect::<Vec<_>>() {
+            let is_banned_address = self
+                .resolver
+                .get_address(peer_ip)
+                .map_or(false, |address| ban_list.is_banned_identity(&address.to_string()));
+            if ban_list.is_banned(peer_ip.ip()) || is_banned_address {
+                warn!("{CONTEXT} Disconnecting from '{peer_ip}' (banned)");
+                self.disconnect(peer_ip);
+            }
+        }
+    }
+
     /// Initialize a new instance of the heartbeat.
     fn initialize_heartbeat(&self) {
         let self_clone = self.clone();
@@ -991,6 +1023,8 @@ impl<N: Network> Reading for Gateway<N> {
         // Process the message. Disconnect if the peer violated the protocol.
         if let Err(error) = self.inbound(peer_addr, message).await {
             if let Some(peer_ip) = self.resolver.get_listener(peer_addr) {
+                // Lower the reputation of the validator, so it is not preferred for block requests once reconnected.
+                self.reputation.update(peer_ip, ReputationEvent::InvalidMessage);
                 warn!("{CONTEXT} Disconnecting from '{peer_ip}' - {error}");
                 let self_ = self.clone();
                 tokio::spawn(async move {
@@ -1140,7 +1174,7 @@ impl<N: Network> Gateway<N> {
         // Sample a random nonce.
         let our_nonce = rng.gen();
         // Send a challenge request to the peer.
-        let our_request = ChallengeRequest::new(self.local_ip().port(), self.account.address(), our_nonce);
+        let our_request = ChallengeRequest::new(self.local_ip().port(), self.address(), our_nonce);
         send_event(&mut framed, peer_addr, Event::ChallengeRequest(our_request)).await?;
 
         /* Step 2: Receive the peer's challenge response followed by the challenge request. */
@@ -1165,8 +1199,9 @@ impl<N: Network> Gateway<N> {
 
         /* Step 3: Send the challenge response. */
 
-        // Sign the counterparty nonce.
-        let Ok(our_signature) = self.account.sign_bytes(&peer_request.nonce.to_le_bytes(), rng) else {
+        // Sign the counterparty nonce. A remote signer blocks on its socket, so sign in a blocking task.
+        let (signer, nonce) = (self.signer.clone(), peer_request.nonce);
+        let Ok(our_signature) = spawn_blocking!(signer.sign(&SignRequest::Challenge { nonce })) else {
             return Err(error(format!("Failed to sign the challenge request nonce from '{peer_addr}'")));
         };
         // Send the challenge response.
@@ -1195,7 +1230,7 @@ impl<N: Network> Gateway<N> {
         let peer_request = expect_event!(Event::ChallengeRequest, framed, peer_addr);
 
         // Ensure the address is not the same as this node.
-        if self.account.address() == peer_request.address {
+        if self.address() == peer_request.address {
             return Err(error("Skipping request to connect to self".to_string()));
         }
 
@@ -1218,8 +1253,9 @@ impl<N: Network> Gateway<N> {
         // Initialize an RNG.
         let rng = &mut rand::rngs::OsRng;
 
-        // Sign the counterparty nonce.
-        let Ok(our_signature) = self.account.sign_bytes(&peer_request.nonce.to_le_bytes(), rng) else {
+        // Sign the counterparty nonce. A remote signer blocks on its socket, so sign in a blocking task.
+        let (signer, nonce) = (self.signer.clone(), peer_request.nonce);
+        let Ok(our_signature) = spawn_blocking!(signer.sign(&SignRequest::Challenge { nonce })) else {
             return Err(error(format!("Failed to sign the challenge request nonce from '{peer_addr}'")));
         };
         // Send the challenge response.
@@ -1229,7 +1265,7 @@ impl<N: Network> Gateway<N> {
         // Sample a random nonce.
         let our_nonce = rng.gen();
         // Send the challenge request.
-        let our_request = ChallengeRequest::new(self.local_ip().port(), self.account.address(), our_nonce);
+        let our_request = ChallengeRequest::new(self.local_ip().port(), self.address(), our_nonce);
         send_event(&mut framed, peer_addr, Event::ChallengeRequest(our_request)).await?;
 
         /* Step 3: Receive the challenge response. */
@@ -1291,6 +1327,11 @@ impl<N: Network> Gateway<N> {
             warn!("{CONTEXT} Gateway handshake with '{peer_addr}' failed (invalid signature)");
             return Some(DisconnectReason::InvalidChallengeResponse);
         }
+        // Now that the peer has proven ownership of its address, ensure the address is not banned.
+        if self.tcp.ban_list().is_banned_identity(&peer_address.to_string()) {
+            warn!("{CONTEXT} Gateway handshake with '{peer_addr}' failed (banned address '{peer_address}')");
+            return Some(DisconnectReason::NoReasonGiven);
+        }
         None
     }
 }
@@ -1333,7 +1374,7 @@ mod prop_tests {
     impl Debug for Gateway<CurrentNetwork> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
             // TODO implement Debug properly and move it over to production code
-            f.debug_tuple("Gateway").field(&self.account.address()).field(&self.tcp.config()).finish()
+            f.debug_tuple("Gateway").field(&self.address()).field(&self.tcp.config()).finish()
         }
     }
 
@@ -1367,7 +1408,7 @@ mod prop_tests {
             any_valid_dev_gateway()
                 .prop_map(|(storage, _, private_key, address)| {
                     Gateway::new(
-                        Account::try_from(private_key).unwrap(),
+                        Account::try_from(private_key).unwrap().into(),
                         storage.ledger().clone(),
                         address.ip(),
                         &[],
@@ -1416,14 +1457,15 @@ mod prop_tests {
         let (storage, _, private_key, dev) = input;
         let account = Account::try_from(private_key).unwrap();
 
-        let gateway = Gateway::new(account.clone(), storage.ledger().clone(), dev.ip(), &[], dev.port()).unwrap();
+        let gateway =
+            Gateway::new(account.clone().into(), storage.ledger().clone(), dev.ip(), &[], dev.port()).unwrap();
         let tcp_config = gateway.tcp().config();
         assert_eq!(tcp_config.listener_ip, Some(IpAddr::V4(Ipv4Addr::LOCALHOST)));
         assert_eq!(tcp_config.desired_listening_port, Some(MEMORY_POOL_PORT + dev.port().unwrap()));
 
         let tcp_config = gateway.tcp().config();
         assert_eq!(tcp_config.max_connections, Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE);
-        assert_eq!(gateway.account().address(), account.address());
+        assert_eq!(gateway.address(), account.address());
     }
 
     #[proptest]
@@ -1431,7 +1473,8 @@ mod prop_tests {
         let (storage, _, private_key, dev) = input;
         let account = Account::try_from(private_key).unwrap();
 
-        let gateway = Gateway::new(account.clone(), storage.ledger().clone(), dev.ip(), &[], dev.port()).unwrap();
+        let gateway =
+            Gateway::new(account.clone().into(), storage.ledger().clone(), dev.ip(), &[], dev.port()).unwrap();
         let tcp_config = gateway.tcp().config();
         if let Some(socket_addr) = dev.ip() {
             assert_eq!(tcp_config.listener_ip, Some(socket_addr.ip()));
@@ -1443,7 +1486,7 @@ mod prop_tests {
 
         let tcp_config = gateway.tcp().config();
         assert_eq!(tcp_config.max_connections, Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE);
-        assert_eq!(gateway.account().address(), account.address());
+        assert_eq!(gateway.address(), account.address());
     }
 
     #[proptest(async = "tokio")]
@@ -1456,7 +1499,7 @@ mod prop_tests {
         let worker_storage = storage.clone();
         let account = Account::try_from(private_key).unwrap();
 
-        let gateway = Gateway::new(account, storage.ledger().clone(), dev.ip(), &[], dev.port()).unwrap();
+        let gateway = Gateway::new(account.into(), storage.ledger().clone(), dev.ip(), &[], dev.port()).unwrap();
 
         let (primary_sender, _) = init_primary_channels();
 
@@ -38,7 +38,7 @@ use crate::{
     PRIMARY_PING_IN_MS,
     WORKER_PING_IN_MS,
 };
-use snarkos_account::Account;
+use snarkos_account::{SignRequest, Signer};
 use snarkos_node_bft_events::PrimaryPing;
 use snarkos_node_bft_ledger_service::LedgerService;
 use snarkvm::{
@@ -101,7 +101,7 @@ pub struct Primary<N: Network> {
 impl<N: Network> Primary<N> {
     /// Initializes a new primary instance.
     pub fn new(
-        account: Account<N>,
+        signer: Arc<dyn Signer<N>>,
         storage: Storage<N>,
         ledger: Arc<dyn LedgerService<N>>,
         ip: Option<SocketAddr>,
@@ -109,7 +109,7 @@ impl<N: Network> Primary<N> {
         dev: Option<u16>,
     ) -> Result<Self> {
         // Initialize the gateway.
-        let gateway = Gateway::new(account, ledger.clone(), ip, trusted_validators, dev)?;
+        let gateway = Gateway::new(signer, ledger.clone(), ip, trusted_validators, dev)?;
         // Initialize the sync module.
         let sync = Sync::new(gateway.clone(), storage.clone(), ledger.clone());
         // Initialize the primary instance.
@@ -186,6 +186,11 @@ impl<N: Network> Primary<N> {
         self.storage.current_round()
     }
 
+    /// Returns `true` if the primary is synced and has connected peers.
+    pub fn is_synced(&self) -> bool {
+        self.sync.is_synced()
+    }
+
     /// Returns the gateway.
     pub const fn gateway(&self) -> &Gateway<N> {
         &self.gateway
@@ -311,7 +316,7 @@ impl<N: Network> Primary<N> {
         let round = self.current_round();
 
         // Ensure the primary has not proposed a batch for this round before.
-        if self.storage.contains_certificate_in_round_from(round, self.gateway.account().address()) {
+        if self.storage.contains_certificate_in_round_from(round, self.gateway.address()) {
             // If a BFT sender was provided, attempt to advance the current round.
             if let Some(bft_sender) = self.bft_sender.get() {
                 match bft_sender.send_primary_round_to_bft(self.current_round()).await {
@@ -336,7 +341,7 @@ impl<N: Network> Primary<N> {
             // Retrieve the connected validator addresses.
             let mut connected_validators = self.gateway.connected_addresses();
             // Append the primary to the set.
-            connected_validators.insert(self.gateway.account().address());
+            connected_validators.insert(self.gateway.address());
             // If quorum threshold is not reached, return early.
             if !committee.is_quorum_threshold_reached(&connected_validators) {
                 debug!(
@@ -442,21 +447,23 @@ impl<N: Network> Primary<N> {
         /* Proceeding to sign & propose the batch. */
         info!("Proposing a batch with {} transmissions for round {round}...", transmissions.len());
 
-        // Retrieve the private key.
-        let private_key = *self.gateway.account().private_key();
+        // Retrieve the signer.
+        let signer = self.gateway.signer().clone();
         // Prepare the transmission IDs.
         let transmission_ids = transmissions.keys().copied().collect();
         // Prepare the certificate IDs.
         let certificate_ids = previous_certificates.into_iter().map(|c| c.id()).collect();
         // Sign the batch header.
-        let batch_header = spawn_blocking!(BatchHeader::new(
-            &private_key,
-            round,
-            now(),
-            transmission_ids,
-            certificate_ids,
-            &mut rand::thread_rng()
-        ))?;
+        let batch_header = spawn_blocking!({
+            let (author, timestamp) = (signer.address(), now());
+            // Compute the batch ID.
+            let batch_id =
+                BatchHeader::compute_batch_id(author, round, timestamp, &transmission_ids, &certificate_ids)?;
+            // Sign the batch ID.
+            let signature = signer.sign(&SignRequest::BatchHeader { round, batch_id })?;
+            // Construct the batch header.
+            BatchHeader::from(author, round, timestamp, transmission_ids, certificate_ids, signature)
+        })?;
         // Construct the proposal.
         let proposal =
             Proposal::new(self.ledger.get_previous_committee_for_round(round)?, batch_header.clone(), transmissions)?;
@@ -510,7 +517,7 @@ impl<N: Network> Primary<N> {
             bail!("Malicious peer - proposed batch from a non-committee member ({batch_author})");
         }
         // Ensure the batch proposal is not from the current primary.
-        if self.gateway.account().address() == batch_author {
+        if self.gateway.address() == batch_author {
             bail!("Invalid peer - proposed batch from myself ({batch_author})");
         }
 
@@ -559,8 +566,9 @@ impl<N: Network> Primary<N> {
         // Retrieve the batch ID.
         let batch_id = batch_header.batch_id();
         // Sign the batch ID.
-        let account = self.gateway.account().clone();
-        let signature = spawn_blocking!(account.sign(&[batch_id], &mut rand::thread_rng()))?;
+        let signer = self.gateway.signer().clone();
+        let request = SignRequest::BatchSignature { author: batch_author, round: batch_round, batch_id };
+        let signature = spawn_blocking!(signer.sign(&request))?;
 
         // Ensure the proposal has not already been signed.
         //
@@ -627,7 +635,7 @@ impl<N: Network> Primary<N> {
             bail!("Malicious peer - batch signature is from a different validator ({signer})");
         }
         // Ensure the batch signature is not from the current primary.
-        if self.gateway.account().address() == signer {
+        if self.gateway.address() == signer {
             bail!("Invalid peer - received a batch signature from myself ({signer})");
         }
 
@@ -727,7 +735,7 @@ impl<N: Network> Primary<N> {
             bail!("Malicious peer - Received a batch certificate from a non-committee member ({author})");
         }
         // Ensure the batch certificate is not from the current primary.
-        if self.gateway.account().address() == author {
+        if self.gateway.address() == author {
             bail!("Received a batch certificate for myself ({author})");
         }
 
@@ -825,7 +833,7 @@ impl<N: Network> Primary<N> {
                     // Retrieve the latest certificate of the primary.
                     let primary_certificate = {
                         // Retrieve the primary address.
-                        let primary_address = self_.gateway.account().address();
+                        let primary_address = self_.gateway.address();
 
                         // Iterate backwards from the latest round to find the primary certificate.
                         let mut certificate = None;
@@ -1450,6 +1458,7 @@ impl<N: Network> Primary<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkos_account::Account;
     use snarkos_node_bft_ledger_service::MockLedgerService;
     use snarkos_node_bft_storage_service::BFTMemoryService;
     use snarkvm::{
@@ -1488,7 +1497,7 @@ mod tests {
         let storage = Storage::new(ledger.clone(), Arc::new(BFTMemoryService::new()), 10);
 
         // Initialize the primary.
-        let mut primary = Primary::new(account, storage, ledger, None, &[], None).unwrap();
+        let mut primary = Primary::new(account.into(), storage, ledger, None, &[], None).unwrap();
 
         // Construct a worker instance.
         primary.workers = Arc::from([Worker::new(
@@ -1577,7 +1586,7 @@ mod tests {
         // Each committee member signs the batch.
         let mut signatures = Vec::with_capacity(accounts.len() - 1);
         for (socket_addr, account) in accounts {
-            if account.address() == primary.gateway.account().address() {
+            if account.address() == primary.gateway.address() {
                 continue;
             }
             let batch_id = primary.proposed_batch.read().as_ref().unwrap().batch_id();
@@ -1886,7 +1895,7 @@ mod tests {
         let round = 1;
         let timestamp = now();
         let proposal = create_test_proposal(
-            primary.gateway.account(),
+            &accounts[0].1,
             primary.ledger.current_committee().unwrap(),
             round,
             Default::default(),
@@ -1906,7 +1915,7 @@ mod tests {
         }
 
         // Check the certificate was created and stored by the primary.
-        assert!(primary.storage.contains_certificate_in_round_from(round, primary.gateway.account().address()));
+        assert!(primary.storage.contains_certificate_in_round_from(round, primary.gateway.address()));
         // Check the round was incremented.
         assert_eq!(primary.current_round(), round + 1);
     }
@@ -1924,7 +1933,7 @@ mod tests {
         // Create a valid proposal.
         let timestamp = now();
         let proposal = create_test_proposal(
-            primary.gateway.account(),
+            &accounts[0].1,
             primary.ledger.current_committee().unwrap(),
             round,
             previous_certificates,
@@ -1944,7 +1953,7 @@ mod tests {
         }
 
         // Check the certificate was created and stored by the primary.
-        assert!(primary.storage.contains_certificate_in_round_from(round, primary.gateway.account().address()));
+        assert!(primary.storage.contains_certificate_in_round_from(round, primary.gateway.address()));
         // Check the round was incremented.
         assert_eq!(primary.current_round(), round + 1);
     }
@@ -1959,7 +1968,7 @@ mod tests {
         let round = 1;
         let timestamp = now();
         let proposal = create_test_proposal(
-            primary.gateway.account(),
+            &accounts[0].1,
             primary.ledger.current_committee().unwrap(),
             round,
             Default::default(),
@@ -1978,7 +1987,7 @@ mod tests {
         primary.process_batch_signature_from_peer(*socket_addr, *signature).await.unwrap();
 
         // Check the certificate was not created and stored by the primary.
-        assert!(!primary.storage.contains_certificate_in_round_from(round, primary.gateway.account().address()));
+        assert!(!primary.storage.contains_certificate_in_round_from(round, primary.gateway.address()));
         // Check the round was incremented.
         assert_eq!(primary.current_round(), round);
     }
@@ -1996,7 +2005,7 @@ mod tests {
         // Create a valid proposal.
         let timestamp = now();
         let proposal = create_test_proposal(
-            primary.gateway.account(),
+            &accounts[0].1,
             primary.ledger.current_committee().unwrap(),
             round,
             previous_certificates,
@@ -2015,7 +2024,7 @@ mod tests {
         primary.process_batch_signature_from_peer(*socket_addr, *signature).await.unwrap();
 
         // Check the certificate was not created and stored by the primary.
-        assert!(!primary.storage.contains_certificate_in_round_from(round, primary.gateway.account().address()));
+        assert!(!primary.storage.contains_certificate_in_round_from(round, primary.gateway.address()));
         // Check the round was incremented.
         assert_eq!(primary.current_round(), round);
     }
@@ -29,7 +29,7 @@ use snarkvm::{
 };
 
 use indexmap::{indexset, IndexSet};
-use snarkvm::ledger::store::cow_to_cloned;
+use snarkvm::ledger::store::{cow_to_cloned, cow_to_copied};
 use std::{borrow::Cow, collections::HashMap};
 use tracing::error;
 
@@ -46,6 +46,24 @@ impl<N: Network> BFTPersistentStorage<N> {
         Ok(Self { transmissions: internal::RocksDB::open_map(N::ID, dev, MapID::BFT(BFTMap::Transmissions))? })
     }
 
+    /// Returns the number of transmissions in storage.
+    pub fn num_transmissions(&self) -> usize {
+        self.transmissions.keys_confirmed().count()
+    }
+
+    /// Removes all transmissions from storage, returning the number of removed transmissions.
+    pub fn clear(&self) -> Result<usize> {
+        let transmission_ids = self.transmissions.keys_confirmed().map(|id| cow_to_copied!(id)).collect::<Vec<_>>();
+        // Remove the transmissions atomically.
+        self.transmissions.start_atomic();
+        if let Err(error) = transmission_ids.iter().try_for_each(|id| self.transmissions.remove(id)) {
+            self.transmissions.abort_atomic();
+            return Err(error);
+        }
+        self.transmissions.finish_atomic()?;
+        Ok(transmission_ids.len())
+    }
+
     /// Initializes a new BFT persistent storage service.
     #[cfg(any(test, feature = "test"))]
     pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
@@ -194,7 +212,6 @@ impl<N: Network> StorageService<N> for BFTPersistentStorage<N> {
     /// Returns a HashMap over the `(transmission ID, (transmission, certificate IDs))` entries.
     #[cfg(any(test, feature = "test"))]
     fn as_hashmap(&self) -> HashMap<TransmissionID<N>, (Transmission<N>, IndexSet<Field<N>>)> {
-        use snarkvm::ledger::store::cow_to_copied;
         self.transmissions.iter_confirmed().map(|(k, v)| (cow_to_copied!(k), cow_to_cloned!(v))).collect()
     }
 }
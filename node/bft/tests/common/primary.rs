@@ -155,11 +155,13 @@ impl TestNetwork {
             let storage = Storage::new(ledger.clone(), Arc::new(BFTMemoryService::new()), MAX_GC_ROUNDS);
 
             let (primary, bft) = if config.bft {
-                let bft = BFT::<CurrentNetwork>::new(account, storage, ledger, None, &[], Some(id as u16)).unwrap();
+                let bft =
+                    BFT::<CurrentNetwork>::new(account.into(), storage, ledger, None, &[], Some(id as u16)).unwrap();
                 (bft.primary().clone(), Some(bft))
             } else {
                 let primary =
-                    Primary::<CurrentNetwork>::new(account, storage, ledger, None, &[], Some(id as u16)).unwrap();
+                    Primary::<CurrentNetwork>::new(account.into(), storage, ledger, None, &[], Some(id as u16))
+                        .unwrap();
                 (primary, None)
             };
 
@@ -29,12 +29,13 @@ use futures::{Future, StreamExt};
 use parking_lot::RwLock;
 use reqwest::Client;
 use std::{
+    path::{Path, PathBuf},
     sync::Arc,
     time::{Duration, Instant},
 };
 
 /// The number of blocks per file.
-const BLOCKS_PER_FILE: u32 = 50;
+pub const BLOCKS_PER_FILE: u32 = 50;
 /// The supported network.
 const NETWORK_ID: u16 = 3;
 
@@ -262,18 +263,60 @@ pub async fn load_blocks<N: Network>(
     }
 }
 
+/// A representation of the 'latest.json' file object.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct LatestState {
+    /// The height after the last block.
+    pub exclusive_height: u32,
+    /// The height of the last block.
+    pub inclusive_height: u32,
+    /// The hash of the last block.
+    pub hash: String,
+}
+
+/// Writes the given blocks to the file starting at the given height, in the CDN format.
+///
+/// The start height must be a multiple of `BLOCKS_PER_FILE`, and the blocks must be
+/// the (possibly partial) sequence of blocks starting at that height.
+pub fn write_blocks<N: Network>(dir: &Path, start_height: u32, blocks: &[Block<N>]) -> Result<PathBuf> {
+    // Ensure the blocks fit in the file.
+    if start_height % BLOCKS_PER_FILE != 0 {
+        bail!("The start height ({start_height}) must be a multiple of {BLOCKS_PER_FILE}");
+    }
+    if blocks.is_empty() || blocks.len() > BLOCKS_PER_FILE as usize {
+        bail!("A block file must contain between 1 and {BLOCKS_PER_FILE} blocks");
+    }
+    // Ensure the blocks are in order by height.
+    for (i, block) in blocks.iter().enumerate() {
+        if block.height() != start_height + i as u32 {
+            bail!("Expected block {} in the block file, found block {}", start_height + i as u32, block.height());
+        }
+    }
+    // Write the blocks.
+    let path = dir.join(format!("{start_height}.{}.blocks", start_height + BLOCKS_PER_FILE));
+    std::fs::write(&path, bincode::serialize(blocks)?)?;
+    Ok(path)
+}
+
+/// Writes the 'latest.json' file for the blocks in the given directory, in the CDN format.
+pub fn write_latest_state(dir: &Path, latest: &LatestState) -> Result<()> {
+    std::fs::write(dir.join("latest.json"), bincode::serialize(&serde_json::to_string(latest)?)?)?;
+    Ok(())
+}
+
+/// Fetches the bytes at the given URL, which may also be a `file://` URL to a local directory.
+async fn fetch_bytes(client: &Client, url: &str) -> Result<Vec<u8>> {
+    match url.strip_prefix("file://") {
+        Some(path) => Ok(tokio::fs::read(path).await?),
+        None => Ok(client.get(url).send().await?.bytes().await?.to_vec()),
+    }
+}
+
 /// Retrieves the CDN height with the given base URL.
 ///
 /// Note: This function decrements the tip by a few blocks, to ensure the
 /// tip is not on a block that is not yet available on the CDN.
 async fn cdn_height<const BLOCKS_PER_FILE: u32>(base_url: &str) -> Result<u32> {
-    // A representation of the 'latest.json' file object.
-    #[derive(Deserialize, Serialize, Debug)]
-    struct LatestState {
-        exclusive_height: u32,
-        inclusive_height: u32,
-        hash: String,
-    }
     // Create a request client.
     let client = match reqwest::Client::builder().build() {
         Ok(client) => client,
@@ -281,15 +324,10 @@ async fn cdn_height<const BLOCKS_PER_FILE: u32>(base_url: &str) -> Result<u32> {
     };
     // Prepare the URL.
     let latest_json_url = format!("{base_url}/latest.json");
-    // Send the request.
-    let response = match client.get(latest_json_url).send().await {
-        Ok(response) => response,
-        Err(error) => bail!("Failed to fetch the CDN height: {error}"),
-    };
-    // Parse the response.
-    let bytes = match response.bytes().await {
+    // Fetch the bytes.
+    let bytes = match fetch_bytes(&client, &latest_json_url).await {
         Ok(bytes) => bytes,
-        Err(error) => bail!("Failed to parse the CDN height response: {error}"),
+        Err(error) => bail!("Failed to fetch the CDN height: {error}"),
     };
     // Parse the bytes for the string.
     let latest_state_string = match bincode::deserialize::<String>(&bytes) {
@@ -301,6 +339,10 @@ async fn cdn_height<const BLOCKS_PER_FILE: u32>(base_url: &str) -> Result<u32> {
         Ok(latest) => latest.exclusive_height,
         Err(error) => bail!("Failed to extract the CDN height response: {error}"),
     };
+    // A local directory is complete, so adjust its tip to the end of its last file.
+    if base_url.starts_with("file://") {
+        return Ok((tip + BLOCKS_PER_FILE - 1) / BLOCKS_PER_FILE * BLOCKS_PER_FILE);
+    }
     // Decrement the tip by a few blocks to ensure the CDN is caught up.
     let tip = tip.saturating_sub(10);
     // Adjust the tip to the closest subsequent multiple of BLOCKS_PER_FILE.
@@ -310,14 +352,9 @@ async fn cdn_height<const BLOCKS_PER_FILE: u32>(base_url: &str) -> Result<u32> {
 /// Retrieves the objects from the CDN with the given URL.
 async fn cdn_get<T: 'static + DeserializeOwned + Send>(client: Client, url: &str, ctx: &str) -> Result<T> {
     // Fetch the bytes from the given URL.
-    let response = match client.get(url).send().await {
-        Ok(response) => response,
-        Err(error) => bail!("Failed to fetch {ctx}: {error}"),
-    };
-    // Parse the response.
-    let bytes = match response.bytes().await {
+    let bytes = match fetch_bytes(&client, url).await {
         Ok(bytes) => bytes,
-        Err(error) => bail!("Failed to parse {ctx}: {error}"),
+        Err(error) => bail!("Failed to fetch {ctx}: {error}"),
     };
     // Parse the objects.
     match tokio::task::spawn_blocking(move || bincode::deserialize::<T>(&bytes)).await {
@@ -389,8 +426,11 @@ mod tests {
     use crate::{
         blocks::{cdn_get, cdn_height, handle_dispatch_error, log_progress, BLOCKS_PER_FILE},
         load_blocks,
+        write_blocks,
+        write_latest_state,
+        LatestState,
     };
-    use snarkvm::prelude::{block::Block, Testnet3};
+    use snarkvm::prelude::{block::Block, FromBytes, Network, Testnet3};
 
     use anyhow::{anyhow, Result};
     use parking_lot::RwLock;
@@ -465,6 +505,40 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_load_blocks_from_directory() {
+        let dir = std::env::temp_dir().join(format!("snarkos-cdn-blocks-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // Write the genesis block into a local directory.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        write_blocks(&dir, 0, &[genesis.clone()]).unwrap();
+        let latest = LatestState { exclusive_height: 1, inclusive_height: 0, hash: genesis.hash().to_string() };
+        write_latest_state(&dir, &latest).unwrap();
+
+        // Ensure invalid block files are rejected.
+        assert!(write_blocks(&dir, 1, &[genesis.clone()]).is_err());
+        assert!(write_blocks::<CurrentNetwork>(&dir, 50, &[]).is_err());
+        assert!(write_blocks(&dir, 50, &[genesis.clone()]).is_err());
+
+        // Load the blocks from the local directory.
+        let blocks = Arc::new(RwLock::new(Vec::new()));
+        let blocks_clone = blocks.clone();
+        let process = move |block: Block<CurrentNetwork>| {
+            blocks_clone.write().push(block);
+            Ok(())
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let base_url = format!("file://{}", dir.display());
+            assert_eq!(cdn_height::<BLOCKS_PER_FILE>(&base_url).await.unwrap(), BLOCKS_PER_FILE);
+            assert_eq!(load_blocks(&base_url, 0, None, process).await.unwrap(), 0);
+        });
+        assert_eq!(*blocks.read(), vec![genesis]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_cdn_get() {
         let rt = tokio::runtime::Runtime::new().unwrap();
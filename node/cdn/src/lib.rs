@@ -18,4 +18,4 @@
 extern crate tracing;
 
 mod blocks;
-pub use blocks::{load_blocks, sync_ledger_with_cdn};
+pub use blocks::{load_blocks, sync_ledger_with_cdn, write_blocks, write_latest_state, LatestState, BLOCKS_PER_FILE};
@@ -17,7 +17,7 @@
 #[macro_use]
 extern crate tracing;
 
-use snarkos_account::Account;
+use snarkos_account::Signer;
 use snarkos_node_bft::{
     helpers::{
         fmt_id,
@@ -77,7 +77,7 @@ pub struct Consensus<N: Network> {
 impl<N: Network> Consensus<N> {
     /// Initializes a new instance of consensus.
     pub fn new(
-        account: Account<N>,
+        signer: Arc<dyn Signer<N>>,
         ledger: Arc<dyn LedgerService<N>>,
         ip: Option<SocketAddr>,
         trusted_validators: &[SocketAddr],
@@ -88,7 +88,7 @@ impl<N: Network> Consensus<N> {
         // Initialize the Narwhal storage.
         let storage = NarwhalStorage::new(ledger.clone(), transmissions, MAX_GC_ROUNDS);
         // Initialize the BFT.
-        let bft = BFT::new(account, storage, ledger.clone(), ip, trusted_validators, dev)?;
+        let bft = BFT::new(signer, storage, ledger.clone(), ip, trusted_validators, dev)?;
         // Return the consensus.
         Ok(Self {
             ledger,
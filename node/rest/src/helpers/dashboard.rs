@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::{
+    extract::ConnectInfo,
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Html,
+    },
+};
+use once_cell::sync::OnceCell;
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+};
+use tokio::sync::broadcast;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+/// The number of log lines buffered for each client of the log stream.
+const LOG_BUFFER_SIZE: usize = 1024;
+
+/// The web page of the dashboard.
+const DASHBOARD_HTML: &str = include_str!("../../assets/dashboard.html");
+
+/// Returns the sender of the log stream, if the dashboard is enabled.
+fn dashboard_logs() -> &'static OnceCell<broadcast::Sender<String>> {
+    static LOGS: OnceCell<broadcast::Sender<String>> = OnceCell::new();
+    &LOGS
+}
+
+/// Enables the dashboard on the REST server, and returns the sender of its log stream.
+pub fn enable_dashboard() -> broadcast::Sender<String> {
+    dashboard_logs().get_or_init(|| broadcast::channel(LOG_BUFFER_SIZE).0).clone()
+}
+
+/// Returns `true` if the dashboard is enabled.
+pub fn is_dashboard_enabled() -> bool {
+    dashboard_logs().get().is_some()
+}
+
+/// Ensures the request comes from the local machine, as the dashboard exposes the logs of the node.
+fn ensure_loopback(peer_addr: SocketAddr) -> Result<(), StatusCode> {
+    let is_loopback = match peer_addr.ip() {
+        IpAddr::V4(ip) => ip.is_loopback(),
+        IpAddr::V6(ip) => ip.is_loopback() || ip.to_ipv4_mapped().map_or(false, |ip| ip.is_loopback()),
+    };
+    match is_loopback {
+        true => Ok(()),
+        false => Err(StatusCode::FORBIDDEN),
+    }
+}
+
+/// Returns the web page of the dashboard, to the local machine only.
+pub(crate) async fn get_dashboard(
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+) -> Result<Html<&'static str>, StatusCode> {
+    ensure_loopback(peer_addr)?;
+    match is_dashboard_enabled() {
+        true => Ok(Html(DASHBOARD_HTML)),
+        false => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+/// Returns the stream of the logs of the node as server-sent events to the local machine only,
+/// if the dashboard is enabled.
+pub(crate) async fn get_dashboard_logs(
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, StatusCode> {
+    ensure_loopback(peer_addr)?;
+    let receiver = dashboard_logs().get().ok_or(StatusCode::NOT_FOUND)?.subscribe();
+    // A client that falls behind skips the logs it missed.
+    let stream = BroadcastStream::new(receiver).filter_map(|log| log.ok()).map(|log| Ok(Event::default().data(log)));
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
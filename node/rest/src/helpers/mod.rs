@@ -15,5 +15,8 @@
 mod auth;
 pub use auth::*;
 
+mod dashboard;
+pub use dashboard::*;
+
 mod error;
 pub use error::*;
@@ -25,6 +25,7 @@ mod routes;
 use snarkos_node_consensus::Consensus;
 use snarkos_node_router::{
     messages::{Message, UnconfirmedTransaction},
+    BanTarget,
     Routing,
 };
 use snarkvm::{
@@ -45,7 +46,12 @@ use axum::{
 };
 use axum_extra::response::ErasedJson;
 use parking_lot::Mutex;
-use std::{net::SocketAddr, sync::Arc};
+use std::{
+    net::SocketAddr,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
 use tokio::task::JoinHandle;
 use tower_http::{
     cors::{Any, CorsLayer},
@@ -106,8 +112,18 @@ impl<N: Network, C: ConsensusStorage<N>, R: Routing<N>> Rest<N, C, R> {
 
             // All the endpoints before the call to `route_layer` are protected with JWT auth.
             .route("/testnet3/node/address", get(Self::get_node_address))
+            .route("/testnet3/node/bans", get(Self::get_node_bans))
+            .route("/testnet3/node/ban", post(Self::ban_peer))
+            .route("/testnet3/node/unban", post(Self::unban_peer))
             .route_layer(middleware::from_fn(auth_middleware))
 
+            // GET ../node/..
+            .route("/testnet3/node/status", get(Self::get_node_status))
+            .route("/testnet3/node/logs", get(get_dashboard_logs))
+
+            // GET the dashboard, if it is enabled.
+            .route("/dashboard", get(get_dashboard))
+
             // ----------------- DEPRECATED ROUTES -----------------
             // The following `GET ../latest/..` routes will be removed before mainnet.
             // Please refer to the recommended routes for each endpoint:
@@ -35,6 +35,16 @@ pub(crate) struct Metadata {
     metadata: bool,
 }
 
+/// The `ban_peer` and `unban_peer` request object.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct BanRequest {
+    /// The IP address, the IP range in CIDR notation, or the Aleo address to (un)ban.
+    target: String,
+    /// The duration of the ban in seconds, or `None` for a permanent ban.
+    #[serde(default)]
+    duration_in_secs: Option<u64>,
+}
+
 impl<N: Network, C: ConsensusStorage<N>, R: Routing<N>> Rest<N, C, R> {
     // ----------------- DEPRECATED FUNCTIONS -----------------
     // The functions below are associated with deprecated routes.
@@ -272,6 +282,72 @@ impl<N: Network, C: ConsensusStorage<N>, R: Routing<N>> Rest<N, C, R> {
         ErasedJson::pretty(rest.routing.router().address())
     }
 
+    // GET /testnet3/node/bans
+    pub(crate) async fn get_node_bans(State(rest): State<Self>) -> ErasedJson {
+        let bans = rest
+            .routing
+            .router()
+            .bans()
+            .into_iter()
+            .map(|(target, expires_at)| {
+                let expires_at = expires_at.and_then(|time| time.duration_since(UNIX_EPOCH).ok()).map(|d| d.as_secs());
+                json!({ "target": target.to_string(), "expires_at": expires_at })
+            })
+            .collect::<Vec<_>>();
+        ErasedJson::pretty(bans)
+    }
+
+    // POST /testnet3/node/ban
+    pub(crate) async fn ban_peer(
+        State(rest): State<Self>,
+        Json(request): Json<BanRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        let target = BanTarget::<N>::from_str(&request.target)?;
+        rest.routing.router().ban(target, request.duration_in_secs.map(Duration::from_secs));
+        Ok(ErasedJson::pretty(target.to_string()))
+    }
+
+    // POST /testnet3/node/unban
+    pub(crate) async fn unban_peer(
+        State(rest): State<Self>,
+        Json(request): Json<BanRequest>,
+    ) -> Result<ErasedJson, RestError> {
+        let target = BanTarget::<N>::from_str(&request.target)?;
+        Ok(ErasedJson::pretty(rest.routing.router().unban(&target)))
+    }
+
+    // GET /testnet3/node/status
+    pub(crate) async fn get_node_status(State(rest): State<Self>) -> ErasedJson {
+        let router = rest.routing.router();
+        let latest_height = rest.ledger.latest_height();
+        // The memory pool and the BFT are only available on validators.
+        let (mempool, bft) = match &rest.consensus {
+            Some(consensus) => (
+                json!({
+                    "transmissions": consensus.num_unconfirmed_transmissions(),
+                    "solutions": consensus.num_unconfirmed_solutions(),
+                    "transactions": consensus.num_unconfirmed_transactions(),
+                }),
+                json!({ "round": consensus.bft().primary().current_round(), "leader": consensus.bft().leader() }),
+            ),
+            None => (serde_json::Value::Null, serde_json::Value::Null),
+        };
+        ErasedJson::pretty(json!({
+            "node_type": router.node_type(),
+            "height": latest_height,
+            "hash": rest.ledger.latest_hash(),
+            "is_synced": rest.routing.is_block_synced(),
+            "peers": {
+                "total": router.number_of_connected_peers(),
+                "validators": router.number_of_connected_validators(),
+                "provers": router.number_of_connected_provers(),
+                "clients": router.number_of_connected_clients(),
+            },
+            "mempool": mempool,
+            "bft": bft,
+        }))
+    }
+
     // GET /testnet3/find/blockHash/{transactionID}
     pub(crate) async fn find_block_hash(
         State(rest): State<Self>,
@@ -17,10 +17,11 @@ use crate::{
     Peer,
     Router,
 };
+use snarkos_account::SignRequest;
 use snarkos_node_tcp::{ConnectionSide, Tcp, P2P};
 use snarkvm::{
     ledger::narwhal::Data,
-    prelude::{block::Header, error, Address, Network},
+    prelude::{block::Header, error, Address, Network, Signature},
 };
 
 use anyhow::{bail, Result};
@@ -165,7 +166,7 @@ impl<N: Network> Router<N> {
         /* Step 3: Send the challenge response. */
 
         // Sign the counterparty nonce.
-        let Ok(our_signature) = self.account.sign_bytes(&peer_request.nonce.to_le_bytes(), rng) else {
+        let Ok(our_signature) = self.sign_challenge(peer_request.nonce).await else {
             return Err(error(format!("Failed to sign the challenge request nonce from '{peer_addr}'")));
         };
         // Send the challenge response.
@@ -213,7 +214,7 @@ impl<N: Network> Router<N> {
         let rng = &mut OsRng;
 
         // Sign the counterparty nonce.
-        let Ok(our_signature) = self.account.sign_bytes(&peer_request.nonce.to_le_bytes(), rng) else {
+        let Ok(our_signature) = self.sign_challenge(peer_request.nonce).await else {
             return Err(error(format!("Failed to sign the challenge request nonce from '{peer_addr}'")));
         };
         // Send the challenge response.
@@ -262,6 +263,10 @@ impl<N: Network> Router<N> {
         if self.is_restricted(&peer_ip) {
             bail!("Dropping connection request from '{peer_ip}' (restricted)")
         }
+        // Ensure the subnet of the peer is not full.
+        if let Some(subnet) = self.full_subnet(&peer_ip) {
+            bail!("Dropping connection request from '{peer_ip}' (subnet '{subnet}' is full)")
+        }
         // Ensure the peer is not spamming connection attempts.
         if !peer_ip.ip().is_loopback() {
             // Add this connection attempt and retrieve the number of attempts.
@@ -320,6 +325,17 @@ impl<N: Network> Router<N> {
             warn!("Handshake with '{peer_addr}' failed (invalid signature)");
             return Some(DisconnectReason::InvalidChallengeResponse);
         }
+        // Now that the peer has proven ownership of its address, ensure the address is not banned.
+        if self.is_banned_address(&peer_address) {
+            warn!("Handshake with '{peer_addr}' failed (banned address '{peer_address}')");
+            return Some(DisconnectReason::NoReasonGiven);
+        }
         None
     }
+
+    /// Signs the given challenge nonce. A remote signer blocks on its socket, so sign in a blocking task.
+    async fn sign_challenge(&self, nonce: u64) -> Result<Signature<N>> {
+        let signer = self.signer.clone();
+        tokio::task::spawn_blocking(move || signer.sign(&SignRequest::Challenge { nonce })).await?
+    }
 }
@@ -15,12 +15,16 @@
 use crate::{
     messages::{DisconnectReason, Message, PeerRequest},
     Outbound,
+    ReputationEvent,
     Router,
 };
 use snarkvm::prelude::Network;
 
 use colored::Colorize;
-use rand::{prelude::IteratorRandom, rngs::OsRng};
+use rand::{
+    prelude::{IteratorRandom, SliceRandom},
+    rngs::OsRng,
+};
 
 /// A helper function to compute the maximum of two numbers.
 /// See Rust issue 92391: https://github.com/rust-lang/rust/issues/92391.
@@ -48,6 +52,8 @@ pub trait Heartbeat<N: Network>: Outbound<N> {
 
         // Remove any stale connected peers.
         self.remove_stale_connected_peers();
+        // Remove the connected peers that exceed the subnet limits.
+        self.remove_subnet_surplus_peers();
         // Remove the oldest connected peer.
         self.remove_oldest_connected_peer();
         // Keep the number of connected peers within the allowed range.
@@ -58,6 +64,8 @@ pub trait Heartbeat<N: Network>: Outbound<N> {
         self.handle_trusted_peers();
         // Keep the puzzle request up to date.
         self.handle_puzzle_request();
+        // Persist the peer book, from time to time.
+        self.router().save_peer_book_periodically();
     }
 
     /// TODO (howardwu): Consider checking minimum number of validators, to exclude clients and provers.
@@ -90,12 +98,45 @@ pub trait Heartbeat<N: Network>: Outbound<N> {
             let elapsed = peer.last_seen().elapsed().as_secs();
             if elapsed > Router::<N>::RADIO_SILENCE_IN_SECS {
                 warn!("Peer {} has not communicated in {elapsed} seconds", peer.ip());
+                // Lower the reputation of this peer.
+                self.router().update_reputation(peer.ip(), ReputationEvent::Timeout);
                 // Disconnect from this peer.
                 self.router().disconnect(peer.ip());
             }
         }
     }
 
+    /// This function removes the connected peers that exceed the subnet limits (e.g. after the limits were lowered),
+    /// starting with the lowest reputation. The trusted peers are not subject to the subnet limits.
+    fn remove_subnet_surplus_peers(&self) {
+        // Skip if the node is in development mode.
+        if self.router().is_dev() {
+            return;
+        }
+
+        // Retrieve the trusted peers.
+        let trusted = self.router().trusted_peers();
+
+        // Order the connected peers by preference, starting with the trusted peers and the highest reputation.
+        let mut peer_ips = self.router().connected_peers();
+        peer_ips.sort_by(|a, b| {
+            trusted
+                .contains(b)
+                .cmp(&trusted.contains(a))
+                .then_with(|| self.router().reputation_score(b).total_cmp(&self.router().reputation_score(a)))
+        });
+
+        for peer_ip in self.router().subnet_limits().surplus_peers(&peer_ips) {
+            if trusted.contains(&peer_ip) {
+                continue;
+            }
+            info!("Disconnecting from '{peer_ip}' (exceeded the subnet limits)");
+            self.send(peer_ip, Message::Disconnect(DisconnectReason::TooManyPeers.into()));
+            // Disconnect from this peer.
+            self.router().disconnect(peer_ip);
+        }
+    }
+
     /// This function removes the oldest connected peer, to keep the connections fresh.
     /// This function only triggers if the router is above the minimum number of connected peers.
     fn remove_oldest_connected_peer(&self) {
@@ -108,13 +149,17 @@ pub trait Heartbeat<N: Network>: Outbound<N> {
         let trusted = self.router().trusted_peers();
         // Retrieve the bootstrap peers.
         let bootstrap = self.router().bootstrap_peers();
+        // Retrieve the protected long-lived peers.
+        let protected = self.router().protected_peers();
 
-        // Find the oldest connected peer, that is neither trusted nor a bootstrap peer.
+        // Find the oldest connected peer, that is neither trusted, nor a bootstrap peer, nor protected.
         let oldest_peer = self
             .router()
             .get_connected_peers()
             .iter()
-            .filter(|peer| !trusted.contains(&peer.ip()) && !bootstrap.contains(&peer.ip()))
+            .filter(|peer| {
+                !trusted.contains(&peer.ip()) && !bootstrap.contains(&peer.ip()) && !protected.contains(&peer.ip())
+            })
             .min_by_key(|peer| peer.last_seen())
             .map(|peer| peer.ip());
 
@@ -144,19 +189,26 @@ pub trait Heartbeat<N: Network>: Outbound<N> {
             let trusted = self.router().trusted_peers();
             // Retrieve the bootstrap peers.
             let bootstrap = self.router().bootstrap_peers();
+            // Retrieve the protected long-lived peers.
+            let protected = self.router().protected_peers();
 
             // Initialize an RNG.
             let rng = &mut OsRng;
 
             // TODO (howardwu): As a validator, prioritize disconnecting from clients and provers.
-            //  Remove RNG, pick the `n` oldest nodes.
-            // Determine the peers to disconnect from.
-            let peer_ips_to_disconnect = self
+            // Determine the peers to disconnect from, starting with the lowest reputation and breaking ties randomly.
+            let mut peer_ips_to_disconnect = self
                 .router()
                 .connected_peers()
                 .into_iter()
-                .filter(|peer_ip| !trusted.contains(peer_ip) && !bootstrap.contains(peer_ip))
-                .choose_multiple(rng, num_surplus);
+                .filter(|peer_ip| {
+                    !trusted.contains(peer_ip) && !bootstrap.contains(peer_ip) && !protected.contains(peer_ip)
+                })
+                .collect::<Vec<_>>();
+            peer_ips_to_disconnect.shuffle(rng);
+            peer_ips_to_disconnect
+                .sort_by(|a, b| self.router().reputation_score(a).total_cmp(&self.router().reputation_score(b)));
+            peer_ips_to_disconnect.truncate(num_surplus);
 
             // Proceed to send disconnect requests to these peers.
             for peer_ip in peer_ips_to_disconnect {
@@ -180,8 +232,27 @@ pub trait Heartbeat<N: Network>: Outbound<N> {
             // Initialize an RNG.
             let rng = &mut OsRng;
 
-            // Attempt to connect to more peers.
-            for peer_ip in self.router().candidate_peers().into_iter().choose_multiple(rng, num_deficient) {
+            // Attempt to connect to more peers, starting with the highest reputation, then preferring
+            // the peers the node has connected to before, and breaking ties randomly.
+            let mut candidate_peers = self.router().candidate_peers().into_iter().collect::<Vec<_>>();
+            candidate_peers.shuffle(rng);
+            candidate_peers.sort_by(|a, b| {
+                self.router()
+                    .reputation_score(b)
+                    .total_cmp(&self.router().reputation_score(a))
+                    .then_with(|| self.router().has_connected_before(b).cmp(&self.router().has_connected_before(a)))
+            });
+            // Select the candidates that spread the connections over as many subnets as possible.
+            let connected_peers = self.router().connected_peers();
+            let candidate_peers = match self.router().is_dev() {
+                true => candidate_peers.into_iter().take(num_deficient).collect(),
+                false => self.router().subnet_limits().select_diverse_peers(
+                    &candidate_peers,
+                    &connected_peers,
+                    num_deficient,
+                ),
+            };
+            for peer_ip in candidate_peers {
                 self.router().connect(peer_ip);
             }
             // Request more peers from the connected peers.
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkos_node_tcp::{BanList, IpRange};
+use snarkvm::prelude::{Address, Network};
+
+use anyhow::{anyhow, Result};
+use std::{fmt, str::FromStr, time::SystemTime};
+
+/// The target of an operator-configured ban, which is either a range of IP addresses or an Aleo address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BanTarget<N: Network> {
+    /// A range of IP addresses, in CIDR notation, or a single IP address.
+    Ip(IpRange),
+    /// The Aleo address of a peer, which is enforced after the challenge handshake.
+    Address(Address<N>),
+}
+
+impl<N: Network> BanTarget<N> {
+    /// Bans the target in the given ban list, until the given expiry time (or permanently, if `None`).
+    /// The Aleo addresses are banned as identities, which are enforced after the challenge handshake.
+    pub fn insert_into(&self, ban_list: &BanList, expires_at: Option<SystemTime>) {
+        match self {
+            Self::Ip(range) => ban_list.insert(*range, expires_at),
+            Self::Address(address) => ban_list.insert_identity(address.to_string(), expires_at),
+        }
+    }
+
+    /// Lifts the ban of the target in the given ban list. Returns `true` if the target was banned.
+    pub fn remove_from(&self, ban_list: &BanList) -> bool {
+        match self {
+            Self::Ip(range) => ban_list.remove(range),
+            Self::Address(address) => ban_list.remove_identity(&address.to_string()),
+        }
+    }
+
+    /// Returns the targets banned in the given ban list, with the expiry time of each ban (if any).
+    pub fn list(ban_list: &BanList) -> Vec<(Self, Option<SystemTime>)> {
+        let ranges = ban_list.snapshot().into_iter().map(|(range, expires_at)| (Self::Ip(range), expires_at));
+        let addresses = ban_list.identities().into_iter().filter_map(|(identity, expires_at)| {
+            Address::from_str(&identity).ok().map(|address| (Self::Address(address), expires_at))
+        });
+        ranges.chain(addresses).collect()
+    }
+}
+
+impl<N: Network> FromStr for BanTarget<N> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        match s.starts_with("aleo1") {
+            true => Ok(Self::Address(Address::from_str(s)?)),
+            false => Ok(Self::Ip(IpRange::from_str(s).map_err(|_| anyhow!("Invalid ban target '{s}'"))?)),
+        }
+    }
+}
+
+impl<N: Network> fmt::Display for BanTarget<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ip(range) => write!(f, "{range}"),
+            Self::Address(address) => write!(f, "{address}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_ban_target_from_str() {
+        let target = BanTarget::<CurrentNetwork>::from_str("10.0.0.0/8").unwrap();
+        assert_eq!(target, BanTarget::Ip(IpRange::from_str("10.0.0.0/8").unwrap()));
+        assert_eq!(target.to_string(), "10.0.0.0/8");
+
+        let address = "aleo1uxl69laseuv3876ksh8k0nd7tvpgjt6ccrgccedpjk9qwyfensxst9ftg5";
+        let target = BanTarget::<CurrentNetwork>::from_str(address).unwrap();
+        assert!(matches!(target, BanTarget::Address(_)));
+        assert_eq!(target.to_string(), address);
+
+        assert!(BanTarget::<CurrentNetwork>::from_str("aleo1invalid").is_err());
+        assert!(BanTarget::<CurrentNetwork>::from_str("not an ip").is_err());
+    }
+}
@@ -12,11 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod ban_target;
+pub use ban_target::*;
+
 mod cache;
 pub use cache::Cache;
 
 mod peer;
 pub use peer::*;
 
+mod peer_book;
+pub use peer_book::*;
+
 mod resolver;
 pub use resolver::*;
+
+mod subnet;
+pub use subnet::*;
+
+pub use snarkos_node_tcp::{Reputation, ReputationAction, ReputationEvent};
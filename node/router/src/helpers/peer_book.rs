@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::messages::NodeType;
+
+use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    net::SocketAddr,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Returns the current UNIX timestamp in seconds.
+pub fn now_unix() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs() as i64)
+}
+
+/// The record of a known peer in the peer book.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerRecord {
+    /// The node type of the peer, if the node has connected to it before.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_type: Option<NodeType>,
+    /// The UNIX timestamp of the last successful connection to the peer, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_connected: Option<i64>,
+    /// The number of failed connection attempts since the last successful connection.
+    #[serde(default)]
+    pub failures: u32,
+    /// The UNIX timestamp at which the ban of the peer expires, if the peer is banned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banned_until: Option<i64>,
+}
+
+impl PeerRecord {
+    /// Returns the remaining duration of the ban of the peer, if the peer is still banned at the given time.
+    pub fn remaining_ban(&self, now: i64) -> Option<Duration> {
+        self.banned_until.filter(|until| *until > now).map(|until| Duration::from_secs((until - now) as u64))
+    }
+
+    /// Returns `true` if the peer has failed too many connection attempts, and is no longer worth remembering.
+    pub fn is_unreachable(&self) -> bool {
+        self.failures >= PeerBook::MAXIMUM_CONNECTION_FAILURES
+    }
+}
+
+/// The peer book is the on-disk record of the known peers, which allows the node
+/// to reconnect to peers it knows after a restart, even if the bootstrap peers are down.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerBook {
+    /// The map of peer IPs to their records.
+    peers: IndexMap<SocketAddr, PeerRecord>,
+    /// The map of operator-configured ban targets to the UNIX timestamps at which the bans expire (if any).
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    operator_bans: IndexMap<String, Option<i64>>,
+}
+
+impl PeerBook {
+    /// The maximum number of failed connection attempts before a peer is forgotten.
+    pub const MAXIMUM_CONNECTION_FAILURES: u32 = 10;
+    /// The maximum number of peers stored in the peer book.
+    pub const MAXIMUM_PEERS: usize = 10_000;
+
+    /// Loads the peer book from the given path, returning an empty peer book if the file does not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let bytes = fs::read(path).with_context(|| format!("Failed to read the peer book at '{}'", path.display()))?;
+        serde_json::from_slice(&bytes).with_context(|| format!("Failed to parse the peer book at '{}'", path.display()))
+    }
+
+    /// Saves the peer book to the given path.
+    /// The file is written to a temporary path first, so an interrupted save does not corrupt the peer book.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("tmp");
+        fs::write(&temp_path, serde_json::to_vec_pretty(self)?)
+            .with_context(|| format!("Failed to write the peer book at '{}'", temp_path.display()))?;
+        fs::rename(&temp_path, path).with_context(|| format!("Failed to write the peer book at '{}'", path.display()))
+    }
+
+    /// Returns the number of peers in the peer book.
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Returns `true` if the peer book is empty.
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// Returns the record of the given peer IP, if it exists.
+    pub fn get(&self, peer_ip: &SocketAddr) -> Option<&PeerRecord> {
+        self.peers.get(peer_ip)
+    }
+
+    /// Returns an iterator over the peer IPs and their records.
+    pub fn iter(&self) -> impl Iterator<Item = (&SocketAddr, &PeerRecord)> {
+        self.peers.iter()
+    }
+
+    /// Returns an iterator over the peer IPs and their mutable records.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&SocketAddr, &mut PeerRecord)> {
+        self.peers.iter_mut()
+    }
+
+    /// Returns the record of the given peer IP, inserting a new record if it does not exist.
+    pub fn entry(&mut self, peer_ip: SocketAddr) -> &mut PeerRecord {
+        self.peers.entry(peer_ip).or_default()
+    }
+
+    /// Returns an iterator over the operator-configured ban targets and their expiry timestamps.
+    pub fn operator_bans(&self) -> impl Iterator<Item = (&String, &Option<i64>)> {
+        self.operator_bans.iter()
+    }
+
+    /// Replaces the operator-configured bans with the given ban targets and their expiry timestamps.
+    pub fn set_operator_bans(&mut self, bans: impl IntoIterator<Item = (String, Option<i64>)>) {
+        self.operator_bans = bans.into_iter().collect();
+    }
+
+    /// Records a successful connection to the given peer IP.
+    pub fn record_connection(&mut self, peer_ip: SocketAddr, node_type: NodeType) {
+        let record = self.entry(peer_ip);
+        record.node_type = Some(node_type);
+        record.last_connected = Some(now_unix());
+        record.failures = 0;
+    }
+
+    /// Records a failed connection attempt to the given peer IP.
+    pub fn record_failure(&mut self, peer_ip: SocketAddr) {
+        let record = self.entry(peer_ip);
+        record.failures = record.failures.saturating_add(1);
+    }
+
+    /// Removes the peers that are unreachable and are not banned, and bounds the size of the peer book,
+    /// preferring to keep the banned peers and the most recently connected peers.
+    pub fn prune(&mut self) {
+        let now = now_unix();
+        self.peers.retain(|_, record| record.remaining_ban(now).is_some() || !record.is_unreachable());
+        if self.peers.len() > Self::MAXIMUM_PEERS {
+            let priority = |record: &PeerRecord| (record.remaining_ban(now).is_some(), record.last_connected);
+            self.peers.sort_by(|_, a, _, b| priority(b).cmp(&priority(a)));
+            self.peers.truncate(Self::MAXIMUM_PEERS);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    #[test]
+    fn test_peer_book_save_and_load() {
+        let path = std::env::temp_dir().join(format!("snarkos-peer-book-{}.json", std::process::id()));
+
+        // Loading a missing peer book returns an empty peer book.
+        assert!(PeerBook::load(&path).unwrap().is_empty());
+
+        let mut peer_book = PeerBook::default();
+        let connected = SocketAddr::from_str("1.2.3.4:4133").unwrap();
+        let failed = SocketAddr::from_str("5.6.7.8:4133").unwrap();
+        peer_book.record_connection(connected, NodeType::Validator);
+        peer_book.record_failure(failed);
+        peer_book.entry(failed).banned_until = Some(now_unix() + 60);
+
+        peer_book.save(&path).unwrap();
+        let loaded = PeerBook::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, peer_book);
+        assert_eq!(loaded.get(&connected).unwrap().node_type, Some(NodeType::Validator));
+        assert_eq!(loaded.get(&failed).unwrap().failures, 1);
+        assert!(loaded.get(&failed).unwrap().remaining_ban(now_unix()).is_some());
+    }
+
+    #[test]
+    fn test_peer_book_prune() {
+        let mut peer_book = PeerBook::default();
+        let unreachable = SocketAddr::from_str("1.2.3.4:4133").unwrap();
+        let banned = SocketAddr::from_str("5.6.7.8:4133").unwrap();
+        for _ in 0..PeerBook::MAXIMUM_CONNECTION_FAILURES {
+            peer_book.record_failure(unreachable);
+            peer_book.record_failure(banned);
+        }
+        peer_book.entry(banned).banned_until = Some(now_unix() + 60);
+
+        // The unreachable peer is forgotten, while the banned peer is kept until its ban expires.
+        peer_book.prune();
+        assert!(peer_book.get(&unreachable).is_none());
+        assert!(peer_book.get(&banned).is_some());
+
+        // A successful connection resets the failures.
+        peer_book.record_connection(unreachable, NodeType::Client);
+        assert_eq!(peer_book.get(&unreachable).unwrap().failures, 0);
+    }
+}
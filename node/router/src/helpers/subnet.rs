@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkos_node_tcp::{is_bogon_ip, IpRange};
+
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+};
+
+/// The maximum number of connected peers per subnet, which keeps a single network operator
+/// from occupying all of the connection slots of the node (i.e. an eclipse attack).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SubnetLimits {
+    /// The maximum number of connected peers in an IPv4 /16 subnet.
+    pub ipv4_16: usize,
+    /// The maximum number of connected peers in an IPv4 /24 subnet.
+    pub ipv4_24: usize,
+    /// The maximum number of connected peers in an IPv6 /48 subnet.
+    pub ipv6_48: usize,
+}
+
+impl Default for SubnetLimits {
+    fn default() -> Self {
+        Self { ipv4_16: 8, ipv4_24: 4, ipv6_48: 4 }
+    }
+}
+
+impl SubnetLimits {
+    /// Returns the subnets of the given IP address, along with their limits.
+    /// The bogon IP addresses (e.g. local networks) are not subject to any limits.
+    pub fn subnets(&self, ip: IpAddr) -> Vec<(IpRange, usize)> {
+        if is_bogon_ip(ip) {
+            return vec![];
+        }
+        let subnet = |prefix_len| IpRange::new(ip, prefix_len).expect("The prefix length is valid for the IP version");
+        // Note: The IPv4-mapped IPv6 addresses are treated as IPv4 addresses.
+        let is_ipv4 = match ip {
+            IpAddr::V4(_) => true,
+            IpAddr::V6(ipv6) => ipv6.to_ipv4_mapped().is_some(),
+        };
+        match is_ipv4 {
+            true => vec![(subnet(16), self.ipv4_16), (subnet(24), self.ipv4_24)],
+            false => vec![(subnet(48), self.ipv6_48)],
+        }
+    }
+
+    /// Returns the first subnet of the given IP address that is full, given the IP addresses of the connected peers.
+    pub fn full_subnet(&self, ip: IpAddr, connected: &[SocketAddr]) -> Option<IpRange> {
+        self.subnets(ip).into_iter().find_map(|(subnet, limit)| {
+            let num_connected = connected.iter().filter(|peer_ip| subnet.contains(peer_ip.ip())).count();
+            (num_connected >= limit).then_some(subnet)
+        })
+    }
+
+    /// Returns the peers that exceed the subnet limits, given the peers in order of preference,
+    /// such that the earlier peers are kept over the later ones.
+    pub fn surplus_peers(&self, peers: &[SocketAddr]) -> Vec<SocketAddr> {
+        let mut counts = HashMap::<IpRange, usize>::new();
+        let mut surplus = Vec::new();
+        for peer_ip in peers {
+            let subnets = self.subnets(peer_ip.ip());
+            match subnets.iter().all(|(subnet, limit)| counts.get(subnet).copied().unwrap_or_default() < *limit) {
+                true => subnets.into_iter().for_each(|(subnet, _)| *counts.entry(subnet).or_default() += 1),
+                false => surplus.push(*peer_ip),
+            }
+        }
+        surplus
+    }
+
+    /// Selects up to `num` peers from the candidates, which are given in order of preference,
+    /// so that the selected peers are spread over as many subnets as possible.
+    /// Candidates that would exceed the subnet limits are never selected.
+    pub fn select_diverse_peers(
+        &self,
+        candidates: &[SocketAddr],
+        connected: &[SocketAddr],
+        num: usize,
+    ) -> Vec<SocketAddr> {
+        // Count the connected peers per subnet.
+        let mut counts = HashMap::<IpRange, usize>::new();
+        for peer_ip in connected {
+            for (subnet, _) in self.subnets(peer_ip.ip()) {
+                *counts.entry(subnet).or_default() += 1;
+            }
+        }
+
+        let mut remaining = candidates.iter().map(|peer_ip| (*peer_ip, self.subnets(peer_ip.ip()))).collect::<Vec<_>>();
+        let mut selected = Vec::with_capacity(num);
+        while selected.len() < num {
+            // Find the candidate in the least populated subnets, preferring the earlier candidates on ties.
+            let best = remaining
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, subnets))| {
+                    subnets.iter().all(|(subnet, limit)| counts.get(subnet).copied().unwrap_or_default() < *limit)
+                })
+                .min_by_key(|(index, (_, subnets))| {
+                    let occupancy = subnets.iter().map(|(subnet, _)| counts.get(subnet).copied().unwrap_or_default());
+                    (occupancy.collect::<Vec<_>>(), *index)
+                })
+                .map(|(index, _)| index);
+            let Some(index) = best else { break };
+
+            let (peer_ip, subnets) = remaining.remove(index);
+            for (subnet, _) in subnets {
+                *counts.entry(subnet).or_default() += 1;
+            }
+            selected.push(peer_ip);
+        }
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    fn ip(s: &str) -> SocketAddr {
+        SocketAddr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_full_subnet() {
+        let limits = SubnetLimits { ipv4_16: 3, ipv4_24: 2, ipv6_48: 1 };
+        let connected = [ip("1.2.3.4:4133"), ip("1.2.3.5:4133"), ip("1.2.4.4:4133"), ip("[2001:db8::1]:4133")];
+
+        assert_eq!(
+            limits.full_subnet(ip("1.2.3.6:4133").ip(), &connected),
+            Some(IpRange::from_str("1.2.0.0/16").unwrap())
+        );
+        assert_eq!(limits.full_subnet(ip("1.3.3.6:4133").ip(), &connected), None);
+        assert!(limits.full_subnet(ip("[2001:db8:0:1::1]:4133").ip(), &connected).is_some());
+        assert!(limits.full_subnet(ip("[2001:db9::1]:4133").ip(), &connected).is_none());
+        // The local networks are not subject to any limits.
+        let local = [ip("127.0.0.1:4130"), ip("127.0.0.1:4131")];
+        assert_eq!(limits.full_subnet(ip("127.0.0.1:4132").ip(), &local), None);
+    }
+
+    #[test]
+    fn test_surplus_peers() {
+        let limits = SubnetLimits { ipv4_16: 3, ipv4_24: 2, ipv6_48: 1 };
+        let peers = [
+            ip("1.2.3.4:4133"),
+            ip("1.2.3.5:4133"),
+            ip("1.2.3.6:4133"),
+            ip("1.2.4.4:4133"),
+            ip("1.2.5.4:4133"),
+            ip("[2001:db8::1]:4133"),
+            ip("[2001:db8::2]:4133"),
+        ];
+        assert_eq!(limits.surplus_peers(&peers), vec![
+            ip("1.2.3.6:4133"),
+            ip("1.2.5.4:4133"),
+            ip("[2001:db8::2]:4133")
+        ]);
+    }
+
+    #[test]
+    fn test_select_diverse_peers() {
+        let limits = SubnetLimits::default();
+        let connected = [ip("1.2.3.4:4133")];
+        let candidates = [ip("1.2.3.5:4133"), ip("1.2.4.5:4133"), ip("5.6.7.8:4133"), ip("9.9.9.9:4133")];
+
+        // The candidates in unoccupied subnets are preferred, in order of preference.
+        let selected = limits.select_diverse_peers(&candidates, &connected, 2);
+        assert_eq!(selected, vec![ip("5.6.7.8:4133"), ip("9.9.9.9:4133")]);
+
+        // The /24 subnet of the connected peer is used last.
+        let selected = limits.select_diverse_peers(&candidates, &connected, 4);
+        assert_eq!(selected, vec![ip("5.6.7.8:4133"), ip("9.9.9.9:4133"), ip("1.2.4.5:4133"), ip("1.2.3.5:4133")]);
+
+        // The subnet limits are never exceeded.
+        let limits = SubnetLimits { ipv4_16: 1, ..Default::default() };
+        let selected = limits.select_diverse_peers(&candidates, &connected, 4);
+        assert_eq!(selected, vec![ip("5.6.7.8:4133"), ip("9.9.9.9:4133")]);
+    }
+}
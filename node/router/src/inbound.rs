@@ -26,6 +26,7 @@ use crate::{
     },
     Outbound,
     Peer,
+    ReputationEvent,
 };
 use snarkos_node_tcp::protocols::Reading;
 use snarkvm::prelude::{
@@ -61,11 +62,22 @@ pub trait Inbound<N: Network>: Reading + Outbound<N> {
         // in the last `MESSAGE_LIMIT_TIME_FRAME_IN_SECS` seconds.
         let num_messages = self.router().cache.insert_inbound_message(peer_ip, Self::MESSAGE_LIMIT_TIME_FRAME_IN_SECS);
         if num_messages > Self::MESSAGE_LIMIT {
+            self.router().update_reputation(peer_ip, ReputationEvent::Spam);
             bail!("Dropping '{peer_ip}' for spamming messages (num_messages = {num_messages})")
         }
 
         trace!("Received '{}' from '{peer_ip}'", message.name());
 
+        // Handle the message, and lower the reputation of the peer if the message is invalid.
+        let result = self.inbound_message(peer_ip, message).await;
+        if result.is_err() {
+            self.router().update_reputation(peer_ip, ReputationEvent::InvalidMessage);
+        }
+        result
+    }
+
+    /// Handles the inbound message from the peer, given the listener IP of the peer.
+    async fn inbound_message(&self, peer_ip: SocketAddr, message: Message<N>) -> Result<()> {
         // This match statement handles the inbound message by deserializing the message,
         // checking the message is valid, and then calling the appropriate (trait) handler.
         match message {
@@ -102,7 +114,10 @@ pub trait Inbound<N: Network>: Reading + Outbound<N> {
                 // Process the block response.
                 let node = self.clone();
                 match spawn_blocking(move || node.block_response(peer_ip, blocks.0)).await? {
-                    true => Ok(()),
+                    true => {
+                        self.router().update_reputation(peer_ip, ReputationEvent::ValidBlockResponse);
+                        Ok(())
+                    }
                     false => bail!("Peer '{peer_ip}' sent an invalid block response"),
                 }
             }
@@ -158,7 +173,10 @@ pub trait Inbound<N: Network>: Reading + Outbound<N> {
                 }
             }
             Message::Pong(message) => match self.pong(peer_ip, message) {
-                true => Ok(()),
+                true => {
+                    self.router().update_reputation(peer_ip, ReputationEvent::TimelyResponse);
+                    Ok(())
+                }
                 false => bail!("Peer '{peer_ip}' sent an invalid pong"),
             },
             Message::PuzzleRequest(..) => {
@@ -189,7 +207,10 @@ pub trait Inbound<N: Network>: Reading + Outbound<N> {
                 };
                 // Process the puzzle response.
                 match self.puzzle_response(peer_ip, message.epoch_challenge, header) {
-                    true => Ok(()),
+                    true => {
+                        self.router().update_reputation(peer_ip, ReputationEvent::TimelyResponse);
+                        Ok(())
+                    }
                     false => bail!("Peer '{peer_ip}' sent an invalid puzzle response"),
                 }
             }
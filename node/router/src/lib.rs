@@ -40,14 +40,23 @@ mod routing;
 pub use routing::*;
 
 use crate::messages::NodeType;
-use snarkos_account::Account;
-use snarkos_node_tcp::{is_bogon_ip, is_unspecified_ip, Config, Tcp};
-use snarkvm::prelude::{Address, Network, PrivateKey, ViewKey};
+use snarkos_account::Signer;
+use snarkos_node_tcp::{is_bogon_ip, is_unspecified_ip, BanList, Config, IpRange, Tcp};
+use snarkvm::prelude::{Address, Network};
 
 use anyhow::{bail, Result};
 use indexmap::{IndexMap, IndexSet};
 use parking_lot::{Mutex, RwLock};
-use std::{collections::HashSet, future::Future, net::SocketAddr, ops::Deref, str::FromStr, sync::Arc, time::Instant};
+use std::{
+    collections::HashSet,
+    future::Future,
+    net::SocketAddr,
+    ops::Deref,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 use tokio::task::JoinHandle;
 
 #[derive(Clone)]
@@ -66,8 +75,8 @@ pub struct InnerRouter<N: Network> {
     tcp: Tcp,
     /// The node type.
     node_type: NodeType,
-    /// The account of the node.
-    account: Account<N>,
+    /// The signer of the node, which holds (or has access to) the account private key.
+    signer: Arc<dyn Signer<N>>,
     /// The cache.
     cache: Cache<N>,
     /// The resolver.
@@ -83,8 +92,19 @@ pub struct InnerRouter<N: Network> {
     connecting_peers: Mutex<HashSet<SocketAddr>>,
     /// The set of candidate peer IPs.
     candidate_peers: RwLock<IndexSet<SocketAddr>>,
-    /// The set of restricted peer IPs.
-    restricted_peers: RwLock<IndexMap<SocketAddr, Instant>>,
+    /// The reputation of the peers, which restricts the misbehaving peers.
+    reputation: Reputation,
+    /// The maximum number of connected peers per subnet.
+    subnet_limits: RwLock<SubnetLimits>,
+    /// The ban lists linked to the router, which enforce the operator-configured bans on other TCP stacks,
+    /// with the callback to disconnect the banned peers of each TCP stack.
+    linked_ban_lists: RwLock<Vec<(BanList, Box<dyn Fn() + Send + Sync>)>>,
+    /// The peer book, which records the known peers across restarts.
+    peer_book: RwLock<PeerBook>,
+    /// The path to the peer book on disk, if the peer book is persisted.
+    peer_book_path: Option<PathBuf>,
+    /// The timestamp of the last save of the peer book.
+    peer_book_saved_at: Mutex<Instant>,
     /// The spawned handles.
     handles: Mutex<Vec<JoinHandle<()>>>,
     /// The boolean flag for the development mode.
@@ -96,6 +116,10 @@ impl<N: Network> Router<N> {
     const MAXIMUM_CANDIDATE_PEERS: usize = 10_000;
     /// The maximum number of connection failures permitted by an inbound connecting peer.
     const MAXIMUM_CONNECTION_FAILURES: usize = 5;
+    /// The maximum number of long-lived connections that are protected from eviction.
+    const MAXIMUM_PROTECTED_PEERS: usize = 4;
+    /// The minimum duration in seconds between the periodic saves of the peer book.
+    const PEER_BOOK_SAVE_INTERVAL_IN_SECS: u64 = 300;
     /// The duration in seconds after which a connected peer is considered inactive or
     /// disconnected if no message has been received in the meantime.
     const RADIO_SILENCE_IN_SECS: u64 = 150; // 2.5 minutes
@@ -106,28 +130,222 @@ impl<N: Network> Router<N> {
     pub async fn new(
         node_ip: SocketAddr,
         node_type: NodeType,
-        account: Account<N>,
+        signer: Arc<dyn Signer<N>>,
         trusted_peers: &[SocketAddr],
         max_peers: u16,
         is_dev: bool,
+        peer_book_path: Option<PathBuf>,
     ) -> Result<Self> {
         // Initialize the TCP stack.
         let tcp = Tcp::new(Config::new(node_ip, max_peers));
         // Initialize the router.
-        Ok(Self(Arc::new(InnerRouter {
+        let router = Self(Arc::new(InnerRouter {
             tcp,
             node_type,
-            account,
+            signer,
             cache: Default::default(),
             resolver: Default::default(),
             trusted_peers: trusted_peers.iter().copied().collect(),
             connected_peers: Default::default(),
             connecting_peers: Default::default(),
             candidate_peers: Default::default(),
-            restricted_peers: Default::default(),
+            reputation: Default::default(),
+            subnet_limits: Default::default(),
+            linked_ban_lists: Default::default(),
+            peer_book: Default::default(),
+            peer_book_path,
+            peer_book_saved_at: Mutex::new(Instant::now()),
             handles: Default::default(),
             is_dev,
-        })))
+        }));
+        // Load the peer book, if it is persisted.
+        router.load_peer_book();
+        Ok(router)
+    }
+}
+
+impl<N: Network> Router<N> {
+    /// Loads the peer book from disk, restoring the bans and adding the known peers to the candidate peers.
+    /// The peers that were connected most recently are inserted first.
+    fn load_peer_book(&self) {
+        let Some(path) = &self.peer_book_path else { return };
+        let peer_book = match PeerBook::load(path) {
+            Ok(peer_book) => peer_book,
+            Err(error) => {
+                warn!("Unable to load the peer book - {error}");
+                return;
+            }
+        };
+
+        let now = now_unix();
+        let mut known_peers = Vec::with_capacity(peer_book.len());
+        for (peer_ip, record) in peer_book.iter() {
+            match record.remaining_ban(now) {
+                // Restore the ban of the peer.
+                Some(duration) => self.reputation.restore_ban(*peer_ip, duration),
+                None if !record.is_unreachable() => known_peers.push((*peer_ip, record.last_connected)),
+                None => {}
+            }
+        }
+        // Restore the operator-configured bans.
+        for (target, expires_at) in peer_book.operator_bans() {
+            match BanTarget::<N>::from_str(target) {
+                Ok(target) => {
+                    match expires_at.map(|timestamp| UNIX_EPOCH + Duration::from_secs(timestamp.max(0) as u64)) {
+                        Some(expires_at) if expires_at <= SystemTime::now() => {}
+                        expires_at => self.ban_until(target, expires_at),
+                    }
+                }
+                Err(error) => warn!("Unable to restore a ban from the peer book - {error}"),
+            }
+        }
+
+        known_peers.sort_by_key(|(_, last_connected)| std::cmp::Reverse(*last_connected));
+        known_peers.truncate(Self::MAXIMUM_CANDIDATE_PEERS);
+        // Note: The listener is not enabled yet, so the known peers are inserted without the checks
+        // of `insert_candidate_peers`, and self-connections are rejected when connecting instead.
+        self.candidate_peers.write().extend(known_peers.iter().map(|(peer_ip, _)| *peer_ip));
+
+        info!("Loaded {} known peers from the peer book", known_peers.len());
+        *self.peer_book.write() = peer_book;
+    }
+
+    /// Saves the peer book to disk in a blocking task, if it was not saved in the last
+    /// `PEER_BOOK_SAVE_INTERVAL_IN_SECS` seconds.
+    pub fn save_peer_book_periodically(&self) {
+        let Some(path) = self.peer_book_path.clone() else { return };
+        {
+            let mut saved_at = self.peer_book_saved_at.lock();
+            if saved_at.elapsed() < Duration::from_secs(Self::PEER_BOOK_SAVE_INTERVAL_IN_SECS) {
+                return;
+            }
+            *saved_at = Instant::now();
+        }
+        let peer_book = self.update_peer_book();
+        tokio::task::spawn_blocking(move || {
+            if let Err(error) = peer_book.save(&path) {
+                warn!("Unable to save the peer book - {error}");
+            }
+        });
+    }
+
+    /// Saves the peer book to disk in a blocking task, and waits for it to complete.
+    pub async fn save_peer_book(&self) {
+        let Some(path) = self.peer_book_path.clone() else { return };
+        let peer_book = self.update_peer_book();
+        match tokio::task::spawn_blocking(move || peer_book.save(&path)).await {
+            Ok(Ok(())) => (),
+            Ok(Err(error)) => warn!("Unable to save the peer book - {error}"),
+            Err(error) => warn!("Unable to save the peer book - {error}"),
+        }
+    }
+
+    /// Updates the peer book with the candidate peers and the current bans, and returns a copy of it.
+    fn update_peer_book(&self) -> PeerBook {
+        let mut peer_book = self.peer_book.write();
+        // Record the candidate peers, so they are remembered even if the node never connected to them.
+        for peer_ip in self.candidate_peers.read().iter() {
+            peer_book.entry(*peer_ip);
+        }
+        // Record the current bans.
+        for peer_ip in self.reputation.banned_peers() {
+            peer_book.entry(peer_ip);
+        }
+        let now = now_unix();
+        for (peer_ip, record) in peer_book.iter_mut() {
+            record.banned_until =
+                self.reputation.remaining_ban(peer_ip).map(|duration| now + duration.as_secs() as i64);
+        }
+        // Record the operator-configured bans.
+        let to_timestamp = |time: SystemTime| time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+        peer_book.set_operator_bans(
+            self.bans().into_iter().map(|(target, expires_at)| (target.to_string(), expires_at.map(to_timestamp))),
+        );
+        peer_book.prune();
+        peer_book.clone()
+    }
+
+    /// Bans the given target for the given duration (or permanently, if `None`), and disconnects the matching peers.
+    /// A duration that overflows the system time is treated as a permanent ban.
+    pub fn ban(&self, target: BanTarget<N>, duration: Option<Duration>) {
+        let expires_at = duration.and_then(|duration| SystemTime::now().checked_add(duration));
+        match (duration, expires_at) {
+            (Some(duration), Some(_)) => info!("Banning '{target}' for {} seconds", duration.as_secs()),
+            _ => info!("Banning '{target}'"),
+        }
+        self.ban_until(target, expires_at);
+    }
+
+    /// Bans the given target until the given expiry time (or permanently, if `None`), and disconnects the matching peers.
+    fn ban_until(&self, target: BanTarget<N>, expires_at: Option<SystemTime>) {
+        // The TCP stacks refuse connections from and to the banned IP ranges,
+        // and the handshakes refuse the banned addresses.
+        for ban_list in self.ban_lists() {
+            target.insert_into(&ban_list, expires_at);
+        }
+        let peer_ips = match target {
+            BanTarget::Ip(range) => {
+                self.candidate_peers.write().retain(|peer_ip| !range.contains(peer_ip.ip()));
+                self.connected_peers.read().keys().filter(|peer_ip| range.contains(peer_ip.ip())).copied().collect()
+            }
+            BanTarget::Address(address) => self
+                .connected_peers
+                .read()
+                .values()
+                .filter(|peer| peer.address() == address)
+                .map(|peer| peer.ip())
+                .collect::<Vec<_>>(),
+        };
+        for peer_ip in peer_ips {
+            warn!("Disconnecting from '{peer_ip}' (banned)");
+            self.disconnect(peer_ip);
+        }
+        // Disconnect the banned peers of the linked TCP stacks.
+        for (_, disconnect_banned) in self.linked_ban_lists.read().iter() {
+            disconnect_banned();
+        }
+    }
+
+    /// Lifts the ban of the given target. Returns `true` if the target was banned.
+    pub fn unban(&self, target: &BanTarget<N>) -> bool {
+        let is_removed =
+            self.ban_lists().iter().fold(false, |is_removed, ban_list| target.remove_from(ban_list) || is_removed);
+        if is_removed {
+            info!("Lifted the ban of '{target}'");
+        }
+        is_removed
+    }
+
+    /// Returns the operator-configured bans, with the expiry time of each ban (if any).
+    pub fn bans(&self) -> Vec<(BanTarget<N>, Option<SystemTime>)> {
+        BanTarget::list(self.tcp.ban_list())
+    }
+
+    /// Returns `true` if the given Aleo address is banned by the operator.
+    pub fn is_banned_address(&self, address: &Address<N>) -> bool {
+        self.tcp.ban_list().is_banned_identity(&address.to_string())
+    }
+
+    /// Links the given ban list (e.g. of the memory pool gateway) to the router, so that it enforces
+    /// the current and future operator-configured bans of the router. The given callback is invoked
+    /// on every new ban, to disconnect the banned peers that use the ban list.
+    pub fn link_ban_list(&self, ban_list: BanList, disconnect_banned: impl Fn() + Send + Sync + 'static) {
+        for (target, expires_at) in self.bans() {
+            target.insert_into(&ban_list, expires_at);
+        }
+        self.linked_ban_lists.write().push((ban_list, Box::new(disconnect_banned)));
+    }
+
+    /// Returns the ban list of the router, followed by the linked ban lists.
+    fn ban_lists(&self) -> Vec<BanList> {
+        let mut ban_lists = vec![self.tcp.ban_list().clone()];
+        ban_lists.extend(self.linked_ban_lists.read().iter().map(|(ban_list, _)| ban_list.clone()));
+        ban_lists
+    }
+
+    /// Returns `true` if the node has successfully connected to the given peer IP before, according to the peer book.
+    pub fn has_connected_before(&self, peer_ip: &SocketAddr) -> bool {
+        self.peer_book.read().get(peer_ip).map_or(false, |record| record.last_connected.is_some())
     }
 }
 
@@ -152,6 +370,8 @@ impl<N: Network> Router<N> {
                 // If the connection was not allowed, log the error.
                 Err(error) => {
                     router.connecting_peers.lock().remove(&peer_ip);
+                    // Record the failed connection attempt in the peer book.
+                    router.peer_book.write().record_failure(peer_ip);
                     warn!("Unable to connect to '{peer_ip}' - {error}");
                     false
                 }
@@ -177,6 +397,10 @@ impl<N: Network> Router<N> {
         if self.is_restricted(&peer_ip) {
             bail!("Dropping connection attempt to '{peer_ip}' (restricted)")
         }
+        // Ensure the subnet of the peer is not full.
+        if let Some(subnet) = self.full_subnet(&peer_ip) {
+            bail!("Dropping connection attempt to '{peer_ip}' (subnet '{subnet}' is full)")
+        }
         // Ensure the node is not already connecting to this peer.
         if !self.connecting_peers.lock().insert(peer_ip) {
             bail!("Dropping connection attempt to '{peer_ip}' (already shaking hands as the initiator)")
@@ -225,19 +449,14 @@ impl<N: Network> Router<N> {
         self.node_type
     }
 
-    /// Returns the account private key of the node.
-    pub fn private_key(&self) -> &PrivateKey<N> {
-        self.account.private_key()
-    }
-
-    /// Returns the account view key of the node.
-    pub fn view_key(&self) -> &ViewKey<N> {
-        self.account.view_key()
+    /// Returns the signer of the node.
+    pub fn signer(&self) -> &Arc<dyn Signer<N>> {
+        &self.signer
     }
 
     /// Returns the account address of the node.
     pub fn address(&self) -> Address<N> {
-        self.account.address()
+        self.signer.address()
     }
 
     /// Returns `true` if the node is in development mode.
@@ -280,13 +499,57 @@ impl<N: Network> Router<N> {
         self.connecting_peers.lock().contains(ip)
     }
 
-    /// Returns `true` if the given IP is restricted.
+    /// Returns `true` if the given IP is restricted, either for its reputation or by the operator.
     pub fn is_restricted(&self, ip: &SocketAddr) -> bool {
-        self.restricted_peers
-            .read()
-            .get(ip)
-            .map(|time| time.elapsed().as_secs() < Self::RADIO_SILENCE_IN_SECS)
-            .unwrap_or(false)
+        self.reputation.is_banned(ip) || self.tcp.ban_list().is_banned(ip.ip())
+    }
+
+    /// Returns the reputation score of the given peer IP.
+    pub fn reputation_score(&self, ip: &SocketAddr) -> f64 {
+        self.reputation.score(ip)
+    }
+
+    /// Returns the maximum number of connected peers per subnet.
+    pub fn subnet_limits(&self) -> SubnetLimits {
+        *self.subnet_limits.read()
+    }
+
+    /// Sets the maximum number of connected peers per subnet.
+    pub fn set_subnet_limits(&self, subnet_limits: SubnetLimits) {
+        *self.subnet_limits.write() = subnet_limits;
+    }
+
+    /// Returns the subnet of the given peer IP that is full, if any.
+    /// The trusted peers, and the nodes in development mode, are not subject to the subnet limits.
+    pub fn full_subnet(&self, peer_ip: &SocketAddr) -> Option<IpRange> {
+        if self.is_dev || self.trusted_peers.contains(peer_ip) {
+            return None;
+        }
+        self.subnet_limits().full_subnet(peer_ip.ip(), &self.connected_peers())
+    }
+
+    /// Returns the long-lived connected peers that are protected from eviction. These are the longest
+    /// connected peers without a negative reputation, taking at most one peer per subnet, so that
+    /// the node remains connected to a diverse set of peers it has reason to trust.
+    pub fn protected_peers(&self) -> Vec<SocketAddr> {
+        let mut peers = self
+            .get_connected_peers()
+            .into_iter()
+            .filter(|peer| !self.trusted_peers.contains(&peer.ip()) && self.reputation_score(&peer.ip()) >= 0.0)
+            .collect::<Vec<_>>();
+        peers.sort_by_key(|peer| peer.first_seen());
+
+        let subnet_limits = SubnetLimits { ipv4_16: 1, ipv4_24: 1, ipv6_48: 1 };
+        let mut protected = Vec::with_capacity(Self::MAXIMUM_PROTECTED_PEERS);
+        for peer in peers {
+            if protected.len() >= Self::MAXIMUM_PROTECTED_PEERS {
+                break;
+            }
+            if subnet_limits.full_subnet(peer.ip().ip(), &protected).is_none() {
+                protected.push(peer.ip());
+            }
+        }
+        protected
     }
 
     /// Returns the maximum number of connected peers.
@@ -321,7 +584,7 @@ impl<N: Network> Router<N> {
 
     /// Returns the number of restricted peers.
     pub fn number_of_restricted_peers(&self) -> usize {
-        self.restricted_peers.read().len()
+        self.reputation.banned_peers().len()
     }
 
     /// Returns the connected peer given the peer IP, if it exists.
@@ -361,7 +624,7 @@ impl<N: Network> Router<N> {
 
     /// Returns the list of restricted peers.
     pub fn restricted_peers(&self) -> Vec<SocketAddr> {
-        self.restricted_peers.read().keys().copied().collect()
+        self.reputation.banned_peers()
     }
 
     /// Returns the list of trusted peers.
@@ -393,12 +656,12 @@ impl<N: Network> Router<N> {
         let peer_ip = peer.ip();
         // Adds a bidirectional map between the listener address and (ambiguous) peer address.
         self.resolver.insert_peer(peer_ip, peer_addr);
+        // Record the successful connection in the peer book.
+        self.peer_book.write().record_connection(peer_ip, peer.node_type());
         // Add an entry for this `Peer` in the connected peers.
         self.connected_peers.write().insert(peer_ip, peer);
         // Remove this peer from the candidate peers, if it exists.
         self.candidate_peers.write().remove(&peer_ip);
-        // Remove this peer from the restricted peers, if it exists.
-        self.restricted_peers.write().remove(&peer_ip);
     }
 
     /// Inserts the given peer IPs to the set of candidate peers.
@@ -421,12 +684,35 @@ impl<N: Network> Router<N> {
         self.candidate_peers.write().extend(eligible_peers);
     }
 
-    /// Inserts the given peer into the restricted peers.
+    /// Inserts the given peer into the restricted peers, by banning it.
     pub fn insert_restricted_peer(&self, peer_ip: SocketAddr) {
         // Remove this peer from the candidate peers, if it exists.
         self.candidate_peers.write().remove(&peer_ip);
-        // Add the peer to the restricted peers.
-        self.restricted_peers.write().insert(peer_ip, Instant::now());
+        // Ban the peer.
+        let duration = self.reputation.ban(peer_ip);
+        debug!("Banned '{peer_ip}' for {} seconds", duration.as_secs());
+    }
+
+    /// Updates the reputation of the given peer IP with the event, and disconnects or bans the peer if it misbehaved.
+    /// The trusted peers are never disconnected or banned for their reputation.
+    pub fn update_reputation(&self, peer_ip: SocketAddr, event: ReputationEvent) {
+        let action = self.reputation.update(peer_ip, event);
+        if self.trusted_peers.contains(&peer_ip) {
+            return;
+        }
+        match action {
+            ReputationAction::None => {}
+            ReputationAction::Disconnect => {
+                warn!("Disconnecting from '{peer_ip}' (low reputation)");
+                self.disconnect(peer_ip);
+            }
+            ReputationAction::Ban(duration) => {
+                warn!("Banning '{peer_ip}' for {} seconds (low reputation)", duration.as_secs());
+                // Remove this peer from the candidate peers, if it exists.
+                self.candidate_peers.write().remove(&peer_ip);
+                self.disconnect(peer_ip);
+            }
+        }
     }
 
     /// Updates the connected peer with the given function.
@@ -476,6 +762,8 @@ impl<N: Network> Router<N> {
     /// Shuts down the router.
     pub async fn shut_down(&self) {
         info!("Shutting down the router...");
+        // Save the peer book.
+        self.save_peer_book().await;
         // Abort the tasks.
         self.handles.lock().iter().for_each(|handle| handle.abort());
         // Close the listener.
@@ -74,10 +74,11 @@ pub async fn client(listening_port: u16, max_peers: u16) -> TestRouter<CurrentNe
     Router::new(
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), listening_port),
         NodeType::Client,
-        sample_account(),
+        sample_account().into(),
         &[],
         max_peers,
         true,
+        None,
     )
     .await
     .expect("couldn't create client router")
@@ -90,10 +91,11 @@ pub async fn prover(listening_port: u16, max_peers: u16) -> TestRouter<CurrentNe
     Router::new(
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), listening_port),
         NodeType::Prover,
-        sample_account(),
+        sample_account().into(),
         &[],
         max_peers,
         true,
+        None,
     )
     .await
     .expect("couldn't create prover router")
@@ -106,10 +108,11 @@ pub async fn validator(listening_port: u16, max_peers: u16) -> TestRouter<Curren
     Router::new(
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), listening_port),
         NodeType::Validator,
-        sample_account(),
+        sample_account().into(),
         &[],
         max_peers,
         true,
+        None,
     )
     .await
     .expect("couldn't create validator router")
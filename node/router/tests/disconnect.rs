@@ -15,9 +15,17 @@
 mod common;
 use common::*;
 
-use snarkos_node_tcp::{protocols::Handshake, P2P};
+use snarkos_node_router::BanTarget;
+use snarkos_node_tcp::{protocols::Handshake, BanList, P2P};
 
 use core::time::Duration;
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 #[tokio::test]
 async fn test_disconnect_without_handshake() {
@@ -108,3 +116,27 @@ async fn test_disconnect_with_handshake() {
     assert_eq!(node1.tcp().num_connected(), 1); // Router 1 has no way of knowing that Router 0 disconnected.
     assert_eq!(node1.tcp().num_connecting(), 0);
 }
+
+#[tokio::test]
+async fn test_ban_disconnects_linked_peers() {
+    let node = validator(0, 1).await;
+
+    // Link a ban list, as a validator does with the ban list of its memory pool gateway.
+    let ban_list = BanList::default();
+    let num_disconnects = Arc::new(AtomicUsize::new(0));
+    let num_disconnects_clone = num_disconnects.clone();
+    node.link_ban_list(ban_list.clone(), move || {
+        num_disconnects_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    // Ban an IP range, and ensure the linked ban list enforces it, and its banned peers are disconnected.
+    node.ban(BanTarget::from_str("10.0.0.0/8").unwrap(), None);
+    assert!(ban_list.is_banned("10.1.2.3".parse().unwrap()));
+    assert_eq!(num_disconnects.load(Ordering::SeqCst), 1);
+
+    // Ban an address, and ensure the same.
+    let address = sample_account().address();
+    node.ban(BanTarget::Address(address), Some(Duration::from_secs(60)));
+    assert!(ban_list.is_banned_identity(&address.to_string()));
+    assert_eq!(num_disconnects.load(Ordering::SeqCst), 2);
+}
@@ -107,10 +107,11 @@ impl<N: Network, C: ConsensusStorage<N>> Client<N, C> {
         let router = Router::new(
             node_ip,
             NodeType::Client,
-            account,
+            account.into(),
             trusted_peers,
             Self::MAXIMUM_NUMBER_OF_PEERS as u16,
             dev.is_some(),
+            Some(crate::peer_book_path(N::ID, dev)),
         )
         .await?;
         // Load the coinbase puzzle.
@@ -151,6 +151,11 @@ impl<N: Network, C: ConsensusStorage<N>> CommunicationService for Client<N, C> {
     ) -> Option<tokio::sync::oneshot::Receiver<io::Result<()>>> {
         Outbound::send(self, peer_ip, message)
     }
+
+    /// Returns the reputation score of the given peer, to prefer the reputable peers for block requests.
+    fn peer_score(&self, peer_ip: SocketAddr) -> f64 {
+        self.router().reputation_score(&peer_ip)
+    }
 }
 
 #[async_trait]
@@ -163,6 +168,11 @@ impl<N: Network, C: ConsensusStorage<N>> Outbound<N> for Client<N, C> {
     fn router(&self) -> &Router<N> {
         &self.router
     }
+
+    /// Returns `true` if the node is synced up to the latest block.
+    fn is_block_synced(&self) -> bool {
+        self.sync.is_block_synced()
+    }
 }
 
 #[async_trait]
@@ -53,6 +53,19 @@ pub fn log_clean_error(dev: Option<u16>) {
     }
 }
 
+/// Returns the path to the double-sign guard of the validator, which is stored next to the ledger.
+pub fn double_sign_guard_path(network: u16, dev: Option<u16>) -> std::path::PathBuf {
+    aleo_std::aleo_ledger_dir(network, dev).with_extension("signer.guard")
+}
+
+/// The extension of the file next to the ledger, which holds the peer book of the node.
+pub const PEER_BOOK_EXTENSION: &str = "peers.json";
+
+/// Returns the path to the peer book of the node, which is stored next to the ledger.
+pub fn peer_book_path(network: u16, dev: Option<u16>) -> std::path::PathBuf {
+    aleo_std::aleo_ledger_dir(network, dev).with_extension(PEER_BOOK_EXTENSION)
+}
+
 use snarkvm::{
     ledger::store::ConsensusStorage,
     prelude::{const_assert, hrp2, AleoID, Field, Ledger, Network},
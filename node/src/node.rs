@@ -13,15 +13,13 @@
 // limitations under the License.
 
 use crate::{traits::NodeInterface, Client, Prover, Validator};
-use snarkos_account::Account;
-use snarkos_node_router::messages::NodeType;
+use snarkos_account::{Account, Signer};
+use snarkos_node_router::{messages::NodeType, Outbound, Router};
 use snarkvm::prelude::{
     block::Block,
     store::helpers::{memory::ConsensusMemory, rocksdb::ConsensusDB},
     Address,
     Network,
-    PrivateKey,
-    ViewKey,
 };
 
 use anyhow::Result;
@@ -42,7 +40,8 @@ impl<N: Network> Node<N> {
         node_ip: SocketAddr,
         rest_ip: Option<SocketAddr>,
         bft_ip: Option<SocketAddr>,
-        account: Account<N>,
+        account: Option<Account<N>>,
+        signer: Option<Arc<dyn Signer<N>>>,
         trusted_peers: &[SocketAddr],
         trusted_validators: &[SocketAddr],
         genesis: Block<N>,
@@ -50,8 +49,19 @@ impl<N: Network> Node<N> {
         dev: Option<u16>,
     ) -> Result<Self> {
         Ok(Self::Validator(Arc::new(
-            Validator::new(node_ip, rest_ip, bft_ip, account, trusted_peers, trusted_validators, genesis, cdn, dev)
-                .await?,
+            Validator::new(
+                node_ip,
+                rest_ip,
+                bft_ip,
+                account,
+                signer,
+                trusted_peers,
+                trusted_validators,
+                genesis,
+                cdn,
+                dev,
+            )
+            .await?,
         )))
     }
 
@@ -88,24 +98,6 @@ impl<N: Network> Node<N> {
         }
     }
 
-    /// Returns the account private key of the node.
-    pub fn private_key(&self) -> &PrivateKey<N> {
-        match self {
-            Self::Validator(node) => node.private_key(),
-            Self::Prover(node) => node.private_key(),
-            Self::Client(node) => node.private_key(),
-        }
-    }
-
-    /// Returns the account view key of the node.
-    pub fn view_key(&self) -> &ViewKey<N> {
-        match self {
-            Self::Validator(node) => node.view_key(),
-            Self::Prover(node) => node.view_key(),
-            Self::Client(node) => node.view_key(),
-        }
-    }
-
     /// Returns the account address of the node.
     pub fn address(&self) -> Address<N> {
         match self {
@@ -123,4 +115,40 @@ impl<N: Network> Node<N> {
             Self::Client(node) => node.is_dev(),
         }
     }
+
+    /// Returns the router of the node.
+    pub fn router(&self) -> &Router<N> {
+        match self {
+            Self::Validator(node) => node.router(),
+            Self::Prover(node) => node.router(),
+            Self::Client(node) => node.router(),
+        }
+    }
+
+    /// Returns `true` if the node is synced up to the latest block.
+    pub fn is_block_synced(&self) -> bool {
+        match self {
+            Self::Validator(node) => node.is_block_synced(),
+            Self::Prover(node) => node.is_block_synced(),
+            Self::Client(node) => node.is_block_synced(),
+        }
+    }
+
+    /// Returns the latest block height of the node.
+    pub fn latest_block_height(&self) -> u32 {
+        match self {
+            Self::Validator(node) => node.ledger().latest_height(),
+            Self::Prover(node) => node.latest_block_header().map(|header| header.height()).unwrap_or(0),
+            Self::Client(node) => node.ledger().latest_height(),
+        }
+    }
+
+    /// Returns the latest block hash of the node, if it maintains a ledger.
+    pub fn latest_block_hash(&self) -> Option<N::BlockHash> {
+        match self {
+            Self::Validator(node) => Some(node.ledger().latest_hash()),
+            Self::Prover(_) => None,
+            Self::Client(node) => Some(node.ledger().latest_hash()),
+        }
+    }
 }
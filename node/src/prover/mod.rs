@@ -102,10 +102,11 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
         let router = Router::new(
             node_ip,
             NodeType::Prover,
-            account,
+            account.into(),
             trusted_peers,
             Self::MAXIMUM_NUMBER_OF_PEERS as u16,
             dev.is_some(),
+            Some(crate::peer_book_path(N::ID, dev)),
         )
         .await?;
         // Load the coinbase puzzle.
@@ -137,6 +138,11 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
         // Return the node.
         Ok(node)
     }
+
+    /// Returns the latest block header known to the prover.
+    pub fn latest_block_header(&self) -> Option<Header<N>> {
+        self.latest_block_header.read().clone()
+    }
 }
 
 #[async_trait]
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use snarkos_node_router::{messages::NodeType, Routing};
-use snarkvm::prelude::{Address, Network, PrivateKey, ViewKey};
+use snarkvm::prelude::{Address, Network};
 
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
@@ -25,16 +25,6 @@ pub trait NodeInterface<N: Network>: Routing<N> {
         self.router().node_type()
     }
 
-    /// Returns the account private key of the node.
-    fn private_key(&self) -> &PrivateKey<N> {
-        self.router().private_key()
-    }
-
-    /// Returns the account view key of the node.
-    fn view_key(&self) -> &ViewKey<N> {
-        self.router().view_key()
-    }
-
     /// Returns the account address of the node.
     fn address(&self) -> Address<N> {
         self.router().address()
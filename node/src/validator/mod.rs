@@ -15,7 +15,7 @@
 mod router;
 
 use crate::traits::NodeInterface;
-use snarkos_account::Account;
+use snarkos_account::{Account, DoubleSignGuard, LocalSigner, Signer};
 use snarkos_node_bft::{helpers::init_primary_channels, ledger_service::CoreLedgerService};
 use snarkos_node_consensus::Consensus;
 use snarkos_node_rest::Rest;
@@ -38,9 +38,10 @@ use snarkvm::prelude::{
     store::ConsensusStorage,
     Ledger,
     Network,
+    PrivateKey,
 };
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use core::future::Future;
 use parking_lot::Mutex;
 use std::{
@@ -75,7 +76,8 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
         node_ip: SocketAddr,
         rest_ip: Option<SocketAddr>,
         bft_ip: Option<SocketAddr>,
-        account: Account<N>,
+        account: Option<Account<N>>,
+        signer: Option<Arc<dyn Signer<N>>>,
         trusted_peers: &[SocketAddr],
         trusted_validators: &[SocketAddr],
         genesis: Block<N>,
@@ -103,8 +105,19 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
         // Initialize the sync module.
         let sync = BlockSync::new(BlockSyncMode::Gateway, ledger_service.clone());
 
+        // Initialize the signer, which is either the remote signer, or a local signer for the account of the node.
+        // Outside of development mode, the double-sign guard is persisted, so it survives restarts.
+        let signer: Arc<dyn Signer<N>> = match (signer, &account, dev) {
+            (Some(signer), _, _) => signer,
+            (None, Some(account), Some(_)) => account.clone().into(),
+            (None, Some(account), None) => {
+                let guard = DoubleSignGuard::open(crate::double_sign_guard_path(N::ID, dev))?;
+                Arc::new(LocalSigner::with_guard(account.clone(), guard))
+            }
+            (None, None, _) => bail!("A validator requires either an account or a remote signer"),
+        };
         // Initialize the consensus.
-        let mut consensus = Consensus::new(account.clone(), ledger_service, bft_ip, trusted_validators, dev)?;
+        let mut consensus = Consensus::new(signer.clone(), ledger_service, bft_ip, trusted_validators, dev)?;
         // Initialize the primary channels.
         let (primary_sender, primary_receiver) = init_primary_channels::<N>();
         // Start the consensus.
@@ -114,12 +127,16 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
         let router = Router::new(
             node_ip,
             NodeType::Validator,
-            account,
+            signer,
             trusted_peers,
             Self::MAXIMUM_NUMBER_OF_PEERS as u16,
             dev.is_some(),
+            Some(crate::peer_book_path(N::ID, dev)),
         )
         .await?;
+        // Enforce the operator-configured bans on the memory pool gateway as well.
+        let gateway = consensus.bft().primary().gateway().clone();
+        router.link_ban_list(gateway.tcp().ban_list().clone(), move || gateway.disconnect_banned());
 
         // Initialize the node.
         let mut node = Self {
@@ -131,8 +148,10 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
             handles: Default::default(),
             shutdown: Default::default(),
         };
-        // Initialize the transaction pool.
-        node.initialize_transaction_pool(dev)?;
+        // Initialize the transaction pool, which requires the account private key.
+        if let Some(account) = account {
+            node.initialize_transaction_pool(*account.private_key(), dev)?;
+        }
 
         // Initialize the REST server.
         if let Some(rest_ip) = rest_ip {
@@ -157,6 +176,11 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
     pub fn rest(&self) -> &Option<Rest<N, C, Self>> {
         &self.rest
     }
+
+    /// Returns the consensus module.
+    pub fn consensus(&self) -> &Consensus<N> {
+        &self.consensus
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
@@ -331,7 +355,7 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
     // }
 
     /// Initialize the transaction pool.
-    fn initialize_transaction_pool(&self, dev: Option<u16>) -> Result<()> {
+    fn initialize_transaction_pool(&self, private_key: PrivateKey<N>, dev: Option<u16>) -> Result<()> {
         use snarkvm::console::{
             program::{Identifier, Literal, ProgramID, Value},
             types::U64,
@@ -379,7 +403,7 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
                 let inputs = [Value::from(Literal::Address(self_.address())), Value::from(Literal::U64(U64::new(1)))];
                 // Execute the transaction.
                 let transaction = match self_.ledger.vm().execute(
-                    self_.private_key(),
+                    &private_key,
                     locator,
                     inputs.into_iter(),
                     None,
@@ -480,7 +504,8 @@ mod tests {
             node,
             Some(rest),
             None,
-            account,
+            Some(account),
+            None,
             &[],
             &[],
             genesis,
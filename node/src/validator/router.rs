@@ -137,6 +137,11 @@ impl<N: Network, C: ConsensusStorage<N>> Outbound<N> for Validator<N, C> {
     fn router(&self) -> &Router<N> {
         &self.router
     }
+
+    /// Returns `true` if the node is synced up to the latest block.
+    fn is_block_synced(&self) -> bool {
+        self.consensus.bft().primary().is_synced()
+    }
 }
 
 #[async_trait]
@@ -34,4 +34,10 @@ pub trait CommunicationService: Send + Sync {
     /// without waiting for the actual delivery; instead, the caller is provided with a [`oneshot::Receiver`]
     /// which can be used to determine when and whether the message has been delivered.
     async fn send(&self, peer_ip: SocketAddr, message: Self::Message) -> Option<oneshot::Receiver<io::Result<()>>>;
+
+    /// Returns the score of the given peer, where the peers with higher scores are preferred for block requests.
+    /// By default, all peers are ranked equally.
+    fn peer_score(&self, _peer_ip: SocketAddr) -> f64 {
+        0.0
+    }
 }
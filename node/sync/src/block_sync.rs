@@ -25,7 +25,7 @@ use anyhow::{bail, ensure, Result};
 use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use parking_lot::RwLock;
-use rand::{prelude::IteratorRandom, CryptoRng, Rng};
+use rand::{prelude::SliceRandom, CryptoRng, Rng};
 use std::{
     collections::BTreeMap,
     net::{IpAddr, Ipv4Addr, SocketAddr},
@@ -212,7 +212,7 @@ impl<N: Network> BlockSync<N> {
     pub async fn try_block_sync<C: CommunicationService>(&self, communication: &C) {
         // Prepare the block requests, if any.
         // In the process, we update the state of `is_block_synced` for the sync module.
-        let block_requests = self.prepare_block_requests();
+        let block_requests = self.prepare_block_requests_with(|peer_ip| communication.peer_score(peer_ip));
         trace!("Prepared {} block requests", block_requests.len());
 
         // Process the block requests.
@@ -377,7 +377,13 @@ impl<N: Network> BlockSync<N> {
 
 impl<N: Network> BlockSync<N> {
     /// Returns a list of block requests, if the node needs to sync.
+    #[cfg(test)]
     fn prepare_block_requests(&self) -> Vec<(u32, SyncRequest<N>)> {
+        self.prepare_block_requests_with(|_| 0.0)
+    }
+
+    /// Returns a list of block requests, if the node needs to sync, preferring the sync peers with higher scores.
+    fn prepare_block_requests_with(&self, peer_score: impl Fn(SocketAddr) -> f64) -> Vec<(u32, SyncRequest<N>)> {
         // Remove timed out block requests.
         self.remove_timed_out_block_requests();
         // Prepare the block requests.
@@ -387,7 +393,7 @@ impl<N: Network> BlockSync<N> {
             // Update the state of `is_block_synced` for the sync module.
             self.update_is_block_synced(greatest_peer_height, MAX_BLOCKS_BEHIND);
             // Return the list of block requests.
-            self.construct_requests(sync_peers, min_common_ancestor, &mut rand::thread_rng())
+            self.construct_requests(sync_peers, min_common_ancestor, peer_score, &mut rand::thread_rng())
         } else {
             // Update the state of `is_block_synced` for the sync module.
             self.update_is_block_synced(0, MAX_BLOCKS_BEHIND);
@@ -725,6 +731,7 @@ impl<N: Network> BlockSync<N> {
         &self,
         sync_peers: IndexMap<SocketAddr, BlockLocators<N>>,
         min_common_ancestor: u32,
+        peer_score: impl Fn(SocketAddr) -> f64,
         rng: &mut R,
     ) -> Vec<(u32, SyncRequest<N>)> {
         // Retrieve the latest canon height.
@@ -761,8 +768,11 @@ impl<N: Network> BlockSync<N> {
                 }
             }
 
-            // Pick the sync peers.
-            let sync_ips = sync_peers.keys().copied().choose_multiple(rng, num_sync_ips);
+            // Pick the sync peers, starting with the highest scores and breaking ties randomly.
+            let mut sync_ips = sync_peers.keys().copied().collect::<Vec<_>>();
+            sync_ips.shuffle(rng);
+            sync_ips.sort_by(|a, b| peer_score(*b).total_cmp(&peer_score(*a)));
+            sync_ips.truncate(num_sync_ips);
 
             // Append the request.
             requests.push((height, (hash, previous_hash, sync_ips.into_iter().collect())));
@@ -0,0 +1,251 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+    sync::Arc,
+    time::SystemTime,
+};
+
+use parking_lot::RwLock;
+
+/// A range of IP addresses, written in CIDR notation (e.g. `10.0.0.0/8`), or as a single IP address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpRange {
+    /// The first IP address of the range.
+    network: IpAddr,
+    /// The number of leading bits shared by the IP addresses of the range.
+    prefix_len: u8,
+}
+
+impl IpRange {
+    /// Returns the range of the given prefix length that contains the given IP address.
+    /// IPv4-mapped IPv6 addresses are treated as IPv4 addresses.
+    pub fn new(ip: IpAddr, prefix_len: u8) -> io::Result<Self> {
+        let ip = canonical_ip(ip);
+        let max_prefix_len = match ip {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        if prefix_len > max_prefix_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("The prefix length of '{ip}' must be at most {max_prefix_len}"),
+            ));
+        }
+        let network = match ip {
+            IpAddr::V4(ipv4) => IpAddr::V4(Ipv4Addr::from(u32::from(ipv4) & mask_u32(prefix_len))),
+            IpAddr::V6(ipv6) => IpAddr::V6(Ipv6Addr::from(u128::from(ipv6) & mask_u128(prefix_len))),
+        };
+        Ok(Self { network, prefix_len })
+    }
+
+    /// Returns the first IP address of the range.
+    pub const fn network(&self) -> IpAddr {
+        self.network
+    }
+
+    /// Returns the prefix length of the range.
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns `true` if the range contains the given IP address.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, canonical_ip(ip)) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => u32::from(ip) & mask_u32(self.prefix_len) == u32::from(network),
+            (IpAddr::V6(network), IpAddr::V6(ip)) => u128::from(ip) & mask_u128(self.prefix_len) == u128::from(network),
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid IP range '{s}'"));
+        match s.split_once('/') {
+            Some((ip, prefix_len)) => {
+                let ip = IpAddr::from_str(ip.trim()).map_err(|_| invalid())?;
+                let prefix_len = u8::from_str(prefix_len.trim()).map_err(|_| invalid())?;
+                Self::new(ip, prefix_len)
+            }
+            None => {
+                let ip = canonical_ip(IpAddr::from_str(s.trim()).map_err(|_| invalid())?);
+                Self::new(ip, if ip.is_ipv4() { 32 } else { 128 })
+            }
+        }
+    }
+}
+
+impl fmt::Display for IpRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.network, self.prefix_len) {
+            (IpAddr::V4(_), 32) | (IpAddr::V6(_), 128) => write!(f, "{}", self.network),
+            _ => write!(f, "{}/{}", self.network, self.prefix_len),
+        }
+    }
+}
+
+/// Returns the IPv4 address for an IPv4-mapped IPv6 address, and the given IP address otherwise.
+fn canonical_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(ipv6) => ipv6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+        ipv4 => ipv4,
+    }
+}
+
+/// Returns the IPv4 mask of the given prefix length.
+fn mask_u32(prefix_len: u8) -> u32 {
+    u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0)
+}
+
+/// Returns the IPv6 mask of the given prefix length.
+fn mask_u128(prefix_len: u8) -> u128 {
+    u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0)
+}
+
+/// The banned IP ranges and identities (e.g. the account addresses of the peers), with the expiry time of each ban.
+#[derive(Debug, Default)]
+struct Bans {
+    /// The banned IP ranges.
+    ranges: HashMap<IpRange, Option<SystemTime>>,
+    /// The banned identities.
+    identities: HashMap<String, Option<SystemTime>>,
+}
+
+/// The list of banned IP ranges and identities. Connections from and to the banned IP ranges are refused
+/// by the TCP stack, while the banned identities are enforced by the handshake of the protocol.
+///
+/// note: The list is cheaply cloneable, and the clones share the same bans.
+#[derive(Clone, Debug, Default)]
+pub struct BanList(Arc<RwLock<Bans>>);
+
+impl BanList {
+    /// Bans the given IP range, until the given expiry time (or permanently, if `None`).
+    pub fn insert(&self, range: IpRange, expires_at: Option<SystemTime>) {
+        self.0.write().ranges.insert(range, expires_at);
+    }
+
+    /// Lifts the ban of the given IP range; returns `true` if the range was banned.
+    pub fn remove(&self, range: &IpRange) -> bool {
+        self.0.write().ranges.remove(range).is_some()
+    }
+
+    /// Returns `true` if the given IP address is within a banned IP range.
+    pub fn is_banned(&self, ip: IpAddr) -> bool {
+        let now = SystemTime::now();
+        self.0.read().ranges.iter().any(|(range, expires_at)| is_active(expires_at, now) && range.contains(ip))
+    }
+
+    /// Bans the given identity, until the given expiry time (or permanently, if `None`).
+    pub fn insert_identity(&self, identity: String, expires_at: Option<SystemTime>) {
+        self.0.write().identities.insert(identity, expires_at);
+    }
+
+    /// Lifts the ban of the given identity; returns `true` if the identity was banned.
+    pub fn remove_identity(&self, identity: &str) -> bool {
+        self.0.write().identities.remove(identity).is_some()
+    }
+
+    /// Returns `true` if the given identity is banned.
+    pub fn is_banned_identity(&self, identity: &str) -> bool {
+        self.0.read().identities.get(identity).map_or(false, |expires_at| is_active(expires_at, SystemTime::now()))
+    }
+
+    /// Returns the list of banned IP ranges and their expiry times, removing the expired bans.
+    pub fn snapshot(&self) -> Vec<(IpRange, Option<SystemTime>)> {
+        let now = SystemTime::now();
+        let mut bans = self.0.write();
+        bans.ranges.retain(|_, expires_at| is_active(expires_at, now));
+        bans.ranges.iter().map(|(range, expires_at)| (*range, *expires_at)).collect()
+    }
+
+    /// Returns the list of banned identities and their expiry times, removing the expired bans.
+    pub fn identities(&self) -> Vec<(String, Option<SystemTime>)> {
+        let now = SystemTime::now();
+        let mut bans = self.0.write();
+        bans.identities.retain(|_, expires_at| is_active(expires_at, now));
+        bans.identities.iter().map(|(identity, expires_at)| (identity.clone(), *expires_at)).collect()
+    }
+}
+
+/// Returns `true` if a ban with the given expiry time is active at the given time.
+fn is_active(expires_at: &Option<SystemTime>, now: SystemTime) -> bool {
+    expires_at.map_or(true, |expires_at| now < expires_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    #[test]
+    fn test_ip_range() {
+        let range = IpRange::from_str("10.1.2.3/16").unwrap();
+        assert_eq!(range.to_string(), "10.1.0.0/16");
+        assert!(range.contains("10.1.255.1".parse().unwrap()));
+        assert!(range.contains("::ffff:10.1.0.1".parse().unwrap()));
+        assert!(!range.contains("10.2.0.1".parse().unwrap()));
+
+        let range = IpRange::from_str("2001:db8:1::/48").unwrap();
+        assert!(range.contains("2001:db8:1:ffff::1".parse().unwrap()));
+        assert!(!range.contains("2001:db8:2::1".parse().unwrap()));
+        assert!(!range.contains("10.1.0.1".parse().unwrap()));
+
+        let range = IpRange::from_str("1.2.3.4").unwrap();
+        assert_eq!(range.prefix_len(), 32);
+        assert_eq!(range.to_string(), "1.2.3.4");
+        assert!(IpRange::from_str("0.0.0.0/0").unwrap().contains("1.2.3.4".parse().unwrap()));
+
+        assert!(IpRange::from_str("1.2.3.4/33").is_err());
+        assert!(IpRange::from_str("1.2.3/24").is_err());
+    }
+
+    #[test]
+    fn test_ban_list() {
+        let ban_list = BanList::default();
+        let range = IpRange::from_str("192.0.2.0/24").unwrap();
+        let ip = "192.0.2.7".parse().unwrap();
+        assert!(!ban_list.is_banned(ip));
+
+        ban_list.insert(range, None);
+        assert!(ban_list.is_banned(ip));
+        assert!(!ban_list.is_banned("192.0.3.7".parse().unwrap()));
+
+        // An expired ban is no longer enforced, and is removed from the snapshot.
+        ban_list.insert(range, Some(SystemTime::now() - Duration::from_secs(1)));
+        assert!(!ban_list.is_banned(ip));
+        assert!(ban_list.snapshot().is_empty());
+
+        ban_list.insert(range, None);
+        assert!(ban_list.remove(&range));
+        assert!(!ban_list.is_banned(ip));
+
+        // The identities are banned separately, and the clones share the same bans.
+        let clone = ban_list.clone();
+        clone.insert_identity("peer".to_string(), None);
+        assert!(ban_list.is_banned_identity("peer"));
+        assert!(!ban_list.is_banned_identity("other"));
+        assert_eq!(ban_list.identities().len(), 1);
+        assert!(ban_list.remove_identity("peer"));
+        assert!(!clone.is_banned_identity("peer"));
+    }
+}
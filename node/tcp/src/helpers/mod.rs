@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod ban_list;
+pub use ban_list::{BanList, IpRange};
+
 mod config;
 pub use config::Config;
 
@@ -21,6 +24,9 @@ pub use connections::{Connection, ConnectionSide};
 mod known_peers;
 pub use known_peers::KnownPeers;
 
+mod reputation;
+pub use reputation::{Reputation, ReputationAction, ReputationEvent};
+
 mod stats;
 pub use stats::Stats;
 
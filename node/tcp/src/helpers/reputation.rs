@@ -0,0 +1,268 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+/// An event that changes the reputation of a peer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReputationEvent {
+    /// The peer sent a block response that was accepted.
+    ValidBlockResponse,
+    /// The peer responded to a request in time.
+    TimelyResponse,
+    /// The peer sent a message that violates the protocol.
+    InvalidMessage,
+    /// The peer did not communicate within the allowed time.
+    Timeout,
+    /// The peer exceeded the rate limit on messages.
+    Spam,
+}
+
+impl ReputationEvent {
+    /// Returns the change in the reputation score for the event.
+    pub const fn score(&self) -> f64 {
+        match self {
+            Self::ValidBlockResponse => 2.0,
+            Self::TimelyResponse => 1.0,
+            Self::InvalidMessage => -20.0,
+            Self::Timeout => -25.0,
+            Self::Spam => -50.0,
+        }
+    }
+}
+
+/// The action to take on a peer, after a change in its reputation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReputationAction {
+    /// The peer may remain connected.
+    None,
+    /// The peer should be disconnected.
+    Disconnect,
+    /// The peer should be disconnected, and is banned for the given duration.
+    Ban(Duration),
+}
+
+/// The reputation score of a peer.
+#[derive(Copy, Clone, Debug)]
+struct Score {
+    /// The score, as of the last update.
+    value: f64,
+    /// The timestamp of the last update.
+    updated_at: Instant,
+    /// The number of times the peer was banned.
+    num_bans: u32,
+    /// The timestamp at which the current ban expires, if the peer was banned.
+    banned_until: Option<Instant>,
+}
+
+impl Score {
+    /// Returns the score at the given time, decayed towards zero since the last update.
+    fn value_at(&self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.value * 0.5f64.powf(elapsed / Reputation::HALF_LIFE_IN_SECS as f64)
+    }
+
+    /// Returns `true` if the peer is banned at the given time.
+    fn is_banned_at(&self, now: Instant) -> bool {
+        self.banned_until.map_or(false, |banned_until| now < banned_until)
+    }
+}
+
+/// The reputation of the peers, which rises with useful behavior and falls with violations.
+/// The scores decay towards zero over time, so that past behavior is eventually forgotten.
+#[derive(Debug, Default)]
+pub struct Reputation {
+    /// The map of peer IPs to their scores.
+    scores: RwLock<IndexMap<SocketAddr, Score>>,
+}
+
+impl Reputation {
+    /// The duration in seconds of the first ban of a peer, which doubles with every subsequent ban.
+    pub const BAN_DURATION_IN_SECS: u64 = 300;
+    /// The score at or below which a peer is banned.
+    pub const BAN_THRESHOLD: f64 = -100.0;
+    /// The score at or below which a peer is disconnected.
+    pub const DISCONNECT_THRESHOLD: f64 = -50.0;
+    /// The duration in seconds after which a score has decayed to half of its value.
+    pub const HALF_LIFE_IN_SECS: u64 = 600;
+    /// The maximum duration in seconds of a ban.
+    pub const MAXIMUM_BAN_DURATION_IN_SECS: u64 = 86_400;
+    /// The maximum reputation score of a peer.
+    pub const MAXIMUM_SCORE: f64 = 100.0;
+    /// The maximum number of peers whose scores are tracked.
+    const MAXIMUM_TRACKED_PEERS: usize = 10_000;
+    /// The minimum reputation score of a peer.
+    pub const MINIMUM_SCORE: f64 = -200.0;
+
+    /// Returns the reputation score of the given peer IP.
+    pub fn score(&self, peer_ip: &SocketAddr) -> f64 {
+        self.scores.read().get(peer_ip).map_or(0.0, |score| score.value_at(Instant::now()))
+    }
+
+    /// Returns `true` if the given peer IP is banned.
+    pub fn is_banned(&self, peer_ip: &SocketAddr) -> bool {
+        self.scores.read().get(peer_ip).map_or(false, |score| score.is_banned_at(Instant::now()))
+    }
+
+    /// Returns the list of banned peer IPs.
+    pub fn banned_peers(&self) -> Vec<SocketAddr> {
+        let now = Instant::now();
+        self.scores.read().iter().filter(|(_, score)| score.is_banned_at(now)).map(|(ip, _)| *ip).collect()
+    }
+
+    /// Returns the remaining duration of the ban of the given peer IP, if it is banned.
+    pub fn remaining_ban(&self, peer_ip: &SocketAddr) -> Option<Duration> {
+        let now = Instant::now();
+        self.scores
+            .read()
+            .get(peer_ip)
+            .and_then(|score| score.banned_until)
+            .map(|until| until.saturating_duration_since(now))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Bans the given peer IP for the given duration, such as a ban restored from the peer book.
+    pub fn restore_ban(&self, peer_ip: SocketAddr, duration: Duration) {
+        let now = Instant::now();
+        let duration = duration.min(Duration::from_secs(Self::MAXIMUM_BAN_DURATION_IN_SECS));
+        let mut scores = self.scores.write();
+        let score =
+            scores.entry(peer_ip).or_insert(Score { value: 0.0, updated_at: now, num_bans: 0, banned_until: None });
+        score.value = Self::BAN_THRESHOLD;
+        score.updated_at = now;
+        score.num_bans = score.num_bans.max(1);
+        score.banned_until = Some(now + duration);
+    }
+
+    /// Updates the reputation of the given peer IP with the event, and returns the action to take on the peer.
+    pub fn update(&self, peer_ip: SocketAddr, event: ReputationEvent) -> ReputationAction {
+        self.update_at(peer_ip, event.score(), Instant::now())
+    }
+
+    /// Bans the given peer IP, and returns the duration of the ban.
+    pub fn ban(&self, peer_ip: SocketAddr) -> Duration {
+        let now = Instant::now();
+        // Lower the score to the ban threshold, so the peer is banned by the update.
+        let delta = (Self::BAN_THRESHOLD - self.score(&peer_ip)).min(0.0);
+        match self.update_at(peer_ip, delta, now) {
+            ReputationAction::Ban(duration) => duration,
+            // The peer is already banned, so return the remainder of the ban.
+            _ => self
+                .scores
+                .read()
+                .get(&peer_ip)
+                .and_then(|score| score.banned_until)
+                .map_or(Duration::ZERO, |until| until.saturating_duration_since(now)),
+        }
+    }
+
+    /// Updates the score of the given peer IP by the given delta at the given time, and returns the action to take.
+    fn update_at(&self, peer_ip: SocketAddr, delta: f64, now: Instant) -> ReputationAction {
+        let mut scores = self.scores.write();
+
+        // Bound the number of tracked peers, by forgetting the peers with negligible scores.
+        if scores.len() >= Self::MAXIMUM_TRACKED_PEERS && !scores.contains_key(&peer_ip) {
+            scores.retain(|_, score| score.is_banned_at(now) || score.value_at(now).abs() >= 1.0);
+        }
+
+        let score =
+            scores.entry(peer_ip).or_insert(Score { value: 0.0, updated_at: now, num_bans: 0, banned_until: None });
+        score.value = (score.value_at(now) + delta).clamp(Self::MINIMUM_SCORE, Self::MAXIMUM_SCORE);
+        score.updated_at = now;
+
+        match score.value {
+            // Ban the peer, unless it is already banned.
+            value if value <= Self::BAN_THRESHOLD && !score.is_banned_at(now) => {
+                score.num_bans = score.num_bans.saturating_add(1);
+                let duration = Self::BAN_DURATION_IN_SECS
+                    .saturating_mul(1 << (score.num_bans - 1).min(16))
+                    .min(Self::MAXIMUM_BAN_DURATION_IN_SECS);
+                let duration = Duration::from_secs(duration);
+                score.banned_until = Some(now + duration);
+                ReputationAction::Ban(duration)
+            }
+            value if value <= Self::DISCONNECT_THRESHOLD => ReputationAction::Disconnect,
+            _ => ReputationAction::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_ip() -> SocketAddr {
+        "1.2.3.4:4130".parse().unwrap()
+    }
+
+    #[test]
+    fn test_reputation_actions() {
+        let reputation = Reputation::default();
+        let peer_ip = sample_ip();
+        let now = Instant::now();
+
+        // Useful behavior does not trigger any action.
+        assert_eq!(
+            reputation.update_at(peer_ip, ReputationEvent::ValidBlockResponse.score(), now),
+            ReputationAction::None
+        );
+        // Violations first disconnect, then ban the peer.
+        assert_eq!(reputation.update_at(peer_ip, -50.0, now), ReputationAction::None);
+        assert_eq!(reputation.update_at(peer_ip, -10.0, now), ReputationAction::Disconnect);
+        let ban = Duration::from_secs(Reputation::BAN_DURATION_IN_SECS);
+        assert_eq!(reputation.update_at(peer_ip, -50.0, now), ReputationAction::Ban(ban));
+        assert!(reputation.is_banned(&peer_ip));
+        assert_eq!(reputation.banned_peers(), vec![peer_ip]);
+        // A banned peer is not banned again while the ban lasts.
+        assert_eq!(reputation.update_at(peer_ip, -50.0, now), ReputationAction::Disconnect);
+    }
+
+    #[test]
+    fn test_reputation_decay() {
+        let reputation = Reputation::default();
+        let peer_ip = sample_ip();
+        let now = Instant::now();
+
+        reputation.update_at(peer_ip, -80.0, now);
+        // The score halves after each half-life.
+        let score = reputation.scores.read()[&peer_ip];
+        let half_life = Duration::from_secs(Reputation::HALF_LIFE_IN_SECS);
+        assert!((score.value_at(now + half_life) + 40.0).abs() < 1e-9);
+        assert!((score.value_at(now + 2 * half_life) + 20.0).abs() < 1e-9);
+        // The scores are bounded.
+        reputation.update_at(peer_ip, 1_000.0, now);
+        assert_eq!(reputation.scores.read()[&peer_ip].value, Reputation::MAXIMUM_SCORE);
+    }
+
+    #[test]
+    fn test_reputation_ban_duration_doubles() {
+        let reputation = Reputation::default();
+        let peer_ip = sample_ip();
+        let now = Instant::now();
+
+        let first_ban = Duration::from_secs(Reputation::BAN_DURATION_IN_SECS);
+        assert_eq!(reputation.update_at(peer_ip, Reputation::BAN_THRESHOLD, now), ReputationAction::Ban(first_ban));
+        // After the ban expires, the next ban lasts twice as long.
+        let later = now + first_ban;
+        assert_eq!(
+            reputation.update_at(peer_ip, Reputation::BAN_THRESHOLD, later),
+            ReputationAction::Ban(2 * first_ban)
+        );
+    }
+}
@@ -39,6 +39,7 @@ use tracing::*;
 use crate::{
     connections::{Connection, ConnectionSide, Connections},
     protocols::{Protocol, Protocols},
+    BanList,
     Config,
     KnownPeers,
     Stats,
@@ -77,6 +78,8 @@ pub struct InnerTcp {
     known_peers: KnownPeers,
     /// Collects statistics related to the node itself.
     stats: Stats,
+    /// The banned IP ranges, which are refused connections.
+    ban_list: BanList,
     /// The node's tasks.
     pub(crate) tasks: Mutex<Vec<JoinHandle<()>>>,
 }
@@ -102,6 +105,7 @@ impl Tcp {
             connections: Default::default(),
             known_peers: Default::default(),
             stats: Default::default(),
+            ban_list: Default::default(),
             tasks: Default::default(),
         }));
 
@@ -171,6 +175,12 @@ impl Tcp {
         &self.stats
     }
 
+    /// Returns the list of banned IP ranges.
+    #[inline]
+    pub fn ban_list(&self) -> &BanList {
+        &self.ban_list
+    }
+
     /// Returns the tracing [`Span`] associated with Tcp.
     #[inline]
     pub fn span(&self) -> &Span {
@@ -210,6 +220,11 @@ impl Tcp {
             }
         }
 
+        if self.ban_list.is_banned(addr.ip()) {
+            warn!(parent: self.span(), "Refusing to connect to {addr} (banned)");
+            return Err(io::ErrorKind::ConnectionRefused.into());
+        }
+
         if !self.can_add_connection() {
             error!(parent: self.span(), "Too many connections; refusing to connect to {addr}");
             return Err(io::ErrorKind::ConnectionRefused.into());
@@ -365,6 +380,11 @@ impl Tcp {
     fn handle_connection(&self, stream: TcpStream, addr: SocketAddr) {
         debug!(parent: self.span(), "Received a connection from {addr}");
 
+        if self.ban_list.is_banned(addr.ip()) {
+            debug!(parent: self.span(), "Rejecting the connection from {addr} (banned)");
+            return;
+        }
+
         if !self.can_add_connection() || self.is_self_connect(addr) {
             debug!(parent: self.span(), "Rejecting the connection from {addr}");
             return;
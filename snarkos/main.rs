@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkos_cli::{commands::CLI, helpers::Updater};
+use snarkos_cli::{
+    commands::CLI,
+    helpers::{json_error, json_success, ErrorCode, OutputFormat, Updater},
+};
 
-use clap::Parser;
+use clap::{error::ErrorKind, Parser};
 use std::process::exit;
 
 #[cfg(feature = "jemalloc")]
@@ -26,7 +29,33 @@ static GLOBAL: Jemalloc = Jemalloc;
 
 fn main() -> anyhow::Result<()> {
     // Parse the given arguments.
-    let cli = CLI::parse();
+    let cli = match CLI::try_parse() {
+        Ok(cli) => cli,
+        // Report invalid arguments as a JSON document, if requested.
+        Err(error)
+            if !matches!(error.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion)
+                && OutputFormat::is_requested_in(std::env::args()) =>
+        {
+            println!("{}", json_error(ErrorCode::InvalidArguments, &error.to_string()));
+            exit(2);
+        }
+        Err(error) => error.exit(),
+    };
+    // Set the output format.
+    cli.output.initialize();
+
+    // Run the CLI, and emit the result as JSON.
+    if OutputFormat::is_json() {
+        match cli.command.parse() {
+            Ok(output) => println!("{}", json_success(&output)),
+            Err(error) => {
+                println!("{}", json_error(ErrorCode::of(&error), &format!("{error:#}")));
+                exit(1);
+            }
+        }
+        return Ok(());
+    }
+
     // Run the updater.
     println!("{}", Updater::print_cli());
     // Run the CLI.